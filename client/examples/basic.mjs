@@ -11,14 +11,14 @@ export class JsBindgen {
     constructor(module) {
         this.#module = module;
         this.#jsEmbed = {
-			js_sys: {
+			js_sys_0_0_0: {
 				'extern_ref': (refPtr) => {
-					const [ptr, len] = this.#jsEmbed.js_sys['view.getUint32'](refPtr, 2)
+					const [ptr, len] = this.#jsEmbed.js_sys_0_0_0['view.getUint32'](refPtr, 2)
 					return { ptr, len }
 				},
 				'view.getInt32': (ptr, count) => {
 
-					if (this.#jsEmbed.js_sys.isLittleEndian) {
+					if (this.#jsEmbed.js_sys_0_0_0.isLittleEndian) {
 						const view = new Int32Array(this.#memory.buffer, ptr, count)
 						return Array.from(view)
 					} else {
@@ -41,14 +41,14 @@ export class JsBindgen {
 				'array.js_value.decode': (ptr, len) => {
 					const array = new Array(len)
 					for (let arrayIndex = 0; arrayIndex < len; arrayIndex++) {
-						const [refIndex] = this.#jsEmbed.js_sys['view.getInt32'](ptr + arrayIndex * 4, 1)
-						array[arrayIndex] = this.#jsEmbed.js_sys['externref.table'].get(refIndex)
+						const [refIndex] = this.#jsEmbed.js_sys_0_0_0['view.getInt32'](ptr + arrayIndex * 4, 1)
+						array[arrayIndex] = this.#jsEmbed.js_sys_0_0_0['externref.table'].get(refIndex)
 					}
 					return array
 				},
 				'array.rust.js_value': (dataPtr) => {
-					const { ptr, len } = this.#jsEmbed.js_sys['extern_ref'](dataPtr)
-					return this.#jsEmbed.js_sys['array.js_value.decode'](ptr, len)
+					const { ptr, len } = this.#jsEmbed.js_sys_0_0_0['extern_ref'](dataPtr)
+					return this.#jsEmbed.js_sys_0_0_0['array.js_value.decode'](ptr, len)
 				},
 				'isLittleEndian': (() => {
 					const buffer = new ArrayBuffer(2)
@@ -57,7 +57,7 @@ export class JsBindgen {
 				})(),
 				'view.getUint32': (ptr, count) => {
 
-					if (this.#jsEmbed.js_sys.isLittleEndian) {
+					if (this.#jsEmbed.js_sys_0_0_0.isLittleEndian) {
 						const view = new Uint32Array(this.#memory.buffer, ptr, count)
 						return Array.from(view)
 					} else {
@@ -78,18 +78,18 @@ export class JsBindgen {
 		};
         this.#importObject = {
 			js_bindgen: { memory: this.#memory },
-			js_sys: {
-				'externref.table': this.#jsEmbed.js_sys['externref.table'],
+			js_sys_0_0_0: {
+				'externref.table': this.#jsEmbed.js_sys_0_0_0['externref.table'],
 				'string_decode': (array, len) => {
 					array >>>= 0
 					len >>>= 0
-					return this.#jsEmbed.js_sys['string.decode'](array, len)
+					return this.#jsEmbed.js_sys_0_0_0['string.decode'](array, len)
 				},
 			},
 			web_sys: {
 				'console.log2': globalThis.console.log,
 				'console.log': (data) => {
-					data = this.#jsEmbed.js_sys['array.rust.js_value'](data)
+					data = this.#jsEmbed.js_sys_0_0_0['array.rust.js_value'](data)
 				globalThis.console.log(data)
 				},
 				'console.log0': globalThis.console.log,
@@ -9,7 +9,7 @@ use core::panic;
 
 use mini_alloc::MiniAlloc;
 use web_sys::console;
-use web_sys::js_sys::JsString;
+use web_sys::js_sys::{JsScope, JsString};
 
 #[panic_handler]
 fn panic(_: &panic::PanicInfo<'_>) -> ! {
@@ -23,8 +23,10 @@ static ALLOC: MiniAlloc = MiniAlloc::INIT;
 extern "C" fn foo() {
 	console::log0();
 	console::log(&[JsString::from("Hello, World!")]);
-	console::log2(
-		JsString::from("Hello").as_ref(),
-		JsString::from("World!").as_ref(),
-	);
+	JsScope::run(|scope| {
+		console::log2(
+			JsString::scoped(scope, "Hello").as_ref(),
+			JsString::scoped(scope, "World!").as_ref(),
+		);
+	});
 }
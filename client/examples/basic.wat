@@ -11,8 +11,8 @@
   (type (;9;) (func (param i32) (result externref)))
   (type (;10;) (func (param i32 i32)))
   (import "js_bindgen" "memory" (memory (;0;) 17 65536))
-  (import "js_sys" "string_decode" (func $js_sys.import.string_decode (;0;) (type 0)))
-  (import "js_sys" "externref.table" (table (;0;) 2 externref))
+  (import "js_sys_0_0_0" "string_decode" (func $js_sys_0_0_0.import.string_decode (;0;) (type 0)))
+  (import "js_sys_0_0_0" "externref.table" (table (;0;) 2 externref))
   (import "web_sys" "console.log" (func $web_sys.import.console.log (;1;) (type 1)))
   (import "web_sys" "console.log0" (func $web_sys.import.console.log0 (;2;) (type 2)))
   (import "web_sys" "console.log2" (func $web_sys.import.console.log2 (;3;) (type 3)))
@@ -21,7 +21,7 @@
   (global (;2;) i32 i32.const 1048624)
   (export "foo" (func $foo))
   (export "__heap_base" (global 1))
-  (export "js_sys.externref.next" (func $js_sys.externref.next))
+  (export "js_sys_0_0_0.externref.next" (func $js_sys_0_0_0.externref.next))
   (export "__data_end" (global 2))
   (start $__wasm_init_memory)
   (func $__wasm_init_memory (;4;) (type 2)
@@ -41,7 +41,7 @@
     local.get 0
     i32.const 1048576
     i32.const 13
-    call $js_sys.string_decode
+    call $js_sys_0_0_0.string_decode
     i32.store offset=4
     local.get 0
     i32.const 1
@@ -60,11 +60,11 @@
     call $_ZN64_$LT$js_sys..value..JsValue$u20$as$u20$core..ops..drop..Drop$GT$4drop17h3a13c04dfd5e7e8cE
     i32.const 1048589
     i32.const 5
-    call $js_sys.string_decode
+    call $js_sys_0_0_0.string_decode
     local.tee 1
     i32.const 1048594
     i32.const 6
-    call $js_sys.string_decode
+    call $js_sys_0_0_0.string_decode
     local.tee 2
     call $web_sys.console.log2
     local.get 2
@@ -150,7 +150,7 @@
           local.get 0
           i32.store
           local.get 0
-          call $js_sys.externref.remove
+          call $js_sys_0_0_0.externref.remove
           i32.const 0
           i32.const 0
           i32.load offset=1048604
@@ -410,7 +410,7 @@
     i32.const 0
     i32.store
   )
-  (func $js_sys.externref.next (;10;) (type 5) (result i32)
+  (func $js_sys_0_0_0.externref.next (;10;) (type 5) (result i32)
     (local i32 i32)
     block ;; label = @1
       block ;; label = @2
@@ -427,7 +427,7 @@
           br_if 1 (;@2;)
           block ;; label = @4
             i32.const 1
-            call $js_sys.externref.grow
+            call $js_sys_0_0_0.externref.grow
             local.tee 0
             i32.const -1
             i32.eq
@@ -467,30 +467,30 @@
     i32.store offset=1048604
     local.get 0
   )
-  (func $js_sys.string_decode (;11;) (type 6) (param i32 i32) (result i32)
+  (func $js_sys_0_0_0.string_decode (;11;) (type 6) (param i32 i32) (result i32)
     local.get 0
     local.get 1
-    call $js_sys.import.string_decode
-    call $js_sys.externref.insert
+    call $js_sys_0_0_0.import.string_decode
+    call $js_sys_0_0_0.externref.insert
   )
-  (func $js_sys.externref.grow (;12;) (type 7) (param i32) (result i32)
+  (func $js_sys_0_0_0.externref.grow (;12;) (type 7) (param i32) (result i32)
     ref.null extern
     local.get 0
     table.grow 0
   )
-  (func $js_sys.externref.insert (;13;) (type 8) (param externref) (result i32)
+  (func $js_sys_0_0_0.externref.insert (;13;) (type 8) (param externref) (result i32)
     (local i32)
-    call $js_sys.externref.next
+    call $js_sys_0_0_0.externref.next
     local.tee 1
     local.get 0
     table.set 0
     local.get 1
   )
-  (func $js_sys.externref.get (;14;) (type 9) (param i32) (result externref)
+  (func $js_sys_0_0_0.externref.get (;14;) (type 9) (param i32) (result externref)
     local.get 0
     table.get 0
   )
-  (func $js_sys.externref.remove (;15;) (type 1) (param i32)
+  (func $js_sys_0_0_0.externref.remove (;15;) (type 1) (param i32)
     local.get 0
     ref.null extern
     table.set 0
@@ -504,9 +504,9 @@
   )
   (func $web_sys.console.log2 (;18;) (type 10) (param i32 i32)
     local.get 0
-    call $js_sys.externref.get
+    call $js_sys_0_0_0.externref.get
     local.get 1
-    call $js_sys.externref.get
+    call $js_sys_0_0_0.externref.get
     call $web_sys.import.console.log2
   )
   (data $.rodata (;0;) (i32.const 1048576) "Hello, World!HelloWorld!")
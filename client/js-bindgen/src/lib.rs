@@ -15,3 +15,22 @@ unsafe extern "C" {}
 pub mod r#macro;
 
 pub use js_bindgen_macro::{embed_js, import_js, unsafe_global_wat};
+
+/// Expands to the namespace of the calling crate, consisting of its name and
+/// the semver-compatible part of its version, e.g. `js_sys_1` for `1.2.3`,
+/// `js_sys_0_2` for `0.2.3` and `js_sys_0_0_3` for `0.0.3`.
+///
+/// Import modules, embeds and symbols should be placed into this namespace, so
+/// semver-incompatible versions of the same crate can be linked into one
+/// binary.
+///
+/// With a name, expands to `<namespace>.<name>`, suitable for symbols.
+#[macro_export]
+macro_rules! namespace {
+	() => {
+		$crate::r#macro::namespace!()
+	};
+	($name:expr) => {
+		::core::concat!($crate::namespace!(), ".", $name)
+	};
+}
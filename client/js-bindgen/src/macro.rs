@@ -2,6 +2,8 @@
 
 #![expect(clippy::cast_possible_truncation, reason = "itoa implementation")]
 
+pub use js_bindgen_macro::namespace;
+
 #[derive(Clone, Copy)]
 pub struct ConstInteger<T>(pub T);
 
@@ -0,0 +1,99 @@
+//! This file was generated by `js-sys-bindgen`.
+
+#![allow(warnings)]
+
+use js_sys::{js_bindgen, r#macro};
+use js_sys::hazard::{Input, Output};
+use js_sys::JsValue;
+
+#[track_caller]
+pub(super) fn atomics_can_block() -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"atomics_can_block\" (func ${ns}.import.atomics_can_block (@sym (name \"{ns}.import.atomics_can_block\")) (result {}))){}",
+		"(func ${ns}.atomics_can_block (@sym) (param {}) (result {})",
+		"  call ${ns}.import.atomics_can_block (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_output_import_type:: < bool > (),
+		interpolate r#macro::wat_imports!((), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate r#macro::wat_direct:: < bool > (), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "atomics_can_block",
+		required_embeds = [
+			(js_bindgen::namespace!(), "atomics.can_block"),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}",
+		interpolate r#macro::js_select!("", "() => {\n\treturn ", (), bool),
+		interpolate r#macro::js_output!(
+			"",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['atomics.can_block']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['atomics.can_block']()"),
+			bool,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("atomics_can_block")]
+		fn atomics_can_block() -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { atomics_can_block() })
+}
+
+#[track_caller]
+pub(super) fn atomics_wait_async(address: f64, expected: i32) -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"atomics_wait_async\" (func ${ns}.import.atomics_wait_async (@sym (name \"{ns}.import.atomics_wait_async\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.atomics_wait_async (@sym) (param {}) (param $address {}) (param $expected {}) (result {})",
+		"  local.get $address{}", "  local.get $expected{}",
+		"  call ${ns}.import.atomics_wait_async (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < f64 > (),
+		interpolate r#macro::wat_input_import_type:: < i32 > (), interpolate
+		r#macro::wat_output_import_type:: < JsValue > (), interpolate r#macro::wat_imports!((f64,
+		i32), JsValue), interpolate r#macro::wat_indirect!(JsValue), interpolate < f64 as Input >
+		::WAT_TYPE, interpolate < i32 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: <
+		JsValue > (), interpolate r#macro::wat_input!(f64), interpolate r#macro::wat_input!(i32),
+		interpolate r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "atomics_wait_async",
+		required_embeds = [
+			(js_bindgen::namespace!(), "atomics.wait_async"),
+			r#macro::js_input_embed::<f64>(),
+			r#macro::js_input_embed::<i32>(),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(address, expected) => {\n", (f64, i32), JsValue),
+		interpolate r#macro::js_parameter!("address", f64),
+		interpolate r#macro::js_parameter!("expected", i32),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['atomics.wait_async']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['atomics.wait_async'](address, expected)",
+			),
+			JsValue,
+			f64,
+			i32,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("atomics_wait_async")]
+		fn atomics_wait_async(
+			address: <f64 as Input>::Type,
+			expected: <i32 as Input>::Type,
+		) -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe {
+		atomics_wait_async(Input::into_raw(address), Input::into_raw(expected))
+	})
+}
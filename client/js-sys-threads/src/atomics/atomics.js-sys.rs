@@ -0,0 +1,10 @@
+use js_sys::JsValue;
+
+#[js_sys]
+extern "js-sys" {
+	#[js_sys(js_embed = "atomics.can_block")]
+	pub(super) fn atomics_can_block() -> bool;
+
+	#[js_sys(js_embed = "atomics.wait_async")]
+	pub(super) fn atomics_wait_async(address: f64, expected: i32) -> JsValue;
+}
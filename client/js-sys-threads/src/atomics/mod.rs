@@ -0,0 +1,98 @@
+//! Waiting on and waking threads via `Atomics`.
+
+#[rustfmt::skip]
+#[path ="atomics.gen.rs"]
+mod atomics;
+
+#[cfg(target_arch = "wasm32")]
+use core::arch::wasm32 as wasm;
+#[cfg(target_arch = "wasm64")]
+use core::arch::wasm64 as wasm;
+use core::cell::Cell;
+use core::hint;
+use core::sync::atomic::AtomicI32;
+
+use js_sys::{JsValue, js_bindgen};
+
+#[thread_local]
+static CAN_BLOCK: Cell<Option<bool>> = Cell::new(None);
+
+/// Returns `true` if the current thread is allowed to block.
+///
+/// Browsers don't allow blocking the main thread, in which case
+/// `memory.atomic.wait32` would trap.
+#[must_use]
+pub fn can_block() -> bool {
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "atomics.can_block",
+		"() => {{",
+		"	try {{",
+		"		Atomics.wait(new Int32Array(new SharedArrayBuffer(4)), 0, 1, 0)",
+		"		return true",
+		"	}} catch {{",
+		"		return false",
+		"	}}",
+		"}}",
+	);
+
+	if let Some(can_block) = CAN_BLOCK.get() {
+		can_block
+	} else {
+		let can_block = atomics::atomics_can_block();
+		CAN_BLOCK.set(Some(can_block));
+		can_block
+	}
+}
+
+/// Blocks the current thread until woken by [`notify()`] if `atomic` still
+/// holds `expected`.
+///
+/// Returns immediately if the current thread is not allowed to block, see
+/// [`can_block()`], letting the caller spin instead. Like
+/// `memory.atomic.wait32` this may return spuriously, callers have to check
+/// their condition again.
+pub fn wait(atomic: &AtomicI32, expected: i32) {
+	if can_block() {
+		// SAFETY: Valid pointer.
+		unsafe { wasm::memory_atomic_wait32(atomic.as_ptr(), expected, -1) };
+	} else {
+		hint::spin_loop();
+	}
+}
+
+/// Calls `Atomics.waitAsync()` on `atomic`, which is allowed on the main
+/// thread as well.
+///
+/// Returns a `Promise` resolving to `"ok"` when woken by [`notify()`], or
+/// immediately to `"not-equal"` if `atomic` didn't hold `expected`.
+/// `Atomics.waitAsync()` itself returns the latter synchronously as a string.
+pub fn wait_async(atomic: &AtomicI32, expected: i32) -> JsValue {
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "atomics.wait_async",
+		"(address, expected) => {{",
+		"	const view = new Int32Array(this.#memory.buffer)",
+		"	return Promise.resolve(Atomics.waitAsync(view, address / 4, expected).value)",
+		"}}",
+	);
+
+	atomics::atomics_wait_async(addr(atomic), expected)
+}
+
+/// Wakes up to `count` threads waiting on `atomic`, including those waiting
+/// via [`wait_async()`]. Returns the number of woken threads.
+///
+/// Equivalent to `Atomics.notify()`.
+pub fn notify(atomic: &AtomicI32, count: u32) -> u32 {
+	// SAFETY: Valid pointer.
+	unsafe { wasm::memory_atomic_notify(atomic.as_ptr(), count) }
+}
+
+#[expect(
+	clippy::cast_precision_loss,
+	reason = "addresses don't exceed `Number.MAX_SAFE_INTEGER`"
+)]
+pub(crate) fn addr<T>(ptr: *const T) -> f64 {
+	ptr.addr() as f64
+}
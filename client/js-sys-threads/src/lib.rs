@@ -0,0 +1,19 @@
+#![no_std]
+#![cfg_attr(target_arch = "wasm64", feature(simd_wasm64))]
+#![cfg_attr(
+	target_feature = "atomics",
+	feature(stdarch_wasm_atomic_wait, thread_local)
+)]
+
+#[cfg(target_feature = "atomics")]
+extern crate alloc;
+
+#[cfg(target_feature = "atomics")]
+pub mod atomics;
+#[cfg(target_feature = "atomics")]
+pub mod sync;
+#[cfg(target_feature = "atomics")]
+mod thread;
+
+#[cfg(target_feature = "atomics")]
+pub use crate::thread::{JoinHandle, spawn};
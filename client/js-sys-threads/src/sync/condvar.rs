@@ -0,0 +1,87 @@
+use core::fmt::{self, Debug, Formatter};
+use core::mem;
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use js_sys::JsValue;
+
+use super::MutexGuard;
+use crate::atomics;
+
+/// A condition variable, used together with a [`Mutex`](super::Mutex).
+///
+/// Like all condition variables, waiting may return spuriously, callers have
+/// to check their condition again.
+#[derive(Default)]
+pub struct Condvar {
+	/// Incremented on every notification.
+	counter: AtomicI32,
+}
+
+impl Condvar {
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			counter: AtomicI32::new(0),
+		}
+	}
+
+	/// Releases the lock of `guard` and blocks the current thread until
+	/// notified, then re-acquires the lock.
+	pub fn wait<'a, T: ?Sized>(&self, guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+		let counter = self.counter.load(Ordering::Relaxed);
+		let mutex = guard.mutex;
+		mem::drop(guard);
+
+		// Notifications after unlocking change the counter, so they aren't missed.
+		atomics::wait(&self.counter, counter);
+
+		mutex.lock()
+	}
+
+	/// Releases the lock of `guard` and returns a `Promise` resolving once
+	/// notified, after which the lock has to be re-acquired.
+	///
+	/// Unlike [`Condvar::wait()`] this doesn't block, so workers can start on
+	/// the main thread of browsers.
+	#[must_use]
+	pub fn wait_async<T: ?Sized>(&self, guard: MutexGuard<'_, T>) -> JsValue {
+		let counter = self.counter.load(Ordering::Relaxed);
+		mem::drop(guard);
+
+		atomics::wait_async(&self.counter, counter)
+	}
+
+	/// Blocks like [`Condvar::wait()`] while `condition` returns `true`.
+	pub fn wait_while<'a, T: ?Sized, F>(
+		&self,
+		mut guard: MutexGuard<'a, T>,
+		mut condition: F,
+	) -> MutexGuard<'a, T>
+	where
+		F: FnMut(&mut T) -> bool,
+	{
+		while condition(&mut guard) {
+			guard = self.wait(guard);
+		}
+
+		guard
+	}
+
+	/// Wakes up one thread blocked on this condition variable.
+	pub fn notify_one(&self) {
+		self.counter.fetch_add(1, Ordering::Relaxed);
+		atomics::notify(&self.counter, 1);
+	}
+
+	/// Wakes up all threads blocked on this condition variable.
+	pub fn notify_all(&self) {
+		self.counter.fetch_add(1, Ordering::Relaxed);
+		atomics::notify(&self.counter, u32::MAX);
+	}
+}
+
+impl Debug for Condvar {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Condvar").finish_non_exhaustive()
+	}
+}
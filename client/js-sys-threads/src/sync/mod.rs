@@ -0,0 +1,10 @@
+//! Synchronization primitives based on [`atomics`](crate::atomics).
+//!
+//! Threads that are not allowed to block, see
+//! [`can_block()`](crate::atomics::can_block), spin instead.
+
+mod condvar;
+mod mutex;
+
+pub use self::condvar::Condvar;
+pub use self::mutex::{Mutex, MutexGuard};
@@ -0,0 +1,160 @@
+use core::cell::UnsafeCell;
+use core::fmt::{self, Debug, Formatter};
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use js_sys::JsValue;
+
+use crate::atomics;
+
+const UNLOCKED: i32 = 0;
+const LOCKED: i32 = 1;
+/// Locked with other threads waiting.
+const CONTENDED: i32 = 2;
+
+/// A mutual exclusion primitive.
+///
+/// Panics abort in Wasm, so unlike [`std::sync::Mutex`] this does not
+/// implement poisoning.
+///
+/// [`std::sync::Mutex`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html
+pub struct Mutex<T: ?Sized> {
+	state: AtomicI32,
+	value: UnsafeCell<T>,
+}
+
+// SAFETY: Access to the value is synchronized.
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+
+// SAFETY: Access to the value is synchronized.
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+	#[must_use]
+	pub const fn new(value: T) -> Self {
+		Self {
+			state: AtomicI32::new(UNLOCKED),
+			value: UnsafeCell::new(value),
+		}
+	}
+
+	pub fn into_inner(self) -> T {
+		self.value.into_inner()
+	}
+}
+
+impl<T: ?Sized> Mutex<T> {
+	/// Acquires the lock, blocking the current thread until it is available.
+	///
+	/// Spins instead on threads that are not allowed to block, see
+	/// [`atomics::can_block()`], which only lasts as long as the thread holding
+	/// the lock.
+	pub fn lock(&self) -> MutexGuard<'_, T> {
+		if self
+			.state
+			.compare_exchange(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+			.is_err()
+		{
+			// Mark the lock as contended so the holder wakes us when unlocking.
+			while self.state.swap(CONTENDED, Ordering::Acquire) != UNLOCKED {
+				atomics::wait(&self.state, CONTENDED);
+			}
+		}
+
+		MutexGuard { mutex: self }
+	}
+
+	/// Returns [`None`] if the lock is currently held.
+	pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+		self.state
+			.compare_exchange(UNLOCKED, LOCKED, Ordering::Acquire, Ordering::Relaxed)
+			.ok()
+			.map(|_| MutexGuard { mutex: self })
+	}
+
+	/// Returns a `Promise` resolving once the lock was released, after which
+	/// [`Mutex::try_lock()`] can be retried. Resolves immediately if the lock
+	/// is not held.
+	#[must_use]
+	pub fn wait_async(&self) -> JsValue {
+		// Mark the lock as contended so the holder wakes us when unlocking.
+		let _ =
+			self.state
+				.compare_exchange(LOCKED, CONTENDED, Ordering::Relaxed, Ordering::Relaxed);
+
+		atomics::wait_async(&self.state, CONTENDED)
+	}
+
+	pub fn get_mut(&mut self) -> &mut T {
+		self.value.get_mut()
+	}
+
+	fn unlock(&self) {
+		if self.state.swap(UNLOCKED, Ordering::Release) == CONTENDED {
+			atomics::notify(&self.state, 1);
+		}
+	}
+}
+
+impl<T: Default> Default for Mutex<T> {
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T> From<T> for Mutex<T> {
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<T: ?Sized + Debug> Debug for Mutex<T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		let mut debug = f.debug_struct("Mutex");
+
+		if let Some(guard) = self.try_lock() {
+			debug.field("data", &&*guard);
+		} else {
+			debug.field("data", &format_args!("<locked>"));
+		}
+
+		debug.finish_non_exhaustive()
+	}
+}
+
+/// Releases the lock of the [`Mutex`] when dropped.
+#[must_use = "if unused the `Mutex` will immediately unlock"]
+pub struct MutexGuard<'a, T: ?Sized> {
+	pub(super) mutex: &'a Mutex<T>,
+}
+
+// SAFETY: Access to the value is synchronized.
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		// SAFETY: The lock is held.
+		unsafe { &*self.mutex.value.get() }
+	}
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		// SAFETY: The lock is held.
+		unsafe { &mut *self.mutex.value.get() }
+	}
+}
+
+impl<T: ?Sized + Debug> Debug for MutexGuard<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		Debug::fmt(&**self, f)
+	}
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+	fn drop(&mut self) {
+		self.mutex.unlock();
+	}
+}
@@ -0,0 +1,258 @@
+#[rustfmt::skip]
+#[path ="thread.gen.rs"]
+mod thread;
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error};
+use alloc::boxed::Box;
+use core::alloc::Layout;
+use core::cell::UnsafeCell;
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use js_sys::{JsValue, js_bindgen};
+
+use crate::atomics::{self, addr};
+
+// Must match the worker bootstrap emitted by the linker.
+const RUNNING: i32 = 0;
+const FINISHED: i32 = 1;
+const PANICKED: i32 = 2;
+
+/// The stack of every spawned thread. The Wasm C ABI requires a 16 byte
+/// alignment.
+#[repr(C, align(16))]
+struct Stack([u8; 1024 * 1024]);
+
+const STACK_LAYOUT: Layout = Layout::new::<Stack>();
+
+type Main = Box<dyn FnOnce() + Send>;
+
+/// Shared between the [`JoinHandle`] and the spawned thread.
+struct Packet<T> {
+	/// Only written by the worker bootstrap after the thread has stopped
+	/// executing.
+	state: AtomicI32,
+	result: UnsafeCell<Option<T>>,
+}
+
+struct SendPacket<T>(NonNull<Packet<T>>);
+
+// SAFETY: Only used to send the result to the `JoinHandle`.
+unsafe impl<T: Send> Send for SendPacket<T> {}
+
+impl<T> SendPacket<T> {
+	/// # Safety
+	///
+	/// Must only be called once by the spawned thread.
+	unsafe fn write(&self, value: T) {
+		// SAFETY: The `JoinHandle` only accesses the result after the thread has
+		// finished.
+		unsafe { *self.0.as_ref().result.get() = Some(value) };
+	}
+}
+
+/// Spawns a new thread in a Web Worker, returning a [`JoinHandle`] for it.
+///
+/// The worker instantiates the same module with the shared
+/// `WebAssembly.Memory` and sets up its own stack, thread-local storage and
+/// `externref` table before calling `f`.
+///
+/// Dropping the [`JoinHandle`] detaches the thread.
+///
+/// # Panics
+///
+/// If the stack could not be allocated.
+pub fn spawn<F, T>(f: F) -> JoinHandle<T>
+where
+	F: FnOnce() -> T + Send + 'static,
+	T: Send + 'static,
+{
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "thread.spawn",
+		"(stack, context, state) => {{",
+		"	const worker = new Worker(import.meta.url, {{ type: 'module' }})",
+		"	worker.postMessage({{",
+		"		jsBindgenThread: {{",
+		"			module: this.#module,",
+		"			memory: this.#memory,",
+		"			stack,",
+		"			context,",
+		"			state,",
+		"			run: '{run}',",
+		"		}},",
+		"	}})",
+		"}}",
+		run = interpolate js_bindgen::namespace!("run"),
+	);
+
+	let packet = NonNull::from(Box::leak(Box::new(Packet {
+		state: AtomicI32::new(RUNNING),
+		result: UnsafeCell::new(None),
+	})));
+	let result = SendPacket(packet);
+	let main: Main = Box::new(move || {
+		let value = f();
+		// SAFETY: Only called once.
+		unsafe { result.write(value) };
+	});
+	let context = Box::into_raw(Box::new(main));
+
+	// SAFETY: `STACK_LAYOUT` has a non-zero size.
+	let stack = unsafe { alloc(STACK_LAYOUT) };
+	let Some(stack) = NonNull::new(stack) else {
+		handle_alloc_error(STACK_LAYOUT)
+	};
+
+	// SAFETY: `packet` is valid.
+	let state = unsafe { packet.as_ref() }.state.as_ptr();
+	// The stack grows downwards.
+	thread::thread_spawn(
+		addr(stack.as_ptr().wrapping_add(STACK_LAYOUT.size())),
+		addr(context),
+		addr(state),
+	);
+
+	JoinHandle {
+		stack,
+		packet,
+		awaited: false,
+	}
+}
+
+/// Called by the worker bootstrap.
+#[unsafe(export_name = js_bindgen::namespace!("run"))]
+extern "C" fn run(context: *mut Main, tls_size: usize, tls_align: usize) {
+	unsafe extern "C" {
+		fn __wasm_init_tls(memory: *mut u8);
+	}
+
+	let layout = Layout::from_size_align(tls_size, tls_align).unwrap();
+	let tls = if layout.size() == 0 {
+		NonNull::<u8>::dangling().as_ptr()
+	} else {
+		// SAFETY: Non-zero size.
+		let tls = unsafe { alloc(layout) };
+
+		if tls.is_null() {
+			handle_alloc_error(layout)
+		}
+
+		tls
+	};
+
+	// SAFETY: Valid allocation of the requested layout.
+	unsafe { __wasm_init_tls(tls) };
+
+	// SAFETY: Passed by `spawn()`.
+	let main = unsafe { Box::from_raw(context) };
+	main();
+
+	// Heap allocations owned by thread-local storage, e.g. the `externref`
+	// table, are leaked.
+	if layout.size() != 0 {
+		// SAFETY: Allocated above.
+		unsafe { dealloc(tls, layout) };
+	}
+}
+
+/// An owned permission to join on a thread.
+pub struct JoinHandle<T> {
+	stack: NonNull<u8>,
+	packet: NonNull<Packet<T>>,
+	/// The `Promise` returned by [`JoinHandle::wait_async()`] might still
+	/// read the state of the packet.
+	awaited: bool,
+}
+
+// SAFETY: The packet is only accessed after the thread has finished.
+unsafe impl<T: Send> Send for JoinHandle<T> {}
+
+impl<T> JoinHandle<T> {
+	/// Blocks until the thread has finished and returns its result.
+	///
+	/// Spins instead on threads that are not allowed to block, see
+	/// [`atomics::can_block()`]. Note that some browsers only start workers
+	/// when control returns to the event loop of the main thread, use
+	/// [`JoinHandle::wait_async()`] there.
+	///
+	/// # Panics
+	///
+	/// If the spawned thread panicked.
+	#[expect(
+		clippy::must_use_candidate,
+		reason = "also useful to wait for the thread"
+	)]
+	pub fn join(self) -> T {
+		let state = &self.packet().state;
+
+		loop {
+			match state.load(Ordering::Acquire) {
+				RUNNING => atomics::wait(state, RUNNING),
+				FINISHED => break,
+				PANICKED => panic!("spawned thread panicked"),
+				state => unreachable!("found invalid thread state: {state}"),
+			}
+		}
+
+		// SAFETY: The thread has finished.
+		unsafe { (*self.packet().result.get()).take() }.expect("found no result")
+	}
+
+	/// Returns a `Promise` resolving once the thread has finished, after which
+	/// [`JoinHandle::join()`] returns without blocking. Rejects if the thread
+	/// panicked.
+	///
+	/// Unlike [`JoinHandle::join()`] this doesn't block, so workers can start
+	/// on the main thread of browsers.
+	#[must_use]
+	pub fn wait_async(&mut self) -> JsValue {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "thread.wait_async",
+			"async (state) => {{",
+			"	const view = new Int32Array(this.#memory.buffer)",
+			"	let current",
+			"",
+			"	// Must match `RUNNING` and `PANICKED`.",
+			"	while ((current = Atomics.load(view, state / 4)) === 0) {{",
+			"		await Atomics.waitAsync(view, state / 4, 0).value",
+			"	}}",
+			"",
+			"	if (current === 2) {{",
+			"		throw new Error('spawned thread panicked')",
+			"	}}",
+			"}}",
+		);
+
+		self.awaited = true;
+		thread::thread_wait_async(addr(self.packet().state.as_ptr()))
+	}
+
+	/// Returns `true` if the thread has finished.
+	#[must_use]
+	pub fn is_finished(&self) -> bool {
+		self.packet().state.load(Ordering::Acquire) != RUNNING
+	}
+
+	fn packet(&self) -> &Packet<T> {
+		// SAFETY: Valid until dropped.
+		unsafe { self.packet.as_ref() }
+	}
+}
+
+impl<T> Drop for JoinHandle<T> {
+	fn drop(&mut self) {
+		// The stack and packet are still in use by a running thread and leaked
+		// instead. The packet is leaked as well if a `Promise` might read it.
+		if self.is_finished() {
+			// SAFETY: Allocated in `spawn()` and not used anymore.
+			unsafe { dealloc(self.stack.as_ptr(), STACK_LAYOUT) };
+
+			if !self.awaited {
+				// SAFETY: Allocated in `spawn()` and not used anymore.
+				drop(unsafe { Box::from_raw(self.packet.as_ptr()) });
+			}
+		}
+	}
+}
@@ -0,0 +1,103 @@
+//! This file was generated by `js-sys-bindgen`.
+
+#![allow(warnings)]
+
+use js_sys::{js_bindgen, r#macro};
+use js_sys::hazard::{Input, Output};
+use js_sys::JsValue;
+
+pub(super) fn thread_spawn(stack: f64, context: f64, state: f64) {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"thread_spawn\" (func ${ns}.import.thread_spawn (@sym (name \"{ns}.import.thread_spawn\")) (param {} {} {}))){}",
+		"(func ${ns}.thread_spawn (@sym) (param $stack {}) (param $context {}) (param $state {})",
+		"  local.get $stack{}", "  local.get $context{}", "  local.get $state{}",
+		"  call ${ns}.import.thread_spawn (@reloc)", ")", ns = interpolate js_bindgen::namespace!(),
+		interpolate r#macro::wat_input_import_type:: < f64 > (), interpolate
+		r#macro::wat_input_import_type:: < f64 > (), interpolate r#macro::wat_input_import_type:: <
+		f64 > (), interpolate r#macro::wat_imports!((f64),), interpolate < f64 as Input >
+		::WAT_TYPE, interpolate < f64 as Input > ::WAT_TYPE, interpolate < f64 as Input >
+		::WAT_TYPE, interpolate r#macro::wat_input!(f64), interpolate r#macro::wat_input!(f64),
+		interpolate r#macro::wat_input!(f64),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "thread_spawn",
+		required_embeds = [
+			(js_bindgen::namespace!(), "thread.spawn"),
+			r#macro::js_input_embed::<f64>(),
+		],
+		"{}{}{}{}{}",
+		interpolate r#macro::js_select!("", "(stack, context, state) => {\n", (f64)),
+		interpolate r#macro::js_parameter!("stack", f64),
+		interpolate r#macro::js_parameter!("context", f64),
+		interpolate r#macro::js_parameter!("state", f64),
+		interpolate r#macro::js_select!(
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['thread.spawn']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['thread.spawn'](stack, context, state)\n}",
+			),
+			(f64),
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("thread_spawn")]
+		fn thread_spawn(
+			stack: <f64 as Input>::Type,
+			context: <f64 as Input>::Type,
+			state: <f64 as Input>::Type,
+		);
+	}
+
+	unsafe {
+		thread_spawn(Input::into_raw(stack), Input::into_raw(context), Input::into_raw(state))
+	};
+}
+
+#[track_caller]
+pub(super) fn thread_wait_async(state: f64) -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"thread_wait_async\" (func ${ns}.import.thread_wait_async (@sym (name \"{ns}.import.thread_wait_async\")) (param {}) (result {}))){}",
+		"(func ${ns}.thread_wait_async (@sym) (param {}) (param $state {}) (result {})",
+		"  local.get $state{}", "  call ${ns}.import.thread_wait_async (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < f64 >
+		(), interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+		r#macro::wat_imports!((f64), JsValue), interpolate r#macro::wat_indirect!(JsValue),
+		interpolate < f64 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsValue > (),
+		interpolate r#macro::wat_input!(f64), interpolate r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "thread_wait_async",
+		required_embeds = [
+			(js_bindgen::namespace!(), "thread.wait_async"),
+			r#macro::js_input_embed::<f64>(),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(state) => {\n", (f64), JsValue),
+		interpolate r#macro::js_parameter!("state", f64),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['thread.wait_async']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['thread.wait_async'](state)",
+			),
+			JsValue,
+			f64,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("thread_wait_async")]
+		fn thread_wait_async(state: <f64 as Input>::Type) -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { thread_wait_async(Input::into_raw(state)) })
+}
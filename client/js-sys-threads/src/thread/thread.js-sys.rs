@@ -0,0 +1,10 @@
+use js_sys::JsValue;
+
+#[js_sys]
+extern "js-sys" {
+	#[js_sys(js_embed = "thread.spawn")]
+	pub(super) fn thread_spawn(stack: f64, context: f64, state: f64);
+
+	#[js_sys(js_embed = "thread.wait_async")]
+	pub(super) fn thread_wait_async(state: f64) -> JsValue;
+}
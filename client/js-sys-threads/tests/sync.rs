@@ -0,0 +1,112 @@
+#![cfg(target_feature = "atomics")]
+
+mod util;
+
+use std::ptr;
+use std::sync::Arc;
+
+use js_bindgen_test::test;
+use js_sys::{JsValue, js_bindgen, js_sys};
+use js_sys_threads::sync::{Condvar, Mutex};
+
+use self::util::spawn_blocking;
+
+#[js_sys]
+extern "js-sys" {
+	/// Returns a `Promise` rejecting if the `bool` at `address` isn't `true`
+	/// once `promise` resolved.
+	#[js_sys(js_embed = "assert_set")]
+	fn assert_set(promise: &JsValue, address: f64) -> JsValue;
+}
+
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "assert_set",
+	"(promise, address) => promise.then(() => {{",
+	"	if (new Uint8Array(this.#memory.buffer)[address] !== 1)",
+	"		throw new Error('woken up before being notified')",
+	"}})",
+);
+
+#[test]
+fn mutex() {
+	spawn_blocking(|| {
+		let mutex = Arc::new(Mutex::new(0));
+		let handles: Vec<_> = (0..4)
+			.map(|_| {
+				let mutex = Arc::clone(&mutex);
+				js_sys_threads::spawn(move || {
+					for _ in 0..100 {
+						*mutex.lock() += 1;
+					}
+				})
+			})
+			.collect();
+
+		for handle in handles {
+			handle.join();
+		}
+
+		assert_eq!(*mutex.lock(), 400);
+		assert!(mutex.try_lock().is_some());
+	});
+}
+
+#[test]
+fn try_lock() {
+	let mutex = Mutex::new(());
+	let guard = mutex.lock();
+	assert!(mutex.try_lock().is_none());
+	drop(guard);
+	assert!(mutex.try_lock().is_some());
+}
+
+#[test]
+fn condvar() {
+	spawn_blocking(|| {
+		let pair = Arc::new((Mutex::new(false), Condvar::new()));
+		let handle = js_sys_threads::spawn({
+			let pair = Arc::clone(&pair);
+			move || {
+				let (mutex, condvar) = &*pair;
+				*mutex.lock() = true;
+				condvar.notify_one();
+			}
+		});
+
+		let (mutex, condvar) = &*pair;
+		let guard = condvar.wait_while(mutex.lock(), |ready| !*ready);
+		assert!(*guard);
+		drop(guard);
+
+		handle.join();
+	});
+}
+
+#[test]
+fn condvar_wait_async() {
+	// Leaked, because JS accesses it after this test returned.
+	let (mutex, condvar) = Box::leak(Box::new((Mutex::new(false), Condvar::new())));
+	let guard = mutex.lock();
+	let address = f64::from(u32::try_from(ptr::from_ref::<bool>(&guard).addr()).unwrap());
+	let promise = condvar.wait_async(guard);
+
+	js_sys_threads::spawn(|| {
+		*mutex.lock() = true;
+		condvar.notify_one();
+	});
+
+	// The guarded value can only be checked after the wake-up, which happens
+	// once this test returned.
+	js_bindgen_test::wait_for(&assert_set(&promise, address));
+}
+
+#[test]
+fn mutex_wait_async() {
+	let mutex = Mutex::new(());
+	let guard = mutex.lock();
+	let promise = mutex.wait_async();
+	drop(guard);
+	js_bindgen_test::wait_for(&promise);
+	assert!(mutex.try_lock().is_some());
+}
@@ -0,0 +1,28 @@
+#![cfg(target_feature = "atomics")]
+
+mod util;
+
+use js_bindgen_test::test;
+
+use self::util::spawn_blocking;
+
+#[test]
+fn spawn_join() {
+	spawn_blocking(|| {
+		let handle = js_sys_threads::spawn(|| 1 + 1);
+		assert_eq!(handle.join(), 2);
+	});
+}
+
+#[test]
+fn wait_async() {
+	let mut handle = js_sys_threads::spawn(|| 1 + 1);
+	js_bindgen_test::wait_for(&handle.wait_async());
+}
+
+#[test]
+#[should_panic = "spawned thread panicked"]
+fn spawn_panic() {
+	let mut handle = js_sys_threads::spawn(|| -> i32 { panic!("test") });
+	js_bindgen_test::wait_for(&handle.wait_async());
+}
@@ -0,0 +1,5 @@
+/// Browsers only start workers once the main thread returns to the event
+/// loop, so only spawned threads block.
+pub fn spawn_blocking(f: impl FnOnce() + Send + 'static) {
+	js_bindgen_test::wait_for(&js_sys_threads::spawn(f).wait_async());
+}
@@ -3,6 +3,7 @@
 #![allow(warnings)]
 
 use core::marker::PhantomData;
+use core::fmt;
 use crate::{js_bindgen, r#macro, JsValue};
 use crate::hazard::{InputJsConv, OutputJsConv, OutputWatConv, Input, InputWatConv, Output, JsCast};
 use crate::util::{PtrConst, PtrLength, PtrMut};
@@ -54,21 +55,28 @@ unsafe impl<T> Output for JsArray<T> {
 	}
 }
 
+impl<T> fmt::Debug for JsArray<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.value, f)
+	}
+}
+
 impl<T> JsArray<T> {
+	#[track_caller]
 	pub fn length(self: &JsArray<T>) -> u32 {
 		js_bindgen::unsafe_global_wat! {
-			"(import \"js_sys\" \"length\" (func $js_sys.import.length (@sym (name \"js_sys.import.length\")) (param {}) (result {}))){}",
-			"(func $js_sys.length (@sym) (param {}) (param $self {}) (result {})",
-			"  local.get $self{}", "  call $js_sys.import.length (@reloc){}", ")", interpolate
-			r#macro::wat_input_import_type:: < & JsValue > (), interpolate
-			r#macro::wat_output_import_type:: < u32 > (), interpolate r#macro::wat_imports!((&
-			JsValue), u32), interpolate r#macro::wat_indirect!(u32), interpolate < & JsValue as
-			Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < u32 > (), interpolate
-			r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(u32),
+			"(import \"{ns}\" \"length\" (func ${ns}.import.length (@sym (name \"{ns}.import.length\")) (param {}) (result {}))){}",
+			"(func ${ns}.length (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.length (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_output_import_type:: < u32 > (), interpolate
+			r#macro::wat_imports!((& JsValue), u32), interpolate r#macro::wat_indirect!(u32),
+			interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < u32 >
+			(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(u32),
 		}
 
 		js_bindgen::import_js! {
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "length",
 			required_embeds = [
 				r#macro::js_input_embed::<&JsValue>(),
@@ -87,7 +95,7 @@ impl<T> JsArray<T> {
 		}
 
 		unsafe extern "C" {
-			#[link_name = "js_sys.length"]
+			#[link_name = js_bindgen::namespace!("length")]
 			fn length(this: <&JsValue as Input>::Type) -> <u32 as Output>::Type;
 		}
 
@@ -95,18 +103,532 @@ impl<T> JsArray<T> {
 	}
 }
 
+#[track_caller]
+pub(super) fn array_get(array: &JsArray, index: u32) -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"array_get\" (func ${ns}.import.array_get (@sym (name \"{ns}.import.array_get\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.array_get (@sym) (param {}) (param $array {}) (param $index {}) (result {})",
+		"  local.get $array{}", "  local.get $index{}", "  call ${ns}.import.array_get (@reloc){}",
+		")", ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type::
+		< & JsArray > (), interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+		r#macro::wat_output_import_type:: < JsValue > (), interpolate r#macro::wat_imports!((&
+		JsArray, u32), JsValue), interpolate r#macro::wat_indirect!(JsValue), interpolate < &
+		JsArray as Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE, interpolate
+		r#macro::wat_direct:: < JsValue > (), interpolate r#macro::wat_input!(& JsArray),
+		interpolate r#macro::wat_input!(u32), interpolate r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "array_get",
+		required_embeds = [
+			(js_bindgen::namespace!(), "array.get"),
+			r#macro::js_input_embed::<&JsArray>(),
+			r#macro::js_input_embed::<u32>(),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(array, index) => {\n", (&JsArray, u32), JsValue),
+		interpolate r#macro::js_parameter!("array", &JsArray),
+		interpolate r#macro::js_parameter!("index", u32),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['array.get']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['array.get'](array, index)",
+			),
+			JsValue,
+			&JsArray,
+			u32,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("array_get")]
+		fn array_get(
+			array: <&JsArray as Input>::Type,
+			index: <u32 as Input>::Type,
+		) -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { array_get(Input::into_raw(array), Input::into_raw(index)) })
+}
+
+pub(super) fn array_set(array: &JsArray, index: u32, value: &JsValue) {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"array_set\" (func ${ns}.import.array_set (@sym (name \"{ns}.import.array_set\")) (param {} {} {}))){}",
+		"(func ${ns}.array_set (@sym) (param $array {}) (param $index {}) (param $value {})",
+		"  local.get $array{}", "  local.get $index{}", "  local.get $value{}",
+		"  call ${ns}.import.array_set (@reloc)", ")", ns = interpolate js_bindgen::namespace!(),
+		interpolate r#macro::wat_input_import_type:: < & JsArray > (), interpolate
+		r#macro::wat_input_import_type:: < u32 > (), interpolate r#macro::wat_input_import_type:: <
+		& JsValue > (), interpolate r#macro::wat_imports!((& JsArray, u32, & JsValue),), interpolate
+		< & JsArray as Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE, interpolate < &
+		JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_input!(& JsArray), interpolate
+		r#macro::wat_input!(u32), interpolate r#macro::wat_input!(& JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "array_set",
+		required_embeds = [
+			(js_bindgen::namespace!(), "array.set"),
+			r#macro::js_input_embed::<&JsArray>(),
+			r#macro::js_input_embed::<u32>(),
+			r#macro::js_input_embed::<&JsValue>(),
+		],
+		"{}{}{}{}{}",
+		interpolate r#macro::js_select!(
+			"",
+			"(array, index, value) => {\n",
+			(&JsArray, u32, &JsValue),
+		),
+		interpolate r#macro::js_parameter!("array", &JsArray),
+		interpolate r#macro::js_parameter!("index", u32),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_select!(
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['array.set']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['array.set'](array, index, value)\n}",
+			),
+			(&JsArray, u32, &JsValue),
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("array_set")]
+		fn array_set(
+			array: <&JsArray as Input>::Type,
+			index: <u32 as Input>::Type,
+			value: <&JsValue as Input>::Type,
+		);
+	}
+
+	unsafe { array_set(Input::into_raw(array), Input::into_raw(index), Input::into_raw(value)) };
+}
+
+impl JsArray {
+	#[track_caller]
+	pub(super) fn array_push(self: &JsArray, value: &JsValue) -> u32 {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"array_push\" (func ${ns}.import.array_push (@sym (name \"{ns}.import.array_push\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.array_push (@sym) (param {}) (param $self {}) (param $value {}) (result {})",
+			"  local.get $self{}", "  local.get $value{}",
+			"  call ${ns}.import.array_push (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & JsValue > (), interpolate
+			r#macro::wat_output_import_type:: < u32 > (), interpolate r#macro::wat_imports!((&
+			JsValue), u32), interpolate r#macro::wat_indirect!(u32), interpolate < & JsValue as
+			Input > ::WAT_TYPE, interpolate < & JsValue as Input > ::WAT_TYPE, interpolate
+			r#macro::wat_direct:: < u32 > (), interpolate r#macro::wat_input!(& JsValue),
+			interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(u32),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "array_push",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<u32>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, value) => ",
+				"(self, value) => {\n",
+				(&JsValue),
+				u32,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("value", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.push(value)",
+				"self.push(value)",
+				u32,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("array_push")]
+			fn array_push(
+				this: <&JsValue as Input>::Type,
+				value: <&JsValue as Input>::Type,
+			) -> <u32 as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { array_push(Input::into_raw(self), Input::into_raw(value)) })
+	}
+}
+
+impl JsArray {
+	#[track_caller]
+	pub(super) fn array_pop(self: &JsArray) -> JsValue {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"array_pop\" (func ${ns}.import.array_pop (@sym (name \"{ns}.import.array_pop\")) (param {}) (result {}))){}",
+			"(func ${ns}.array_pop (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.array_pop (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+			r#macro::wat_imports!((& JsValue), JsValue), interpolate
+			r#macro::wat_indirect!(JsValue), interpolate < & JsValue as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsValue > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_output!(JsValue),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "array_pop",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<JsValue>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), JsValue),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.pop()",
+				"self.pop()",
+				JsValue,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("array_pop")]
+			fn array_pop(this: <&JsValue as Input>::Type) -> <JsValue as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { array_pop(Input::into_raw(self)) })
+	}
+}
+
+#[track_caller]
+pub(super) fn array_splice(
+	array: &JsArray,
+	start: u32,
+	delete_count: u32,
+	items: &[JsValue],
+) -> JsArray {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"array_splice\" (func ${ns}.import.array_splice (@sym (name \"{ns}.import.array_splice\")) (param {} {} {} {}) (result {}))){}",
+		"(func ${ns}.array_splice (@sym) (param {}) (param $array {}) (param $start {}) (param $delete_count {}) (param $items {}) (result {})",
+		"  local.get $array{}", "  local.get $start{}", "  local.get $delete_count{}",
+		"  local.get $items{}", "  call ${ns}.import.array_splice (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsArray > (),
+		interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+		r#macro::wat_input_import_type:: < u32 > (), interpolate r#macro::wat_input_import_type:: <
+		& [JsValue] > (), interpolate r#macro::wat_output_import_type:: < JsArray > (), interpolate
+		r#macro::wat_imports!((& JsArray, u32, & [JsValue]), JsArray), interpolate
+		r#macro::wat_indirect!(JsArray), interpolate < & JsArray as Input > ::WAT_TYPE, interpolate
+		< u32 as Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE, interpolate < &
+		[JsValue] as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsArray > (),
+		interpolate r#macro::wat_input!(& JsArray), interpolate r#macro::wat_input!(u32),
+		interpolate r#macro::wat_input!(u32), interpolate r#macro::wat_input!(& [JsValue]),
+		interpolate r#macro::wat_output!(JsArray),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "array_splice",
+		required_embeds = [
+			(js_bindgen::namespace!(), "array.splice"),
+			r#macro::js_input_embed::<&JsArray>(),
+			r#macro::js_input_embed::<u32>(),
+			r#macro::js_input_embed::<&[JsValue]>(),
+			r#macro::js_output_embed::<JsArray>(),
+		],
+		"{}{}{}{}{}{}",
+		interpolate r#macro::js_select!(
+			"",
+			"(array, start, delete_count, items) => {\n",
+			(&JsArray, u32, &[JsValue]),
+			JsArray,
+		),
+		interpolate r#macro::js_parameter!("array", &JsArray),
+		interpolate r#macro::js_parameter!("start", u32),
+		interpolate r#macro::js_parameter!("delete_count", u32),
+		interpolate r#macro::js_parameter!("items", &[JsValue]),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['array.splice']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['array.splice'](array, start, delete_count, items)",
+			),
+			JsArray,
+			&JsArray,
+			u32,
+			&[JsValue],
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("array_splice")]
+		fn array_splice(
+			array: <&JsArray as Input>::Type,
+			start: <u32 as Input>::Type,
+			delete_count: <u32 as Input>::Type,
+			items: <&[JsValue] as Input>::Type,
+		) -> <JsArray as Output>::Type;
+	}
+
+	Output::from_raw(unsafe {
+		array_splice(
+			Input::into_raw(array),
+			Input::into_raw(start),
+			Input::into_raw(delete_count),
+			Input::into_raw(items),
+		)
+	})
+}
+
+impl JsArray {
+	#[track_caller]
+	pub(super) fn array_slice(self: &JsArray, start: u32, end: u32) -> JsArray {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"array_slice\" (func ${ns}.import.array_slice (@sym (name \"{ns}.import.array_slice\")) (param {} {} {}) (result {}))){}",
+			"(func ${ns}.array_slice (@sym) (param {}) (param $self {}) (param $start {}) (param $end {}) (result {})",
+			"  local.get $self{}", "  local.get $start{}", "  local.get $end{}",
+			"  call ${ns}.import.array_slice (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+			r#macro::wat_input_import_type:: < u32 > (), interpolate
+			r#macro::wat_output_import_type:: < JsArray > (), interpolate r#macro::wat_imports!((&
+			JsValue, u32), JsArray), interpolate r#macro::wat_indirect!(JsArray), interpolate < &
+			JsValue as Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE, interpolate <
+			u32 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsArray > (), interpolate
+			r#macro::wat_input!(& JsValue), interpolate r#macro::wat_input!(u32), interpolate
+			r#macro::wat_input!(u32), interpolate r#macro::wat_output!(JsArray),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "array_slice",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<u32>(),
+				r#macro::js_output_embed::<JsArray>(),
+			],
+			"{}{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, start, end) => ",
+				"(self, start, end) => {\n",
+				(&JsValue, u32),
+				JsArray,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("start", u32),
+			interpolate r#macro::js_parameter!("end", u32),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.slice(start, end)",
+				"self.slice(start, end)",
+				JsArray,
+				&JsValue,
+				u32,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("array_slice")]
+			fn array_slice(
+				this: <&JsValue as Input>::Type,
+				start: <u32 as Input>::Type,
+				end: <u32 as Input>::Type,
+			) -> <JsArray as Output>::Type;
+		}
+
+		Output::from_raw(unsafe {
+			array_slice(Input::into_raw(self), Input::into_raw(start), Input::into_raw(end))
+		})
+	}
+}
+
+impl JsArray {
+	#[track_caller]
+	pub(super) fn array_concat(self: &JsArray, other: &JsArray) -> JsArray {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"array_concat\" (func ${ns}.import.array_concat (@sym (name \"{ns}.import.array_concat\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.array_concat (@sym) (param {}) (param $self {}) (param $other {}) (result {})",
+			"  local.get $self{}", "  local.get $other{}",
+			"  call ${ns}.import.array_concat (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & JsArray > (), interpolate
+			r#macro::wat_output_import_type:: < JsArray > (), interpolate r#macro::wat_imports!((&
+			JsValue, & JsArray), JsArray), interpolate r#macro::wat_indirect!(JsArray), interpolate
+			< & JsValue as Input > ::WAT_TYPE, interpolate < & JsArray as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsArray > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_input!(& JsArray), interpolate
+			r#macro::wat_output!(JsArray),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "array_concat",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<&JsArray>(),
+				r#macro::js_output_embed::<JsArray>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, other) => ",
+				"(self, other) => {\n",
+				(&JsValue, &JsArray),
+				JsArray,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("other", &JsArray),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.concat(other)",
+				"self.concat(other)",
+				JsArray,
+				&JsValue,
+				&JsArray,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("array_concat")]
+			fn array_concat(
+				this: <&JsValue as Input>::Type,
+				other: <&JsArray as Input>::Type,
+			) -> <JsArray as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { array_concat(Input::into_raw(self), Input::into_raw(other)) })
+	}
+}
+
+impl JsArray {
+	#[track_caller]
+	pub(super) fn array_includes(self: &JsArray, value: &JsValue) -> bool {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"array_includes\" (func ${ns}.import.array_includes (@sym (name \"{ns}.import.array_includes\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.array_includes (@sym) (param {}) (param $self {}) (param $value {}) (result {})",
+			"  local.get $self{}", "  local.get $value{}",
+			"  call ${ns}.import.array_includes (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & JsValue > (), interpolate
+			r#macro::wat_output_import_type:: < bool > (), interpolate r#macro::wat_imports!((&
+			JsValue), bool), interpolate r#macro::wat_indirect!(bool), interpolate < & JsValue as
+			Input > ::WAT_TYPE, interpolate < & JsValue as Input > ::WAT_TYPE, interpolate
+			r#macro::wat_direct:: < bool > (), interpolate r#macro::wat_input!(& JsValue),
+			interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(bool),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "array_includes",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<bool>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, value) => ",
+				"(self, value) => {\n",
+				(&JsValue),
+				bool,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("value", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.includes(value)",
+				"self.includes(value)",
+				bool,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("array_includes")]
+			fn array_includes(
+				this: <&JsValue as Input>::Type,
+				value: <&JsValue as Input>::Type,
+			) -> <bool as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { array_includes(Input::into_raw(self), Input::into_raw(value)) })
+	}
+}
+
+impl JsArray {
+	#[track_caller]
+	pub(super) fn array_index_of(self: &JsArray, value: &JsValue) -> i32 {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"array_index_of\" (func ${ns}.import.array_index_of (@sym (name \"{ns}.import.array_index_of\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.array_index_of (@sym) (param {}) (param $self {}) (param $value {}) (result {})",
+			"  local.get $self{}", "  local.get $value{}",
+			"  call ${ns}.import.array_index_of (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & JsValue > (), interpolate
+			r#macro::wat_output_import_type:: < i32 > (), interpolate r#macro::wat_imports!((&
+			JsValue), i32), interpolate r#macro::wat_indirect!(i32), interpolate < & JsValue as
+			Input > ::WAT_TYPE, interpolate < & JsValue as Input > ::WAT_TYPE, interpolate
+			r#macro::wat_direct:: < i32 > (), interpolate r#macro::wat_input!(& JsValue),
+			interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(i32),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "array_index_of",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<i32>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, value) => ",
+				"(self, value) => {\n",
+				(&JsValue),
+				i32,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("value", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.indexOf(value)",
+				"self.indexOf(value)",
+				i32,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("array_index_of")]
+			fn array_index_of(
+				this: <&JsValue as Input>::Type,
+				value: <&JsValue as Input>::Type,
+			) -> <i32 as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { array_index_of(Input::into_raw(self), Input::into_raw(value)) })
+	}
+}
+
+#[track_caller]
 pub(super) unsafe fn array_js_value_decode(
 	array: PtrConst<JsValue>,
 	len: PtrLength<JsValue>,
 ) -> JsArray<JsValue> {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"array_js_value_decode\" (func $js_sys.import.array_js_value_decode (@sym (name \"js_sys.import.array_js_value_decode\")) (param {} {}) (result {}))){}",
-		"(func $js_sys.array_js_value_decode (@sym) (param {}) (param $array {}) (param $len {}) (result {})",
+		"(import \"{ns}\" \"array_js_value_decode\" (func ${ns}.import.array_js_value_decode (@sym (name \"{ns}.import.array_js_value_decode\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.array_js_value_decode (@sym) (param {}) (param $array {}) (param $len {}) (result {})",
 		"  local.get $array{}", "  local.get $len{}",
-		"  call $js_sys.import.array_js_value_decode (@reloc){}", ")", interpolate
-		r#macro::wat_input_import_type:: < PtrConst < JsValue > > (), interpolate
-		r#macro::wat_input_import_type:: < PtrLength < JsValue > > (), interpolate
-		r#macro::wat_output_import_type:: < JsArray < JsValue > > (), interpolate
+		"  call ${ns}.import.array_js_value_decode (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < PtrConst < JsValue
+		> > (), interpolate r#macro::wat_input_import_type:: < PtrLength < JsValue > > (),
+		interpolate r#macro::wat_output_import_type:: < JsArray < JsValue > > (), interpolate
 		r#macro::wat_imports!((PtrConst < JsValue >, PtrLength < JsValue >), JsArray < JsValue >),
 		interpolate r#macro::wat_indirect!(JsArray < JsValue >), interpolate < PtrConst < JsValue >
 		as Input > ::WAT_TYPE, interpolate < PtrLength < JsValue > as Input > ::WAT_TYPE,
@@ -116,10 +638,10 @@ pub(super) unsafe fn array_js_value_decode(
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "array_js_value_decode",
 		required_embeds = [
-			("js_sys", "array.js_value.decode"),
+			(js_bindgen::namespace!(), "array.js_value.decode"),
 			r#macro::js_input_embed::<PtrConst<JsValue>>(),
 			r#macro::js_input_embed::<PtrLength<JsValue>>(),
 			r#macro::js_output_embed::<JsArray<JsValue>>(),
@@ -135,8 +657,12 @@ pub(super) unsafe fn array_js_value_decode(
 		interpolate r#macro::js_parameter!("len", PtrLength<JsValue>),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['array.js_value.decode']",
-			"this.#jsEmbed.js_sys['array.js_value.decode'](array, len)",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['array.js_value.decode']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['array.js_value.decode'](array, len)",
+			),
 			JsArray<JsValue>,
 			PtrConst<JsValue>,
 			PtrLength<JsValue>,
@@ -144,7 +670,7 @@ pub(super) unsafe fn array_js_value_decode(
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.array_js_value_decode"]
+		#[link_name = js_bindgen::namespace!("array_js_value_decode")]
 		fn array_js_value_decode(
 			array: <PtrConst<JsValue> as Input>::Type,
 			len: <PtrLength<JsValue> as Input>::Type,
@@ -154,6 +680,7 @@ pub(super) unsafe fn array_js_value_decode(
 	Output::from_raw(unsafe { array_js_value_decode(Input::into_raw(array), Input::into_raw(len)) })
 }
 
+#[track_caller]
 pub(super) unsafe fn array_js_value_encode(
 	array: &JsArray,
 	array_ptr: PtrMut<JsValue>,
@@ -162,13 +689,13 @@ pub(super) unsafe fn array_js_value_encode(
 	externref_len: i32,
 ) -> bool {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"array_js_value_encode\" (func $js_sys.import.array_js_value_encode (@sym (name \"js_sys.import.array_js_value_encode\")) (param {} {} {} {} {}) (result {}))){}",
-		"(func $js_sys.array_js_value_encode (@sym) (param {}) (param $array {}) (param $array_ptr {}) (param $array_len {}) (param $externref_ptr {}) (param $externref_len {}) (result {})",
+		"(import \"{ns}\" \"array_js_value_encode\" (func ${ns}.import.array_js_value_encode (@sym (name \"{ns}.import.array_js_value_encode\")) (param {} {} {} {} {}) (result {}))){}",
+		"(func ${ns}.array_js_value_encode (@sym) (param {}) (param $array {}) (param $array_ptr {}) (param $array_len {}) (param $externref_ptr {}) (param $externref_len {}) (result {})",
 		"  local.get $array{}", "  local.get $array_ptr{}", "  local.get $array_len{}",
 		"  local.get $externref_ptr{}", "  local.get $externref_len{}",
-		"  call $js_sys.import.array_js_value_encode (@reloc){}", ")", interpolate
-		r#macro::wat_input_import_type:: < & JsArray > (), interpolate
-		r#macro::wat_input_import_type:: < PtrMut < JsValue > > (), interpolate
+		"  call ${ns}.import.array_js_value_encode (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsArray > (),
+		interpolate r#macro::wat_input_import_type:: < PtrMut < JsValue > > (), interpolate
 		r#macro::wat_input_import_type:: < PtrLength < JsValue > > (), interpolate
 		r#macro::wat_input_import_type:: < PtrConst < i32 > > (), interpolate
 		r#macro::wat_input_import_type:: < i32 > (), interpolate r#macro::wat_output_import_type:: <
@@ -184,10 +711,10 @@ pub(super) unsafe fn array_js_value_encode(
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "array_js_value_encode",
 		required_embeds = [
-			("js_sys", "array.js_value.encode"),
+			(js_bindgen::namespace!(), "array.js_value.encode"),
 			r#macro::js_input_embed::<&JsArray>(),
 			r#macro::js_input_embed::<PtrMut<JsValue>>(),
 			r#macro::js_input_embed::<PtrLength<JsValue>>(),
@@ -209,8 +736,12 @@ pub(super) unsafe fn array_js_value_encode(
 		interpolate r#macro::js_parameter!("externref_len", i32),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['array.js_value.encode']",
-			"this.#jsEmbed.js_sys['array.js_value.encode'](array, array_ptr, array_len, externref_ptr, externref_len)",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['array.js_value.encode']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['array.js_value.encode'](array, array_ptr, array_len, externref_ptr, externref_len)",
+			),
 			bool,
 			&JsArray,
 			PtrMut<JsValue>,
@@ -221,7 +752,7 @@ pub(super) unsafe fn array_js_value_encode(
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.array_js_value_encode"]
+		#[link_name = js_bindgen::namespace!("array_js_value_encode")]
 		fn array_js_value_encode(
 			array: <&JsArray as Input>::Type,
 			array_ptr: <PtrMut<JsValue> as Input>::Type,
@@ -242,14 +773,15 @@ pub(super) unsafe fn array_js_value_encode(
 	})
 }
 
+#[track_caller]
 pub(super) unsafe fn array_u32_decode(array: PtrConst<u32>, len: PtrLength<u32>) -> JsArray<u32> {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"array_u32_decode\" (func $js_sys.import.array_u32_decode (@sym (name \"js_sys.import.array_u32_decode\")) (param {} {}) (result {}))){}",
-		"(func $js_sys.array_u32_decode (@sym) (param {}) (param $array {}) (param $len {}) (result {})",
+		"(import \"{ns}\" \"array_u32_decode\" (func ${ns}.import.array_u32_decode (@sym (name \"{ns}.import.array_u32_decode\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.array_u32_decode (@sym) (param {}) (param $array {}) (param $len {}) (result {})",
 		"  local.get $array{}", "  local.get $len{}",
-		"  call $js_sys.import.array_u32_decode (@reloc){}", ")", interpolate
-		r#macro::wat_input_import_type:: < PtrConst < u32 > > (), interpolate
-		r#macro::wat_input_import_type:: < PtrLength < u32 > > (), interpolate
+		"  call ${ns}.import.array_u32_decode (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < PtrConst < u32 > >
+		(), interpolate r#macro::wat_input_import_type:: < PtrLength < u32 > > (), interpolate
 		r#macro::wat_output_import_type:: < JsArray < u32 > > (), interpolate
 		r#macro::wat_imports!((PtrConst < u32 >, PtrLength < u32 >), JsArray < u32 >), interpolate
 		r#macro::wat_indirect!(JsArray < u32 >), interpolate < PtrConst < u32 > as Input >
@@ -260,10 +792,10 @@ pub(super) unsafe fn array_u32_decode(array: PtrConst<u32>, len: PtrLength<u32>)
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "array_u32_decode",
 		required_embeds = [
-			("js_sys", "view.getUint32"),
+			(js_bindgen::namespace!(), "view.getUint32"),
 			r#macro::js_input_embed::<PtrConst<u32>>(),
 			r#macro::js_input_embed::<PtrLength<u32>>(),
 			r#macro::js_output_embed::<JsArray<u32>>(),
@@ -279,8 +811,12 @@ pub(super) unsafe fn array_u32_decode(array: PtrConst<u32>, len: PtrLength<u32>)
 		interpolate r#macro::js_parameter!("len", PtrLength<u32>),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['view.getUint32']",
-			"this.#jsEmbed.js_sys['view.getUint32'](array, len)",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.getUint32']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['view.getUint32'](array, len)",
+			),
 			JsArray<u32>,
 			PtrConst<u32>,
 			PtrLength<u32>,
@@ -288,7 +824,7 @@ pub(super) unsafe fn array_u32_decode(array: PtrConst<u32>, len: PtrLength<u32>)
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.array_u32_decode"]
+		#[link_name = js_bindgen::namespace!("array_u32_decode")]
 		fn array_u32_decode(
 			array: <PtrConst<u32> as Input>::Type,
 			len: <PtrLength<u32> as Input>::Type,
@@ -298,18 +834,19 @@ pub(super) unsafe fn array_u32_decode(array: PtrConst<u32>, len: PtrLength<u32>)
 	Output::from_raw(unsafe { array_u32_decode(Input::into_raw(array), Input::into_raw(len)) })
 }
 
+#[track_caller]
 pub(super) unsafe fn array_u32_encode(
 	array: &JsArray<u32>,
 	ptr: PtrMut<u32>,
 	len: PtrLength<u32>,
 ) -> bool {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"array_u32_encode\" (func $js_sys.import.array_u32_encode (@sym (name \"js_sys.import.array_u32_encode\")) (param {} {} {}) (result {}))){}",
-		"(func $js_sys.array_u32_encode (@sym) (param {}) (param $array {}) (param $ptr {}) (param $len {}) (result {})",
+		"(import \"{ns}\" \"array_u32_encode\" (func ${ns}.import.array_u32_encode (@sym (name \"{ns}.import.array_u32_encode\")) (param {} {} {}) (result {}))){}",
+		"(func ${ns}.array_u32_encode (@sym) (param {}) (param $array {}) (param $ptr {}) (param $len {}) (result {})",
 		"  local.get $array{}", "  local.get $ptr{}", "  local.get $len{}",
-		"  call $js_sys.import.array_u32_encode (@reloc){}", ")", interpolate
-		r#macro::wat_input_import_type:: < & JsArray < u32 > > (), interpolate
-		r#macro::wat_input_import_type:: < PtrMut < u32 > > (), interpolate
+		"  call ${ns}.import.array_u32_encode (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsArray < u32 > >
+		(), interpolate r#macro::wat_input_import_type:: < PtrMut < u32 > > (), interpolate
 		r#macro::wat_input_import_type:: < PtrLength < u32 > > (), interpolate
 		r#macro::wat_output_import_type:: < bool > (), interpolate r#macro::wat_imports!((& JsArray
 		< u32 >, PtrMut < u32 >, PtrLength < u32 >), bool), interpolate
@@ -321,10 +858,10 @@ pub(super) unsafe fn array_u32_encode(
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "array_u32_encode",
 		required_embeds = [
-			("js_sys", "array.u32.encode"),
+			(js_bindgen::namespace!(), "array.u32.encode"),
 			r#macro::js_input_embed::<&JsArray<u32>>(),
 			r#macro::js_input_embed::<PtrMut<u32>>(),
 			r#macro::js_input_embed::<PtrLength<u32>>(),
@@ -342,8 +879,12 @@ pub(super) unsafe fn array_u32_encode(
 		interpolate r#macro::js_parameter!("len", PtrLength<u32>),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['array.u32.encode']",
-			"this.#jsEmbed.js_sys['array.u32.encode'](array, ptr, len)",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['array.u32.encode']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['array.u32.encode'](array, ptr, len)",
+			),
 			bool,
 			&JsArray<u32>,
 			PtrMut<u32>,
@@ -352,7 +893,7 @@ pub(super) unsafe fn array_u32_encode(
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.array_u32_encode"]
+		#[link_name = js_bindgen::namespace!("array_u32_encode")]
 		fn array_u32_encode(
 			array: <&JsArray<u32> as Input>::Type,
 			ptr: <PtrMut<u32> as Input>::Type,
@@ -7,6 +7,38 @@ extern "js-sys" {
 	#[js_sys(property)]
 	pub fn length<T>(self: &JsArray<T>) -> u32;
 
+	#[js_sys(js_embed = "array.get")]
+	pub(super) fn array_get(array: &JsArray, index: u32) -> JsValue;
+
+	#[js_sys(js_embed = "array.set")]
+	pub(super) fn array_set(array: &JsArray, index: u32, value: &JsValue);
+
+	#[js_sys(js_name = "push")]
+	pub(super) fn array_push(self: &JsArray, value: &JsValue) -> u32;
+
+	#[js_sys(js_name = "pop")]
+	pub(super) fn array_pop(self: &JsArray) -> JsValue;
+
+	#[js_sys(js_embed = "array.splice")]
+	pub(super) fn array_splice(
+		array: &JsArray,
+		start: u32,
+		delete_count: u32,
+		items: &[JsValue],
+	) -> JsArray;
+
+	#[js_sys(js_name = "slice")]
+	pub(super) fn array_slice(self: &JsArray, start: u32, end: u32) -> JsArray;
+
+	#[js_sys(js_name = "concat")]
+	pub(super) fn array_concat(self: &JsArray, other: &JsArray) -> JsArray;
+
+	#[js_sys(js_name = "includes")]
+	pub(super) fn array_includes(self: &JsArray, value: &JsValue) -> bool;
+
+	#[js_sys(js_name = "indexOf")]
+	pub(super) fn array_index_of(self: &JsArray, value: &JsValue) -> i32;
+
 	#[js_sys(js_embed = "array.js_value.decode")]
 	pub(super) unsafe fn array_js_value_decode(
 		array: PtrConst<JsValue>,
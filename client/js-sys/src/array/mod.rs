@@ -2,6 +2,7 @@
 #[path ="array.gen.rs"]
 mod array;
 
+use alloc::vec::{self, Vec};
 use core::error::Error;
 use core::fmt::{self, Display, Formatter};
 use core::mem::MaybeUninit;
@@ -25,6 +26,191 @@ impl<T> JsArray<T> {
 	}
 }
 
+impl<T> JsArray<T> {
+	/// Returns the elements between `start` and `end`. Both are clamped to the
+	/// length of the array.
+	#[must_use]
+	pub fn slice(&self, start: u32, end: u32) -> Self {
+		Self::unchecked_from(self.as_any().array_slice(start, end).into())
+	}
+
+	#[must_use]
+	pub fn concat(&self, other: &Self) -> Self {
+		Self::unchecked_from(self.as_any().array_concat(other.as_any()).into())
+	}
+}
+
+impl<T: JsCast> JsArray<T> {
+	/// Returns [`None`] if `index` is out of bounds.
+	#[must_use]
+	pub fn get(&self, index: u32) -> Option<T> {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "array.get",
+			"(array, index) => array[index]",
+		);
+
+		(index < self.length()).then(|| T::unchecked_from(array::array_get(self.as_any(), index)))
+	}
+
+	/// Sets the element at `index`. Like in JS, setting an element out of
+	/// bounds extends the array.
+	pub fn set(&self, index: u32, value: &T) {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "array.set",
+			"(array, index, value) => {{",
+			"	array[index] = value",
+			"}}",
+		);
+
+		array::array_set(self.as_any(), index, JsValue::from_ref(value));
+	}
+
+	/// Appends an element and returns the new length.
+	pub fn push(&self, value: &T) -> u32 {
+		self.as_any().array_push(JsValue::from_ref(value))
+	}
+
+	#[expect(clippy::must_use_candidate, reason = "removing is the main effect")]
+	pub fn pop(&self) -> Option<T> {
+		if self.length() == 0 {
+			None
+		} else {
+			Some(T::unchecked_from(self.as_any().array_pop()))
+		}
+	}
+
+	/// Removes `delete_count` elements at `start`, inserts `items` in their
+	/// place and returns the removed elements.
+	#[expect(
+		clippy::return_self_not_must_use,
+		reason = "removing is the main effect"
+	)]
+	pub fn splice(&self, start: u32, delete_count: u32, items: &[T]) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "array.splice",
+			"(array, start, deleteCount, items) => array.splice(start, deleteCount, ...items)",
+		);
+
+		Self::unchecked_from(
+			array::array_splice(
+				self.as_any(),
+				start,
+				delete_count,
+				JsValue::from_slice(items),
+			)
+			.into(),
+		)
+	}
+
+	/// Compares with the JS `SameValueZero` algorithm.
+	#[must_use]
+	pub fn includes(&self, value: &T) -> bool {
+		self.as_any().array_includes(JsValue::from_ref(value))
+	}
+
+	/// Returns the index of the first element strictly equal to `value`.
+	#[must_use]
+	pub fn index_of(&self, value: &T) -> Option<u32> {
+		self.as_any()
+			.array_index_of(JsValue::from_ref(value))
+			.try_into()
+			.ok()
+	}
+
+	/// Returns an iterator over the elements of the array.
+	///
+	/// Elements are fetched in batches to reduce the number of calls into JS.
+	/// Modifying the array while iterating is not reflected in elements that
+	/// were already fetched.
+	#[must_use]
+	pub fn iter(&self) -> Iter<'_, T> {
+		Iter {
+			array: self,
+			index: 0,
+			len: self.length(),
+			batch: Vec::new().into_iter(),
+		}
+	}
+}
+
+impl<'array, T: JsCast> IntoIterator for &'array JsArray<T> {
+	type Item = T;
+	type IntoIter = Iter<'array, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<T: JsCast> FromIterator<T> for JsArray<T> {
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let vec: Vec<T> = iter.into_iter().collect();
+		vec.as_slice().into()
+	}
+}
+
+impl FromIterator<u32> for JsArray<u32> {
+	fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+		let vec: Vec<u32> = iter.into_iter().collect();
+		vec.as_slice().into()
+	}
+}
+
+/// Iterator over the elements of a [`JsArray`], see [`JsArray::iter()`].
+pub struct Iter<'array, T> {
+	array: &'array JsArray<T>,
+	index: u32,
+	len: u32,
+	batch: vec::IntoIter<T>,
+}
+
+impl<T: JsCast> Iter<'_, T> {
+	const BATCH_SIZE: u32 = 64;
+
+	fn fetch(&mut self) -> Option<()> {
+		let len = self
+			.len
+			.min(self.array.length())
+			.checked_sub(self.index)
+			.filter(|len| *len > 0)?
+			.min(Self::BATCH_SIZE);
+		let slice = self.array.slice(self.index, self.index + len);
+		let mut batch = Vec::with_capacity(len as usize);
+		let init = slice
+			.to_uninit_slice(&mut batch.spare_capacity_mut()[..len as usize])
+			.expect("`slice()` returned a different length")
+			.len();
+		// SAFETY: Initialized by `to_uninit_slice()`.
+		unsafe { batch.set_len(init) };
+
+		self.index += len;
+		self.batch = batch.into_iter();
+
+		Some(())
+	}
+}
+
+impl<T: JsCast> Iterator for Iter<'_, T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(item) = self.batch.next() {
+			return Some(item);
+		}
+
+		self.fetch()?;
+		self.batch.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.batch.len() + (self.len - self.index) as usize;
+		(self.batch.len(), Some(len))
+	}
+}
+
 impl<T, const N: usize> From<&[T; N]> for JsArray<T>
 where
 	Self: for<'a> From<&'a [T]>,
@@ -63,9 +249,10 @@ impl Display for TryFromJsArrayError {
 impl Error for TryFromJsArrayError {}
 
 impl<T: JsCast> JsArray<T> {
+	#[cfg_attr(feature = "leak-detection", track_caller)]
 	pub fn to_slice(&self, slice: &mut [T]) -> Result<(), TryFromJsArrayError> {
 		let slice = JsValue::from_slice_mut(slice);
-		let externref = ExternrefTable::current_ptr();
+		let externref = ExternrefTable::reserve(slice.len());
 
 		// SAFETY: Parameters are correct.
 		let result = unsafe {
@@ -86,12 +273,13 @@ impl<T: JsCast> JsArray<T> {
 		}
 	}
 
+	#[cfg_attr(feature = "leak-detection", track_caller)]
 	pub fn to_uninit_slice<'slice>(
 		&self,
 		slice: &'slice mut [MaybeUninit<T>],
 	) -> Result<&'slice mut [T], TryFromJsArrayError> {
 		let js_slice = JsValue::from_uninit_slice_mut(slice);
-		let externref = ExternrefTable::current_ptr();
+		let externref = ExternrefTable::reserve(js_slice.len());
 
 		// SAFETY: Parameters are correct.
 		let result = unsafe {
@@ -113,9 +301,10 @@ impl<T: JsCast> JsArray<T> {
 		}
 	}
 
+	#[cfg_attr(feature = "leak-detection", track_caller)]
 	pub fn to_array<const N: usize>(&self) -> Result<[T; N], TryFromJsArrayError> {
 		let mut array: MaybeUninit<[T; N]> = MaybeUninit::uninit();
-		let externref = ExternrefTable::current_ptr();
+		let externref = ExternrefTable::reserve(N);
 		let js_array = JsValue::from_mut_uninit_array(&mut array);
 
 		// SAFETY: Parameters are correct.
@@ -140,55 +329,57 @@ impl<T: JsCast> JsArray<T> {
 }
 
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "array.js_value.encode",
-	required_embeds = [("js_sys", "view.getInt32"), ("js_sys", "view.setInt32")],
+	required_embeds = [
+		(js_bindgen::namespace!(), "view.getInt32"),
+		(js_bindgen::namespace!(), "view.setInt32")
+	],
 	"(array, arrPtr, arrLen, refPtr, refLen) => {{",
 	"	if (array.length !== arrLen) return false",
 	"",
-	"	const table = this.#jsEmbed.js_sys['externref.table']",
-	"",
-	// Default value helps browsers to optimize.
-	"	let tableIndex = 0",
-	"	if (arrLen > refLen) {{",
-	"		tableIndex = table.grow(arrLen - refLen)",
-	"	}}",
+	"	const table = this.#jsEmbed.{ns}['externref.table']",
 	"",
+	// Enough free slots were reserved beforehand.
 	"	let refIndex = refLen - 1",
 	"",
 	"	for (let arrayIndex = 0; arrayIndex < arrLen; arrayIndex++) {{",
-	"		let elemIndex",
-	"",
-	"		if (refIndex >= 0) {{",
-	"			elemIndex = this.#jsEmbed.js_sys['view.getInt32'](refPtr + refIndex * 4, 1)[0]",
-	"			refIndex--",
-	"		}} else {{",
-	"			elemIndex = tableIndex",
-	"			tableIndex++",
-	"		}}",
+	"		const [elemIndex] = this.#jsEmbed.{ns}['view.getInt32'](refPtr + refIndex * 4, 1)",
+	"		refIndex--",
 	"",
+	// Strip the generation.
+	#[cfg(debug_assertions)]
+	"		table.set(elemIndex & 0xffffff, array[arrayIndex])",
+	#[cfg(not(debug_assertions))]
 	"		table.set(elemIndex, array[arrayIndex])",
-	"		this.#jsEmbed.js_sys['view.setInt32'](arrPtr + arrayIndex * 4, [elemIndex])",
+	"		this.#jsEmbed.{ns}['view.setInt32'](arrPtr + arrayIndex * 4, [elemIndex])",
 	"	}}",
 	"",
 	"	return true",
 	"}}",
+	ns = interpolate js_bindgen::namespace!(),
 );
 
 impl<T: JsCast> From<&[T]> for JsArray<T> {
 	fn from(value: &[T]) -> Self {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "array.js_value.decode",
-			required_embeds = [("js_sys", "view.getInt32")],
+			required_embeds = [(js_bindgen::namespace!(), "view.getInt32")],
 			"(ptr, len) => {{",
 			"	const array = new Array(len)",
 			"	for (let arrayIndex = 0; arrayIndex < len; arrayIndex++) {{",
-			"		const [refIndex] = this.#jsEmbed.js_sys['view.getInt32'](ptr + arrayIndex * 4, 1)",
-			"		array[arrayIndex] = this.#jsEmbed.js_sys['externref.table'].get(refIndex)",
+			"		const [refIndex] = this.#jsEmbed.{ns}['view.getInt32'](ptr + arrayIndex * 4, 1)",
+			// Strip the generation.
+			#[cfg(debug_assertions)]
+			"		array[arrayIndex] = this.#jsEmbed.{ns}['externref.table'].get(refIndex & \
+			 0xffffff)",
+			#[cfg(not(debug_assertions))]
+			"		array[arrayIndex] = this.#jsEmbed.{ns}['externref.table'].get(refIndex)",
 			"	}}",
 			"	return array",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		let slice = JsValue::from_slice(value);
@@ -205,8 +396,12 @@ unsafe impl<T: JsCast> Input for &[T] {
 	const WAT_TYPE: &'static str = Self::Type::WAT_TYPE;
 	const WAT_CONV: Option<InputWatConv> = Self::Type::WAT_CONV;
 	const JS_CONV: Option<InputJsConv> = Some(InputJsConv {
-		embed: Some(("js_sys", "array.rust.js_value")),
-		pre: " = this.#jsEmbed.js_sys['array.rust.js_value'](",
+		embed: Some((js_bindgen::namespace!(), "array.rust.js_value")),
+		pre: concat!(
+			" = this.#jsEmbed.",
+			js_bindgen::namespace!(),
+			"['array.rust.js_value']("
+		),
 		post: Some(")"),
 	});
 
@@ -214,16 +409,17 @@ unsafe impl<T: JsCast> Input for &[T] {
 
 	fn into_raw(self) -> Self::Type {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "array.rust.js_value",
 			required_embeds = [
-				("js_sys", "extern_ref"),
-				("js_sys", "array.js_value.decode")
+				(js_bindgen::namespace!(), "extern_ref"),
+				(js_bindgen::namespace!(), "array.js_value.decode")
 			],
 			"(dataPtr) => {{",
-			"	const {{ ptr, len }} = this.#jsEmbed.js_sys['extern_ref'](dataPtr)",
-			"	return this.#jsEmbed.js_sys['array.js_value.decode'](ptr, len)",
+			"	const {{ ptr, len }} = this.#jsEmbed.{ns}['extern_ref'](dataPtr)",
+			"	return this.#jsEmbed.{ns}['array.js_value.decode'](ptr, len)",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		ExternSlice::new(JsValue::from_slice(self))
@@ -287,15 +483,16 @@ impl JsArray<u32> {
 }
 
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "array.u32.encode",
-	required_embeds = [("js_sys", "view.setInt32")],
+	required_embeds = [(js_bindgen::namespace!(), "view.setInt32")],
 	"(array, ptr, len) => {{",
 	"	if (array.length !== len) return false",
 	"",
-	"	this.#jsEmbed.js_sys['view.setInt32'](ptr, array)",
+	"	this.#jsEmbed.{ns}['view.setInt32'](ptr, array)",
 	"	return true",
 	"}}",
+	ns = interpolate js_bindgen::namespace!(),
 );
 
 impl From<&[u32]> for JsArray<u32> {
@@ -310,8 +507,12 @@ unsafe impl Input for &[u32] {
 	const WAT_TYPE: &'static str = Self::Type::WAT_TYPE;
 	const WAT_CONV: Option<InputWatConv> = Self::Type::WAT_CONV;
 	const JS_CONV: Option<InputJsConv> = Some(InputJsConv {
-		embed: Some(("js_sys", "array.rust.u32")),
-		pre: " = this.#jsEmbed.js_sys['array.rust.u32'](",
+		embed: Some((js_bindgen::namespace!(), "array.rust.u32")),
+		pre: concat!(
+			" = this.#jsEmbed.",
+			js_bindgen::namespace!(),
+			"['array.rust.u32']("
+		),
 		post: Some(")"),
 	});
 
@@ -319,13 +520,17 @@ unsafe impl Input for &[u32] {
 
 	fn into_raw(self) -> Self::Type {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "array.rust.u32",
-			required_embeds = [("js_sys", "extern_ref"), ("js_sys", "view.getUint32")],
+			required_embeds = [
+				(js_bindgen::namespace!(), "extern_ref"),
+				(js_bindgen::namespace!(), "view.getUint32")
+			],
 			"(dataPtr) => {{",
-			"	const {{ ptr, len }} = this.#jsEmbed.js_sys.extern_ref(dataPtr)",
-			"	return this.#jsEmbed.js_sys['view.getUint32'](ptr, len)",
+			"	const {{ ptr, len }} = this.#jsEmbed.{ns}.extern_ref(dataPtr)",
+			"	return this.#jsEmbed.{ns}['view.getUint32'](ptr, len)",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		ExternSlice::new(self)
@@ -2,9 +2,12 @@
 
 #![allow(warnings)]
 
-use crate::JsValue;
-use crate::hazard::{Input, InputWatConv, InputJsConv, OutputJsConv, Output, JsCast, OutputWatConv};
+use core::fmt;
+use crate::{js_bindgen, r#macro, JsValue};
+use crate::hazard::{InputJsConv, OutputJsConv, OutputWatConv, Input, InputWatConv, Output, JsCast};
+use crate::{JsNumber, JsString};
 
+#[derive(Clone)]
 #[repr(transparent)]
 pub struct JsBigInt(JsValue);
 
@@ -45,3 +48,867 @@ unsafe impl Output for JsBigInt {
 		Self(Output::from_raw(raw))
 	}
 }
+
+impl fmt::Debug for JsBigInt {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.0, f)
+	}
+}
+
+#[track_caller]
+pub(super) fn bigint_from_i64(value: i64) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_from_i64\" (func ${ns}.import.bigint_from_i64 (@sym (name \"{ns}.import.bigint_from_i64\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_from_i64 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_from_i64 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < i64 >
+		(), interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((i64), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < i64 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (),
+		interpolate r#macro::wat_input!(i64), interpolate r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_from_i64",
+		required_embeds = [
+			r#macro::js_input_embed::<i64>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (i64), JsBigInt),
+		interpolate r#macro::js_parameter!("value", i64),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			JsBigInt,
+			i64,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_from_i64")]
+		fn bigint_from_i64(value: <i64 as Input>::Type) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_from_i64(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_from_u64(value: u64) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_from_u64\" (func ${ns}.import.bigint_from_u64 (@sym (name \"{ns}.import.bigint_from_u64\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_from_u64 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_from_u64 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < u64 >
+		(), interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((u64), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < u64 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (),
+		interpolate r#macro::wat_input!(u64), interpolate r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_from_u64",
+		required_embeds = [
+			r#macro::js_input_embed::<u64>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (u64), JsBigInt),
+		interpolate r#macro::js_parameter!("value", u64),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			JsBigInt,
+			u64,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_from_u64")]
+		fn bigint_from_u64(value: <u64 as Input>::Type) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_from_u64(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_from_i128(value: i128) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_from_i128\" (func ${ns}.import.bigint_from_i128 (@sym (name \"{ns}.import.bigint_from_i128\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_from_i128 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_from_i128 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < i128 >
+		(), interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((i128), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < i128 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (),
+		interpolate r#macro::wat_input!(i128), interpolate r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_from_i128",
+		required_embeds = [
+			r#macro::js_input_embed::<i128>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (i128), JsBigInt),
+		interpolate r#macro::js_parameter!("value", i128),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			JsBigInt,
+			i128,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_from_i128")]
+		fn bigint_from_i128(value: <i128 as Input>::Type) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_from_i128(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_from_u128(value: u128) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_from_u128\" (func ${ns}.import.bigint_from_u128 (@sym (name \"{ns}.import.bigint_from_u128\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_from_u128 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_from_u128 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < u128 >
+		(), interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((u128), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < u128 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (),
+		interpolate r#macro::wat_input!(u128), interpolate r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_from_u128",
+		required_embeds = [
+			r#macro::js_input_embed::<u128>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (u128), JsBigInt),
+		interpolate r#macro::js_parameter!("value", u128),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			JsBigInt,
+			u128,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_from_u128")]
+		fn bigint_from_u128(value: <u128 as Input>::Type) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_from_u128(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_to_i64(value: &JsBigInt) -> i64 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_to_i64\" (func ${ns}.import.bigint_to_i64 (@sym (name \"{ns}.import.bigint_to_i64\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_to_i64 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_to_i64 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsBigInt > (), interpolate r#macro::wat_output_import_type:: < i64 > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), i64), interpolate r#macro::wat_indirect!(i64),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < i64 >
+		(), interpolate r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_output!(i64),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_to_i64",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<i64>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsBigInt), i64),
+		interpolate r#macro::js_parameter!("value", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			i64,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_to_i64")]
+		fn bigint_to_i64(value: <&JsBigInt as Input>::Type) -> <i64 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_to_i64(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_to_u64(value: &JsBigInt) -> u64 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_to_u64\" (func ${ns}.import.bigint_to_u64 (@sym (name \"{ns}.import.bigint_to_u64\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_to_u64 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_to_u64 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsBigInt > (), interpolate r#macro::wat_output_import_type:: < u64 > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), u64), interpolate r#macro::wat_indirect!(u64),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < u64 >
+		(), interpolate r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_output!(u64),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_to_u64",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<u64>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsBigInt), u64),
+		interpolate r#macro::js_parameter!("value", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			u64,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_to_u64")]
+		fn bigint_to_u64(value: <&JsBigInt as Input>::Type) -> <u64 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_to_u64(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_to_i128(value: &JsBigInt) -> i128 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_to_i128\" (func ${ns}.import.bigint_to_i128 (@sym (name \"{ns}.import.bigint_to_i128\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_to_i128 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_to_i128 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsBigInt > (), interpolate r#macro::wat_output_import_type:: < i128 > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), i128), interpolate r#macro::wat_indirect!(i128),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < i128 >
+		(), interpolate r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_output!(i128),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_to_i128",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<i128>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsBigInt), i128),
+		interpolate r#macro::js_parameter!("value", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			i128,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_to_i128")]
+		fn bigint_to_i128(value: <&JsBigInt as Input>::Type) -> <i128 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_to_i128(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_to_u128(value: &JsBigInt) -> u128 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_to_u128\" (func ${ns}.import.bigint_to_u128 (@sym (name \"{ns}.import.bigint_to_u128\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_to_u128 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_to_u128 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsBigInt > (), interpolate r#macro::wat_output_import_type:: < u128 > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), u128), interpolate r#macro::wat_indirect!(u128),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < u128 >
+		(), interpolate r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_output!(u128),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_to_u128",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<u128>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsBigInt), u128),
+		interpolate r#macro::js_parameter!("value", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.BigInt",
+			"globalThis.BigInt(value)",
+			u128,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_to_u128")]
+		fn bigint_to_u128(value: <&JsBigInt as Input>::Type) -> <u128 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_to_u128(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_to_number(value: &JsBigInt) -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_to_number\" (func ${ns}.import.bigint_to_number (@sym (name \"{ns}.import.bigint_to_number\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_to_number (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_to_number (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsBigInt > (), interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), JsValue), interpolate r#macro::wat_indirect!(JsValue),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsValue
+		> (), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_to_number",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.to_number"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsBigInt), JsValue),
+		interpolate r#macro::js_parameter!("value", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.to_number']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['bigint.to_number'](value)",
+			),
+			JsValue,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_to_number")]
+		fn bigint_to_number(value: <&JsBigInt as Input>::Type) -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_to_number(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_from_number<T>(value: &JsNumber<T>) -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_from_number\" (func ${ns}.import.bigint_from_number (@sym (name \"{ns}.import.bigint_from_number\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_from_number (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_from_number (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsNumber > (), interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+		r#macro::wat_imports!((& JsNumber), JsValue), interpolate r#macro::wat_indirect!(JsValue),
+		interpolate < & JsNumber as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsValue
+		> (), interpolate r#macro::wat_input!(& JsNumber), interpolate
+		r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_from_number",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.from_number"),
+			r#macro::js_input_embed::<&JsNumber>(),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsNumber), JsValue),
+		interpolate r#macro::js_parameter!("value", &JsNumber),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.from_number']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['bigint.from_number'](value)",
+			),
+			JsValue,
+			&JsNumber,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_from_number")]
+		fn bigint_from_number(value: <&JsNumber as Input>::Type) -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_from_number(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_fits(value: &JsBigInt, bits: u32, signed: bool) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_fits\" (func ${ns}.import.bigint_fits (@sym (name \"{ns}.import.bigint_fits\")) (param {} {} {}) (result {}))){}",
+		"(func ${ns}.bigint_fits (@sym) (param {}) (param $value {}) (param $bits {}) (param $signed {}) (result {})",
+		"  local.get $value{}", "  local.get $bits{}", "  local.get $signed{}",
+		"  call ${ns}.import.bigint_fits (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsBigInt > (),
+		interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+		r#macro::wat_input_import_type:: < bool > (), interpolate r#macro::wat_output_import_type::
+		< bool > (), interpolate r#macro::wat_imports!((& JsBigInt, u32, bool), bool), interpolate
+		r#macro::wat_indirect!(bool), interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate <
+		u32 as Input > ::WAT_TYPE, interpolate < bool as Input > ::WAT_TYPE, interpolate
+		r#macro::wat_direct:: < bool > (), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_input!(u32), interpolate r#macro::wat_input!(bool), interpolate
+		r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_fits",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.fits"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_input_embed::<u32>(),
+			r#macro::js_input_embed::<bool>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}{}{}",
+		interpolate r#macro::js_select!(
+			"",
+			"(value, bits, signed) => {\n",
+			(&JsBigInt, u32, bool),
+			bool,
+		),
+		interpolate r#macro::js_parameter!("value", &JsBigInt),
+		interpolate r#macro::js_parameter!("bits", u32),
+		interpolate r#macro::js_parameter!("signed", bool),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.fits']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['bigint.fits'](value, bits, signed)",
+			),
+			bool,
+			&JsBigInt,
+			u32,
+			bool,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_fits")]
+		fn bigint_fits(
+			value: <&JsBigInt as Input>::Type,
+			bits: <u32 as Input>::Type,
+			signed: <bool as Input>::Type,
+		) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe {
+		bigint_fits(Input::into_raw(value), Input::into_raw(bits), Input::into_raw(signed))
+	})
+}
+
+#[track_caller]
+pub(super) fn bigint_parse(value: &str) -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_parse\" (func ${ns}.import.bigint_parse (@sym (name \"{ns}.import.bigint_parse\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_parse (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_parse (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & str > (),
+		interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+		r#macro::wat_imports!((& str), JsValue), interpolate r#macro::wat_indirect!(JsValue),
+		interpolate < & str as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsValue > (),
+		interpolate r#macro::wat_input!(& str), interpolate r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_parse",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.parse"),
+			r#macro::js_input_embed::<&str>(),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&str), JsValue),
+		interpolate r#macro::js_parameter!("value", &str),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.parse']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.parse'](value)"),
+			JsValue,
+			&str,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_parse")]
+		fn bigint_parse(value: <&str as Input>::Type) -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_parse(Input::into_raw(value)) })
+}
+
+impl JsBigInt {
+	#[track_caller]
+	pub(super) fn bigint_to_string(self: &JsBigInt, radix: u32) -> JsString {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"bigint_to_string\" (func ${ns}.import.bigint_to_string (@sym (name \"{ns}.import.bigint_to_string\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.bigint_to_string (@sym) (param {}) (param $self {}) (param $radix {}) (result {})",
+			"  local.get $self{}", "  local.get $radix{}",
+			"  call ${ns}.import.bigint_to_string (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+			r#macro::wat_output_import_type:: < JsString > (), interpolate r#macro::wat_imports!((&
+			JsValue, u32), JsString), interpolate r#macro::wat_indirect!(JsString), interpolate < &
+			JsValue as Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE, interpolate
+			r#macro::wat_direct:: < JsString > (), interpolate r#macro::wat_input!(& JsValue),
+			interpolate r#macro::wat_input!(u32), interpolate r#macro::wat_output!(JsString),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "bigint_to_string",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<u32>(),
+				r#macro::js_output_embed::<JsString>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, radix) => ",
+				"(self, radix) => {\n",
+				(&JsValue, u32),
+				JsString,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("radix", u32),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.toString(radix)",
+				"self.toString(radix)",
+				JsString,
+				&JsValue,
+				u32,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("bigint_to_string")]
+			fn bigint_to_string(
+				this: <&JsValue as Input>::Type,
+				radix: <u32 as Input>::Type,
+			) -> <JsString as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { bigint_to_string(Input::into_raw(self), Input::into_raw(radix)) })
+	}
+}
+
+#[track_caller]
+pub(super) fn bigint_add(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_add\" (func ${ns}.import.bigint_add (@sym (name \"{ns}.import.bigint_add\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.bigint_add (@sym) (param {}) (param $lhs {}) (param $rhs {}) (result {})",
+		"  local.get $lhs{}", "  local.get $rhs{}", "  call ${ns}.import.bigint_add (@reloc){}",
+		")", ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type::
+		< & JsBigInt > (), interpolate r#macro::wat_input_import_type:: < & JsBigInt > (),
+		interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate < & JsBigInt as Input >
+		::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (), interpolate
+		r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_add",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.add"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(lhs, rhs) => {\n", (&JsBigInt), JsBigInt),
+		interpolate r#macro::js_parameter!("lhs", &JsBigInt),
+		interpolate r#macro::js_parameter!("rhs", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.add']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.add'](lhs, rhs)"),
+			JsBigInt,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_add")]
+		fn bigint_add(
+			lhs: <&JsBigInt as Input>::Type,
+			rhs: <&JsBigInt as Input>::Type,
+		) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_add(Input::into_raw(lhs), Input::into_raw(rhs)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_sub(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_sub\" (func ${ns}.import.bigint_sub (@sym (name \"{ns}.import.bigint_sub\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.bigint_sub (@sym) (param {}) (param $lhs {}) (param $rhs {}) (result {})",
+		"  local.get $lhs{}", "  local.get $rhs{}", "  call ${ns}.import.bigint_sub (@reloc){}",
+		")", ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type::
+		< & JsBigInt > (), interpolate r#macro::wat_input_import_type:: < & JsBigInt > (),
+		interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate < & JsBigInt as Input >
+		::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (), interpolate
+		r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_sub",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.sub"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(lhs, rhs) => {\n", (&JsBigInt), JsBigInt),
+		interpolate r#macro::js_parameter!("lhs", &JsBigInt),
+		interpolate r#macro::js_parameter!("rhs", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.sub']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.sub'](lhs, rhs)"),
+			JsBigInt,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_sub")]
+		fn bigint_sub(
+			lhs: <&JsBigInt as Input>::Type,
+			rhs: <&JsBigInt as Input>::Type,
+		) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_sub(Input::into_raw(lhs), Input::into_raw(rhs)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_mul(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_mul\" (func ${ns}.import.bigint_mul (@sym (name \"{ns}.import.bigint_mul\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.bigint_mul (@sym) (param {}) (param $lhs {}) (param $rhs {}) (result {})",
+		"  local.get $lhs{}", "  local.get $rhs{}", "  call ${ns}.import.bigint_mul (@reloc){}",
+		")", ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type::
+		< & JsBigInt > (), interpolate r#macro::wat_input_import_type:: < & JsBigInt > (),
+		interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate < & JsBigInt as Input >
+		::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (), interpolate
+		r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_mul",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.mul"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(lhs, rhs) => {\n", (&JsBigInt), JsBigInt),
+		interpolate r#macro::js_parameter!("lhs", &JsBigInt),
+		interpolate r#macro::js_parameter!("rhs", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.mul']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.mul'](lhs, rhs)"),
+			JsBigInt,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_mul")]
+		fn bigint_mul(
+			lhs: <&JsBigInt as Input>::Type,
+			rhs: <&JsBigInt as Input>::Type,
+		) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_mul(Input::into_raw(lhs), Input::into_raw(rhs)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_div(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_div\" (func ${ns}.import.bigint_div (@sym (name \"{ns}.import.bigint_div\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.bigint_div (@sym) (param {}) (param $lhs {}) (param $rhs {}) (result {})",
+		"  local.get $lhs{}", "  local.get $rhs{}", "  call ${ns}.import.bigint_div (@reloc){}",
+		")", ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type::
+		< & JsBigInt > (), interpolate r#macro::wat_input_import_type:: < & JsBigInt > (),
+		interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate < & JsBigInt as Input >
+		::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (), interpolate
+		r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_div",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.div"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(lhs, rhs) => {\n", (&JsBigInt), JsBigInt),
+		interpolate r#macro::js_parameter!("lhs", &JsBigInt),
+		interpolate r#macro::js_parameter!("rhs", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.div']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.div'](lhs, rhs)"),
+			JsBigInt,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_div")]
+		fn bigint_div(
+			lhs: <&JsBigInt as Input>::Type,
+			rhs: <&JsBigInt as Input>::Type,
+		) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_div(Input::into_raw(lhs), Input::into_raw(rhs)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_rem(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_rem\" (func ${ns}.import.bigint_rem (@sym (name \"{ns}.import.bigint_rem\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.bigint_rem (@sym) (param {}) (param $lhs {}) (param $rhs {}) (result {})",
+		"  local.get $lhs{}", "  local.get $rhs{}", "  call ${ns}.import.bigint_rem (@reloc){}",
+		")", ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type::
+		< & JsBigInt > (), interpolate r#macro::wat_input_import_type:: < & JsBigInt > (),
+		interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate < & JsBigInt as Input >
+		::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt > (), interpolate
+		r#macro::wat_input!(& JsBigInt), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_rem",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.rem"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(lhs, rhs) => {\n", (&JsBigInt), JsBigInt),
+		interpolate r#macro::js_parameter!("lhs", &JsBigInt),
+		interpolate r#macro::js_parameter!("rhs", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.rem']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.rem'](lhs, rhs)"),
+			JsBigInt,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_rem")]
+		fn bigint_rem(
+			lhs: <&JsBigInt as Input>::Type,
+			rhs: <&JsBigInt as Input>::Type,
+		) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_rem(Input::into_raw(lhs), Input::into_raw(rhs)) })
+}
+
+#[track_caller]
+pub(super) fn bigint_neg(value: &JsBigInt) -> JsBigInt {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"bigint_neg\" (func ${ns}.import.bigint_neg (@sym (name \"{ns}.import.bigint_neg\")) (param {}) (result {}))){}",
+		"(func ${ns}.bigint_neg (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.bigint_neg (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsBigInt > (),
+		interpolate r#macro::wat_output_import_type:: < JsBigInt > (), interpolate
+		r#macro::wat_imports!((& JsBigInt), JsBigInt), interpolate r#macro::wat_indirect!(JsBigInt),
+		interpolate < & JsBigInt as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsBigInt
+		> (), interpolate r#macro::wat_input!(& JsBigInt), interpolate
+		r#macro::wat_output!(JsBigInt),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "bigint_neg",
+		required_embeds = [
+			(js_bindgen::namespace!(), "bigint.neg"),
+			r#macro::js_input_embed::<&JsBigInt>(),
+			r#macro::js_output_embed::<JsBigInt>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsBigInt), JsBigInt),
+		interpolate r#macro::js_parameter!("value", &JsBigInt),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.neg']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['bigint.neg'](value)"),
+			JsBigInt,
+			&JsBigInt,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("bigint_neg")]
+		fn bigint_neg(value: <&JsBigInt as Input>::Type) -> <JsBigInt as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { bigint_neg(Input::into_raw(value)) })
+}
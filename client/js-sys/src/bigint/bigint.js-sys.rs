@@ -1,4 +1,64 @@
+use crate::{JsNumber, JsString};
+
 #[js_sys]
 extern "js-sys" {
+	#[derive(Clone)]
 	pub type JsBigInt;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_from_i64(value: i64) -> JsBigInt;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_from_u64(value: u64) -> JsBigInt;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_from_i128(value: i128) -> JsBigInt;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_from_u128(value: u128) -> JsBigInt;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_to_i64(value: &JsBigInt) -> i64;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_to_u64(value: &JsBigInt) -> u64;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_to_i128(value: &JsBigInt) -> i128;
+
+	#[js_sys(js_name = "BigInt")]
+	pub(super) fn bigint_to_u128(value: &JsBigInt) -> u128;
+
+	#[js_sys(js_embed = "bigint.to_number")]
+	pub(super) fn bigint_to_number(value: &JsBigInt) -> JsValue;
+
+	#[js_sys(js_embed = "bigint.from_number")]
+	pub(super) fn bigint_from_number<T>(#[js_sys(type = &JsNumber)] value: &JsNumber<T>) -> JsValue;
+
+	#[js_sys(js_embed = "bigint.fits")]
+	pub(super) fn bigint_fits(value: &JsBigInt, bits: u32, signed: bool) -> bool;
+
+	#[js_sys(js_embed = "bigint.parse")]
+	pub(super) fn bigint_parse(value: &str) -> JsValue;
+
+	#[js_sys(js_name = "toString")]
+	pub(super) fn bigint_to_string(self: &JsBigInt, radix: u32) -> JsString;
+
+	#[js_sys(js_embed = "bigint.add")]
+	pub(super) fn bigint_add(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.sub")]
+	pub(super) fn bigint_sub(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.mul")]
+	pub(super) fn bigint_mul(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.div")]
+	pub(super) fn bigint_div(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.rem")]
+	pub(super) fn bigint_rem(lhs: &JsBigInt, rhs: &JsBigInt) -> JsBigInt;
+
+	#[js_sys(js_embed = "bigint.neg")]
+	pub(super) fn bigint_neg(value: &JsBigInt) -> JsBigInt;
 }
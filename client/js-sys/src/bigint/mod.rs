@@ -2,4 +2,249 @@
 #[path ="bigint.gen.rs"]
 mod bigint;
 
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::str::FromStr;
+
 pub use self::bigint::JsBigInt;
+use crate::hazard::JsCast;
+use crate::{JsNumber, JsString};
+
+impl JsBigInt {
+	/// Returns the string representation in the given `radix`.
+	///
+	/// # Panics
+	///
+	/// If `radix` is not in the range `2..=36`.
+	#[must_use]
+	pub fn to_string(&self, radix: u32) -> JsString {
+		assert!(
+			(2..=36).contains(&radix),
+			"`radix` must be in the range `2..=36`"
+		);
+
+		self.bigint_to_string(radix)
+	}
+}
+
+impl PartialEq for JsBigInt {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_ref() == other.as_ref()
+	}
+}
+
+impl Eq for JsBigInt {}
+
+macro_rules! from {
+	($($ty:ty => $from:ident),*) => {$(
+		impl From<$ty> for JsBigInt {
+			fn from(value: $ty) -> Self {
+				bigint::$from(value)
+			}
+		}
+	)*};
+}
+
+from!(
+	i64 => bigint_from_i64,
+	u64 => bigint_from_u64,
+	i128 => bigint_from_i128,
+	u128 => bigint_from_u128
+);
+
+macro_rules! from_small {
+	($($ty:ty),*) => {$(
+		impl From<$ty> for JsBigInt {
+			fn from(value: $ty) -> Self {
+				bigint::bigint_from_i64(value.into())
+			}
+		}
+	)*};
+}
+
+from_small!(i8, i16, i32, u8, u16, u32);
+
+macro_rules! try_from {
+	($($ty:ty => $to:ident),*) => {$(
+		impl TryFrom<&JsBigInt> for $ty {
+			type Error = TryFromJsBigIntError;
+
+			fn try_from(value: &JsBigInt) -> Result<Self, Self::Error> {
+				if bigint::bigint_fits(value, Self::BITS, Self::MIN != 0) {
+					Ok(bigint::$to(value))
+				} else {
+					Err(TryFromJsBigIntError)
+				}
+			}
+		}
+	)*};
+}
+
+try_from!(
+	i64 => bigint_to_i64,
+	u64 => bigint_to_u64,
+	i128 => bigint_to_i128,
+	u128 => bigint_to_u128
+);
+
+impl TryFrom<&JsBigInt> for JsNumber {
+	type Error = TryFromJsBigIntError;
+
+	fn try_from(value: &JsBigInt) -> Result<Self, Self::Error> {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "bigint.to_number",
+			"(value) => {{",
+			"	const number = Number(value)",
+			"",
+			"	if (Number.isFinite(number) && BigInt(number) === value)",
+			"		return number",
+			"}}",
+		);
+
+		let value = bigint::bigint_to_number(value);
+
+		if value.is_undefined() {
+			Err(TryFromJsBigIntError)
+		} else {
+			Ok(Self::unchecked_from(value))
+		}
+	}
+}
+
+impl<T> TryFrom<&JsNumber<T>> for JsBigInt {
+	type Error = TryFromJsBigIntError;
+
+	fn try_from(value: &JsNumber<T>) -> Result<Self, Self::Error> {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "bigint.from_number",
+			"(value) => {{",
+			"	if (Number.isInteger(value))",
+			"		return BigInt(value)",
+			"}}",
+		);
+
+		let value = bigint::bigint_from_number(value);
+
+		if value.is_undefined() {
+			Err(TryFromJsBigIntError)
+		} else {
+			Ok(Self::unchecked_from(value))
+		}
+	}
+}
+
+/// Parses the string with the semantics of the JS `BigInt()` function.
+impl FromStr for JsBigInt {
+	type Err = ParseJsBigIntError;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "bigint.parse",
+			"(value) => {{",
+			"	try {{",
+			"		return BigInt(value)",
+			"	}} catch {{}}",
+			"}}",
+		);
+
+		let value = bigint::bigint_parse(value);
+
+		if value.is_undefined() {
+			Err(ParseJsBigIntError)
+		} else {
+			Ok(Self::unchecked_from(value))
+		}
+	}
+}
+
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "bigint.fits",
+	"(value, bits, signed) => {{",
+	"	if (signed)",
+	"		return BigInt.asIntN(bits, value) === value",
+	"	else",
+	"		return BigInt.asUintN(bits, value) === value",
+	"}}",
+);
+
+macro_rules! op {
+	($($trait:ident, $fn:ident, $name:tt, $js:tt, $binding:ident);*) => {$(
+		impl $trait for &JsBigInt {
+			type Output = JsBigInt;
+
+			fn $fn(self, rhs: Self) -> Self::Output {
+				js_bindgen::embed_js!(module = js_bindgen::namespace!(), name = $name, $js);
+
+				bigint::$binding(self, rhs)
+			}
+		}
+
+		impl $trait for JsBigInt {
+			type Output = Self;
+
+			fn $fn(self, rhs: Self) -> Self::Output {
+				(&self).$fn(&rhs)
+			}
+		}
+	)*};
+}
+
+// Like in Rust, division by zero fails, but throws a JS `RangeError` instead.
+op!(
+	Add, add, "bigint.add", "(lhs, rhs) => lhs + rhs", bigint_add;
+	Sub, sub, "bigint.sub", "(lhs, rhs) => lhs - rhs", bigint_sub;
+	Mul, mul, "bigint.mul", "(lhs, rhs) => lhs * rhs", bigint_mul;
+	Div, div, "bigint.div", "(lhs, rhs) => lhs / rhs", bigint_div;
+	Rem, rem, "bigint.rem", "(lhs, rhs) => lhs % rhs", bigint_rem
+);
+
+impl Neg for &JsBigInt {
+	type Output = JsBigInt;
+
+	fn neg(self) -> Self::Output {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "bigint.neg",
+			"(value) => -value"
+		);
+
+		bigint::bigint_neg(self)
+	}
+}
+
+impl Neg for JsBigInt {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		-&self
+	}
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TryFromJsBigIntError;
+
+impl Display for TryFromJsBigIntError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("out of range or lossy conversion attempted")
+	}
+}
+
+impl Error for TryFromJsBigIntError {}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ParseJsBigIntError;
+
+impl Display for ParseJsBigIntError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("invalid big integer literal")
+	}
+}
+
+impl Error for ParseJsBigIntError {}
@@ -0,0 +1,669 @@
+//! This file was generated by `js-sys-bindgen`.
+
+#![allow(warnings)]
+
+use core::ops::Deref;
+use core::fmt;
+use crate::{js_bindgen, r#macro, JsValue};
+use crate::hazard::{InputJsConv, OutputJsConv, OutputWatConv, Input, InputWatConv, Output, JsCast};
+use crate::{JsArray, JsString};
+
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct JsError(JsValue);
+
+impl AsRef<JsValue> for JsError {
+	fn as_ref(&self) -> &JsValue {
+		&self.0
+	}
+}
+
+impl From<JsError> for JsValue {
+	fn from(value: JsError) -> Self {
+		value.0
+	}
+}
+
+unsafe impl Input for &JsError {
+	const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+	const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+	const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+
+	type Type = <&'static JsValue as Input>::Type;
+
+	fn into_raw(self) -> Self::Type {
+		Input::into_raw(&self.0)
+	}
+}
+
+unsafe impl JsCast for JsError {}
+
+unsafe impl Output for JsError {
+	const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+	const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+	const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+
+	type Type = <JsValue as Output>::Type;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		Self(Output::from_raw(raw))
+	}
+}
+
+impl fmt::Debug for JsError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.0, f)
+	}
+}
+
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct TypeError(JsValue);
+
+impl AsRef<JsValue> for TypeError {
+	fn as_ref(&self) -> &JsValue {
+		&self.0
+	}
+}
+
+impl From<TypeError> for JsValue {
+	fn from(value: TypeError) -> Self {
+		value.0
+	}
+}
+
+unsafe impl Input for &TypeError {
+	const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+	const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+	const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+
+	type Type = <&'static JsValue as Input>::Type;
+
+	fn into_raw(self) -> Self::Type {
+		Input::into_raw(&self.0)
+	}
+}
+
+unsafe impl JsCast for TypeError {}
+
+unsafe impl Output for TypeError {
+	const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+	const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+	const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+
+	type Type = <JsValue as Output>::Type;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		Self(Output::from_raw(raw))
+	}
+}
+
+impl fmt::Debug for TypeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.0, f)
+	}
+}
+
+impl Deref for TypeError {
+	type Target = JsError;
+
+	fn deref(&self) -> &Self::Target {
+		JsCast::unchecked_from_ref(&self.0)
+	}
+}
+
+impl AsRef<JsError> for TypeError {
+	fn as_ref(&self) -> &JsError {
+		JsCast::unchecked_from_ref(&self.0)
+	}
+}
+
+impl From<TypeError> for JsError {
+	fn from(value: TypeError) -> Self {
+		JsCast::unchecked_from(value.0)
+	}
+}
+
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct RangeError(JsValue);
+
+impl AsRef<JsValue> for RangeError {
+	fn as_ref(&self) -> &JsValue {
+		&self.0
+	}
+}
+
+impl From<RangeError> for JsValue {
+	fn from(value: RangeError) -> Self {
+		value.0
+	}
+}
+
+unsafe impl Input for &RangeError {
+	const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+	const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+	const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+
+	type Type = <&'static JsValue as Input>::Type;
+
+	fn into_raw(self) -> Self::Type {
+		Input::into_raw(&self.0)
+	}
+}
+
+unsafe impl JsCast for RangeError {}
+
+unsafe impl Output for RangeError {
+	const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+	const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+	const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+
+	type Type = <JsValue as Output>::Type;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		Self(Output::from_raw(raw))
+	}
+}
+
+impl fmt::Debug for RangeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.0, f)
+	}
+}
+
+impl Deref for RangeError {
+	type Target = JsError;
+
+	fn deref(&self) -> &Self::Target {
+		JsCast::unchecked_from_ref(&self.0)
+	}
+}
+
+impl AsRef<JsError> for RangeError {
+	fn as_ref(&self) -> &JsError {
+		JsCast::unchecked_from_ref(&self.0)
+	}
+}
+
+impl From<RangeError> for JsError {
+	fn from(value: RangeError) -> Self {
+		JsCast::unchecked_from(value.0)
+	}
+}
+
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct AggregateError(JsValue);
+
+impl AsRef<JsValue> for AggregateError {
+	fn as_ref(&self) -> &JsValue {
+		&self.0
+	}
+}
+
+impl From<AggregateError> for JsValue {
+	fn from(value: AggregateError) -> Self {
+		value.0
+	}
+}
+
+unsafe impl Input for &AggregateError {
+	const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+	const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+	const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+
+	type Type = <&'static JsValue as Input>::Type;
+
+	fn into_raw(self) -> Self::Type {
+		Input::into_raw(&self.0)
+	}
+}
+
+unsafe impl JsCast for AggregateError {}
+
+unsafe impl Output for AggregateError {
+	const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+	const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+	const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+
+	type Type = <JsValue as Output>::Type;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		Self(Output::from_raw(raw))
+	}
+}
+
+impl fmt::Debug for AggregateError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.0, f)
+	}
+}
+
+impl Deref for AggregateError {
+	type Target = JsError;
+
+	fn deref(&self) -> &Self::Target {
+		JsCast::unchecked_from_ref(&self.0)
+	}
+}
+
+impl AsRef<JsError> for AggregateError {
+	fn as_ref(&self) -> &JsError {
+		JsCast::unchecked_from_ref(&self.0)
+	}
+}
+
+impl From<AggregateError> for JsError {
+	fn from(value: AggregateError) -> Self {
+		JsCast::unchecked_from(value.0)
+	}
+}
+
+#[track_caller]
+pub(super) fn error_new(message: &str) -> JsError {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"error_new\" (func ${ns}.import.error_new (@sym (name \"{ns}.import.error_new\")) (param {}) (result {}))){}",
+		"(func ${ns}.error_new (@sym) (param {}) (param $message {}) (result {})",
+		"  local.get $message{}", "  call ${ns}.import.error_new (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & str > (),
+		interpolate r#macro::wat_output_import_type:: < JsError > (), interpolate
+		r#macro::wat_imports!((& str), JsError), interpolate r#macro::wat_indirect!(JsError),
+		interpolate < & str as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsError > (),
+		interpolate r#macro::wat_input!(& str), interpolate r#macro::wat_output!(JsError),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "error_new",
+		required_embeds = [
+			(js_bindgen::namespace!(), "error.new"),
+			r#macro::js_input_embed::<&str>(),
+			r#macro::js_output_embed::<JsError>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(message) => {\n", (&str), JsError),
+		interpolate r#macro::js_parameter!("message", &str),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['error.new']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['error.new'](message)"),
+			JsError,
+			&str,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("error_new")]
+		fn error_new(message: <&str as Input>::Type) -> <JsError as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { error_new(Input::into_raw(message)) })
+}
+
+#[track_caller]
+pub(super) fn type_error_new(message: &str) -> TypeError {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"type_error_new\" (func ${ns}.import.type_error_new (@sym (name \"{ns}.import.type_error_new\")) (param {}) (result {}))){}",
+		"(func ${ns}.type_error_new (@sym) (param {}) (param $message {}) (result {})",
+		"  local.get $message{}", "  call ${ns}.import.type_error_new (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & str >
+		(), interpolate r#macro::wat_output_import_type:: < TypeError > (), interpolate
+		r#macro::wat_imports!((& str), TypeError), interpolate r#macro::wat_indirect!(TypeError),
+		interpolate < & str as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < TypeError >
+		(), interpolate r#macro::wat_input!(& str), interpolate r#macro::wat_output!(TypeError),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "type_error_new",
+		required_embeds = [
+			(js_bindgen::namespace!(), "error.type.new"),
+			r#macro::js_input_embed::<&str>(),
+			r#macro::js_output_embed::<TypeError>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(message) => {\n", (&str), TypeError),
+		interpolate r#macro::js_parameter!("message", &str),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['error.type.new']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['error.type.new'](message)",
+			),
+			TypeError,
+			&str,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("type_error_new")]
+		fn type_error_new(message: <&str as Input>::Type) -> <TypeError as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { type_error_new(Input::into_raw(message)) })
+}
+
+#[track_caller]
+pub(super) fn range_error_new(message: &str) -> RangeError {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"range_error_new\" (func ${ns}.import.range_error_new (@sym (name \"{ns}.import.range_error_new\")) (param {}) (result {}))){}",
+		"(func ${ns}.range_error_new (@sym) (param {}) (param $message {}) (result {})",
+		"  local.get $message{}", "  call ${ns}.import.range_error_new (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & str >
+		(), interpolate r#macro::wat_output_import_type:: < RangeError > (), interpolate
+		r#macro::wat_imports!((& str), RangeError), interpolate r#macro::wat_indirect!(RangeError),
+		interpolate < & str as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < RangeError >
+		(), interpolate r#macro::wat_input!(& str), interpolate r#macro::wat_output!(RangeError),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "range_error_new",
+		required_embeds = [
+			(js_bindgen::namespace!(), "error.range.new"),
+			r#macro::js_input_embed::<&str>(),
+			r#macro::js_output_embed::<RangeError>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(message) => {\n", (&str), RangeError),
+		interpolate r#macro::js_parameter!("message", &str),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['error.range.new']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['error.range.new'](message)",
+			),
+			RangeError,
+			&str,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("range_error_new")]
+		fn range_error_new(message: <&str as Input>::Type) -> <RangeError as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { range_error_new(Input::into_raw(message)) })
+}
+
+#[track_caller]
+pub(super) fn aggregate_error_new(errors: &JsArray, message: &str) -> AggregateError {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"aggregate_error_new\" (func ${ns}.import.aggregate_error_new (@sym (name \"{ns}.import.aggregate_error_new\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.aggregate_error_new (@sym) (param {}) (param $errors {}) (param $message {}) (result {})",
+		"  local.get $errors{}", "  local.get $message{}",
+		"  call ${ns}.import.aggregate_error_new (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsArray > (),
+		interpolate r#macro::wat_input_import_type:: < & str > (), interpolate
+		r#macro::wat_output_import_type:: < AggregateError > (), interpolate
+		r#macro::wat_imports!((& JsArray, & str), AggregateError), interpolate
+		r#macro::wat_indirect!(AggregateError), interpolate < & JsArray as Input > ::WAT_TYPE,
+		interpolate < & str as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: <
+		AggregateError > (), interpolate r#macro::wat_input!(& JsArray), interpolate
+		r#macro::wat_input!(& str), interpolate r#macro::wat_output!(AggregateError),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "aggregate_error_new",
+		required_embeds = [
+			(js_bindgen::namespace!(), "error.aggregate.new"),
+			r#macro::js_input_embed::<&JsArray>(),
+			r#macro::js_input_embed::<&str>(),
+			r#macro::js_output_embed::<AggregateError>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!(
+			"",
+			"(errors, message) => {\n",
+			(&JsArray, &str),
+			AggregateError,
+		),
+		interpolate r#macro::js_parameter!("errors", &JsArray),
+		interpolate r#macro::js_parameter!("message", &str),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['error.aggregate.new']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['error.aggregate.new'](errors, message)",
+			),
+			AggregateError,
+			&JsArray,
+			&str,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("aggregate_error_new")]
+		fn aggregate_error_new(
+			errors: <&JsArray as Input>::Type,
+			message: <&str as Input>::Type,
+		) -> <AggregateError as Output>::Type;
+	}
+
+	Output::from_raw(unsafe {
+		aggregate_error_new(Input::into_raw(errors), Input::into_raw(message))
+	})
+}
+
+impl JsError {
+	#[track_caller]
+	pub(super) fn error_name(self: &JsError) -> JsString {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"error_name\" (func ${ns}.import.error_name (@sym (name \"{ns}.import.error_name\")) (param {}) (result {}))){}",
+			"(func ${ns}.error_name (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.error_name (@reloc){}", ")", ns =
+			interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < JsString > (), interpolate
+			r#macro::wat_imports!((& JsValue), JsString), interpolate
+			r#macro::wat_indirect!(JsString), interpolate < & JsValue as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsString > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_output!(JsString),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "error_name",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<JsString>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), JsString),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.name",
+				"self.name",
+				JsString,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("error_name")]
+			fn error_name(this: <&JsValue as Input>::Type) -> <JsString as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { error_name(Input::into_raw(self)) })
+	}
+}
+
+impl JsError {
+	#[track_caller]
+	pub(super) fn error_message(self: &JsError) -> JsString {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"error_message\" (func ${ns}.import.error_message (@sym (name \"{ns}.import.error_message\")) (param {}) (result {}))){}",
+			"(func ${ns}.error_message (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.error_message (@reloc){}", ")", ns =
+			interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < JsString > (), interpolate
+			r#macro::wat_imports!((& JsValue), JsString), interpolate
+			r#macro::wat_indirect!(JsString), interpolate < & JsValue as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsString > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_output!(JsString),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "error_message",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<JsString>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), JsString),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.message",
+				"self.message",
+				JsString,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("error_message")]
+			fn error_message(this: <&JsValue as Input>::Type) -> <JsString as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { error_message(Input::into_raw(self)) })
+	}
+}
+
+impl JsError {
+	#[track_caller]
+	pub(super) fn error_cause(self: &JsError) -> JsValue {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"error_cause\" (func ${ns}.import.error_cause (@sym (name \"{ns}.import.error_cause\")) (param {}) (result {}))){}",
+			"(func ${ns}.error_cause (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.error_cause (@reloc){}", ")", ns =
+			interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+			r#macro::wat_imports!((& JsValue), JsValue), interpolate
+			r#macro::wat_indirect!(JsValue), interpolate < & JsValue as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsValue > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_output!(JsValue),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "error_cause",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<JsValue>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), JsValue),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.cause",
+				"self.cause",
+				JsValue,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("error_cause")]
+			fn error_cause(this: <&JsValue as Input>::Type) -> <JsValue as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { error_cause(Input::into_raw(self)) })
+	}
+}
+
+impl JsError {
+	#[track_caller]
+	pub(super) fn error_stack(self: &JsError) -> JsValue {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"error_stack\" (func ${ns}.import.error_stack (@sym (name \"{ns}.import.error_stack\")) (param {}) (result {}))){}",
+			"(func ${ns}.error_stack (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.error_stack (@reloc){}", ")", ns =
+			interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+			r#macro::wat_imports!((& JsValue), JsValue), interpolate
+			r#macro::wat_indirect!(JsValue), interpolate < & JsValue as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsValue > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_output!(JsValue),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "error_stack",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<JsValue>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), JsValue),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.stack",
+				"self.stack",
+				JsValue,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("error_stack")]
+			fn error_stack(this: <&JsValue as Input>::Type) -> <JsValue as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { error_stack(Input::into_raw(self)) })
+	}
+}
+
+impl AggregateError {
+	#[track_caller]
+	pub(super) fn aggregate_error_errors(self: &AggregateError) -> JsArray {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"aggregate_error_errors\" (func ${ns}.import.aggregate_error_errors (@sym (name \"{ns}.import.aggregate_error_errors\")) (param {}) (result {}))){}",
+			"(func ${ns}.aggregate_error_errors (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.aggregate_error_errors (@reloc){}", ")", ns
+			= interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < JsArray > (), interpolate
+			r#macro::wat_imports!((& JsValue), JsArray), interpolate
+			r#macro::wat_indirect!(JsArray), interpolate < & JsValue as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsArray > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_output!(JsArray),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "aggregate_error_errors",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<JsArray>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), JsArray),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.errors",
+				"self.errors",
+				JsArray,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("aggregate_error_errors")]
+			fn aggregate_error_errors(this: <&JsValue as Input>::Type) -> <JsArray as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { aggregate_error_errors(Input::into_raw(self)) })
+	}
+}
@@ -0,0 +1,46 @@
+use crate::{JsArray, JsString};
+
+#[js_sys]
+extern "js-sys" {
+	#[derive(Clone)]
+	pub type JsError;
+
+	#[derive(Clone)]
+	#[js_sys(extends = JsError)]
+	pub type TypeError;
+
+	#[derive(Clone)]
+	#[js_sys(extends = JsError)]
+	pub type RangeError;
+
+	#[derive(Clone)]
+	#[js_sys(extends = JsError)]
+	pub type AggregateError;
+
+	#[js_sys(js_embed = "error.new")]
+	pub(super) fn error_new(message: &str) -> JsError;
+
+	#[js_sys(js_embed = "error.type.new")]
+	pub(super) fn type_error_new(message: &str) -> TypeError;
+
+	#[js_sys(js_embed = "error.range.new")]
+	pub(super) fn range_error_new(message: &str) -> RangeError;
+
+	#[js_sys(js_embed = "error.aggregate.new")]
+	pub(super) fn aggregate_error_new(errors: &JsArray, message: &str) -> AggregateError;
+
+	#[js_sys(property, js_name = "name")]
+	pub(super) fn error_name(self: &JsError) -> JsString;
+
+	#[js_sys(property, js_name = "message")]
+	pub(super) fn error_message(self: &JsError) -> JsString;
+
+	#[js_sys(property, js_name = "cause")]
+	pub(super) fn error_cause(self: &JsError) -> JsValue;
+
+	#[js_sys(property, js_name = "stack")]
+	pub(super) fn error_stack(self: &JsError) -> JsValue;
+
+	#[js_sys(property, js_name = "errors")]
+	pub(super) fn aggregate_error_errors(self: &AggregateError) -> JsArray;
+}
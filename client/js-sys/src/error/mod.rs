@@ -0,0 +1,152 @@
+#[rustfmt::skip]
+#[path ="error.gen.rs"]
+mod error;
+
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+pub use self::error::{AggregateError, JsError, RangeError, TypeError};
+use crate::{JsArray, JsString, JsValue};
+
+impl JsError {
+	#[must_use]
+	pub fn new(message: &str) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "error.new",
+			"(message) => new Error(message)",
+		);
+
+		error::error_new(message)
+	}
+
+	#[must_use]
+	pub fn name(&self) -> JsString {
+		self.error_name()
+	}
+
+	#[must_use]
+	pub fn message(&self) -> JsString {
+		self.error_message()
+	}
+
+	/// Returns [`JsValue::UNDEFINED`] if no cause was set.
+	#[must_use]
+	pub fn cause(&self) -> JsValue {
+		self.error_cause()
+	}
+
+	/// Returns [`JsValue::UNDEFINED`] if the engine does not record stack
+	/// traces.
+	#[must_use]
+	pub fn stack(&self) -> JsValue {
+		self.error_stack()
+	}
+}
+
+impl TypeError {
+	#[must_use]
+	pub fn new(message: &str) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "error.type.new",
+			"(message) => new TypeError(message)",
+		);
+
+		error::type_error_new(message)
+	}
+}
+
+impl RangeError {
+	#[must_use]
+	pub fn new(message: &str) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "error.range.new",
+			"(message) => new RangeError(message)",
+		);
+
+		error::range_error_new(message)
+	}
+}
+
+impl AggregateError {
+	#[must_use]
+	pub fn new(errors: &JsArray, message: &str) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "error.aggregate.new",
+			"(errors, message) => new AggregateError(errors, message)",
+		);
+
+		error::aggregate_error_new(errors, message)
+	}
+
+	#[must_use]
+	pub fn errors(&self) -> JsArray {
+		self.aggregate_error_errors()
+	}
+}
+
+macro_rules! error {
+	($($ty:ty),*) => {$(
+		impl Display for $ty {
+			fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+				Display::fmt(&JsString::new(self.as_ref()), f)
+			}
+		}
+
+		impl Error for $ty {}
+	)*};
+}
+
+error!(JsError, TypeError, RangeError, AggregateError);
+
+/// A copy of the name, message and stack of a [`JsError`].
+///
+/// JS types can't be sent between threads, which error types like
+/// `anyhow::Error` require. Converting into this type copies the error
+/// contents into Rust [`String`]s, which can.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JsErrorSnapshot {
+	name: String,
+	message: String,
+	stack: Option<String>,
+}
+
+impl JsErrorSnapshot {
+	#[must_use]
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	#[must_use]
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// Returns [`None`] if the engine does not record stack traces.
+	#[must_use]
+	pub fn stack(&self) -> Option<&str> {
+		self.stack.as_deref()
+	}
+}
+
+impl From<&JsError> for JsErrorSnapshot {
+	fn from(error: &JsError) -> Self {
+		Self {
+			name: String::from(&error.name()),
+			message: String::from(&error.message()),
+			stack: error.stack().as_string(),
+		}
+	}
+}
+
+impl Display for JsErrorSnapshot {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.name, self.message)
+	}
+}
+
+impl Error for JsErrorSnapshot {}
@@ -1,68 +1,168 @@
+//! Management of the `externref` table holding all
+//! [`JsValue`](crate::JsValue)s.
+
+#[cfg(feature = "leak-detection")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "leak-detection")]
+use alloc::string::String;
 use alloc::vec::Vec;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
+use core::cmp::Reverse;
+#[cfg(feature = "leak-detection")]
+use core::fmt::{self, Display, Formatter, Write};
+#[cfg(feature = "leak-detection")]
+use core::mem::ManuallyDrop;
+use core::num::NonZeroU32;
+#[cfg(feature = "leak-detection")]
+use core::panic::Location;
 
+#[cfg(feature = "leak-detection")]
+use crate::JsValue;
 use crate::panic::panic;
 use crate::util::PtrConst;
 
 js_bindgen::unsafe_global_wat!(
 	// Imports need an explicit name.
 	// See https://github.com/llvm/llvm-project/issues/198509.
-	"(import \"js_sys\" \"externref.table\" (table $js_sys.import.externref.table (@sym (name \
-	 \"js_sys.externref.table\")) 2 externref))",
-	"(import \"env\" \"js_sys.externref.next\" (func $js_sys.externref.next (@sym) (result i32)))",
-	"(func $js_sys.externref.grow (@sym) (param $size i32) (result i32)",
+	"(import \"{ns}\" \"externref.table\" (table ${ns}.import.externref.table (@sym (name \
+	 \"{ns}.externref.table\")) 66 externref))",
+	"(import \"env\" \"{ns}.externref.next\" (func ${ns}.externref.next (@sym) (result i32)))",
+	#[cfg(debug_assertions)]
+	"(import \"env\" \"{ns}.externref.slot\" (func ${ns}.externref.slot (@sym) (param i32) \
+	 (result i32)))",
+	"(func ${ns}.externref.grow (@sym) (param $size i32) (result i32)",
 	"  ref.null extern",
 	"  local.get $size",
-	"  table.grow $js_sys.import.externref.table (@reloc)",
+	"  table.grow ${ns}.import.externref.table (@reloc)",
+	")",
+	"(func ${ns}.externref.insert (@sym) (param $value externref) (result i32)",
+	"  (local $index i32)",
+	// `null` always maps to the reserved slot.
+	"  local.get $value",
+	"  ref.is_null",
+	"  if",
+	"    i32.const 1",
+	"    return",
+	"  end",
+	"  call ${ns}.externref.next (@reloc)",
+	"  local.tee $index",
+	// Strip the generation.
+	#[cfg(debug_assertions)]
+	"  i32.const 0xffffff",
+	#[cfg(debug_assertions)]
+	"  i32.and",
+	"  local.get $value",
+	"  table.set ${ns}.import.externref.table (@reloc)",
+	"  local.get $index",
 	")",
-	"(func $js_sys.externref.insert (@sym) (param $value externref) (result i32)",
+	"(import \"env\" \"{ns}.externref.stack_next\" (func ${ns}.externref.stack_next (@sym) \
+	 (result i32)))",
+	"(func ${ns}.externref.stack_insert (@sym) (param $value externref) (result i32)",
 	"  (local $index i32)",
-	"  call $js_sys.externref.next (@reloc)",
+	"  local.get $value",
+	"  ref.is_null",
+	"  if",
+	"    i32.const 1",
+	"    return",
+	"  end",
+	"  call ${ns}.externref.stack_next (@reloc)",
 	"  local.tee $index",
+	#[cfg(debug_assertions)]
+	"  i32.const 0xffffff",
+	#[cfg(debug_assertions)]
+	"  i32.and",
 	"  local.get $value",
-	"  table.set $js_sys.import.externref.table (@reloc)",
+	"  table.set ${ns}.import.externref.table (@reloc)",
 	"  local.get $index",
 	")",
-	"(func $js_sys.externref.get (@sym) (param $index i32) (result externref)",
+	"(func ${ns}.externref.size (@sym) (result i32)",
+	"  table.size ${ns}.import.externref.table (@reloc)",
+	")",
+	"(func ${ns}.externref.get (@sym) (param $index i32) (result externref)",
 	"  local.get $index",
-	"  table.get $js_sys.import.externref.table (@reloc)",
+	#[cfg(debug_assertions)]
+	"  call ${ns}.externref.slot (@reloc)",
+	"  table.get ${ns}.import.externref.table (@reloc)",
 	")",
-	"(func $js_sys.externref.remove (@sym) (param $index i32)",
+	"(func ${ns}.externref.remove (@sym) (param $index i32)",
 	"  local.get $index",
 	"  ref.null extern",
-	"  table.set $js_sys.import.externref.table (@reloc)",
+	"  table.set ${ns}.import.externref.table (@reloc)",
 	")",
+	ns = interpolate js_bindgen::namespace!(),
 );
 
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "externref.table",
 	"(() => {{",
-	"	const table = new WebAssembly.Table({{ initial: 2, element: 'externref' }})",
+	// Two reserved slots followed by the `JsRef` stack.
+	"	const table = new WebAssembly.Table({{ initial: 66, element: 'externref' }})",
 	"	table.set(1, null)",
 	"	return table",
 	"}})()"
 );
 
 js_bindgen::import_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "externref.table",
-	required_embeds = [("js_sys", "externref.table")],
-	"this.#jsEmbed.js_sys['externref.table']",
+	required_embeds = [(js_bindgen::namespace!(), "externref.table")],
+	"this.#jsEmbed.{ns}['externref.table']",
+	ns = interpolate js_bindgen::namespace!(),
 );
 
 unsafe extern "C" {
-	#[link_name = "js_sys.externref.grow"]
+	#[link_name = js_bindgen::namespace!("externref.grow")]
 	safe fn grow(size: i32) -> i32;
-	#[link_name = "js_sys.externref.remove"]
+	#[link_name = js_bindgen::namespace!("externref.size")]
+	safe fn size() -> i32;
+	#[link_name = js_bindgen::namespace!("externref.remove")]
 	safe fn remove(index: i32);
 }
 
 thread_local! {
 	pub(crate) static EXTERNREF_TABLE: RefCell<ExternrefTable> = RefCell::new(ExternrefTable::new());
+	// Occupied slots of the `JsRef` stack.
+	static STACK: Cell<u64> = Cell::new(0);
 }
 
-pub(crate) struct ExternrefTable(Vec<i32>);
+/// Slots `0` and `1` are reserved for `undefined` and `null`.
+const STACK_START: i32 = 2;
+/// Slots reserved for [`JsRef`](crate::JsRef)s, managed without going through
+/// [`ExternrefTable`]. One bit per slot in [`STACK`].
+const STACK_LEN: i32 = 64;
+/// Start of the slots managed by [`ExternrefTable`].
+pub(crate) const RESERVED: i32 = STACK_START + STACK_LEN;
+
+/// With debug assertions, handles carry a generation in the bits above the
+/// slot, which is incremented every time the slot is freed. Using a stale
+/// handle then panics instead of accessing an unrelated value.
+///
+/// Generations wrap around, so a stale handle can only be detected until its
+/// slot was reused `128` times.
+#[cfg(debug_assertions)]
+const GENERATION_SHIFT: u32 = 24;
+#[cfg(debug_assertions)]
+const SLOT_MASK: i32 = (1 << GENERATION_SHIFT) - 1;
+#[cfg(debug_assertions)]
+const GENERATION_MASK: u8 = 0x7f;
+
+pub(crate) struct ExternrefTable {
+	/// Handles of free slots, the next slot to be used is at the end.
+	free: Vec<i32>,
+	/// Number of slots managed, including the reserved ones. The actual table
+	/// might be bigger after [`shrink()`].
+	len: i32,
+	growth: Growth,
+	/// Current generation of every slot ever managed.
+	#[cfg(debug_assertions)]
+	generations: Vec<u8>,
+	/// Creation order and call site of every live slot.
+	#[cfg(feature = "leak-detection")]
+	locations: BTreeMap<i32, (u64, &'static Location<'static>)>,
+	#[cfg(feature = "leak-detection")]
+	next_id: u64,
+}
 
 pub(crate) struct ExternrefTablePtr {
 	pub(crate) ptr: PtrConst<i32>,
@@ -71,56 +171,450 @@ pub(crate) struct ExternrefTablePtr {
 
 impl ExternrefTable {
 	const fn new() -> Self {
-		Self(Vec::new())
+		Self {
+			free: Vec::new(),
+			len: RESERVED,
+			growth: Growth::Double,
+			#[cfg(debug_assertions)]
+			generations: Vec::new(),
+			#[cfg(feature = "leak-detection")]
+			locations: BTreeMap::new(),
+			#[cfg(feature = "leak-detection")]
+			next_id: 0,
+		}
 	}
 
 	fn next(&mut self) -> i32 {
-		if let Some(slot) = self.0.pop() {
+		if let Some(slot) = self.free.pop() {
 			slot
 		} else {
-			match grow(1) {
-				-1 => panic("`externref` table allocation failure"),
-				slot => slot,
+			self.grow(1);
+			self.free.pop().unwrap()
+		}
+	}
+
+	/// Adds at least `min` slots to the free list.
+	fn grow(&mut self, min: usize) {
+		let min = i32::try_from(min).unwrap_or(i32::MAX);
+		let additional = match self.growth {
+			Growth::Double => (self.len - RESERVED).max(8),
+			Growth::Chunk(size) => i32::try_from(size.get()).unwrap_or(i32::MAX),
+		}
+		.max(min);
+		let new_len = self
+			.len
+			.checked_add(additional)
+			.unwrap_or_else(|| panic("`externref` table allocation failure"));
+
+		// Slots previously released by `shrink()` are still part of the table.
+		let size = size();
+
+		if new_len > size && grow(new_len - size) == -1 {
+			panic("`externref` table allocation failure");
+		}
+
+		#[cfg(debug_assertions)]
+		{
+			// Don't panic while the table is borrowed, see `stale()`.
+			if new_len > SLOT_MASK + 1 {
+				panic("`externref` table exceeded the slots supported by generational handles");
+			}
+
+			let new_len = usize::try_from(new_len).unwrap();
+
+			if self.generations.len() < new_len {
+				self.generations
+					.try_reserve(new_len - self.generations.len())
+					.expect("failure to grow memory");
+				self.generations.resize(new_len, 0);
 			}
 		}
+
+		self.free
+			.try_reserve(usize::try_from(additional).unwrap())
+			.expect("failure to grow memory");
+
+		for slot in (self.len..new_len).rev() {
+			let handle = self.handle(slot);
+			self.free.push(handle);
+		}
+
+		self.len = new_len;
 	}
 
-	pub(crate) fn remove(&mut self, index: i32) {
-		self.0.try_reserve(1).expect("failure to grow memory");
+	/// Returns the handle for the current generation of a slot.
+	#[cfg(debug_assertions)]
+	fn handle(&self, slot: i32) -> i32 {
+		let generation = self.generations[usize::try_from(slot).unwrap()];
+		(i32::from(generation) << GENERATION_SHIFT) | slot
+	}
 
-		self.0.push(index);
-		remove(index);
+	#[cfg(not(debug_assertions))]
+	#[expect(clippy::unused_self, reason = "only used with debug assertions")]
+	const fn handle(&self, slot: i32) -> i32 {
+		slot
 	}
 
-	/// Export a pointer and length to the current list.
+	/// Returns the slot of a handle without validating its generation.
+	const fn slot_unchecked(handle: i32) -> i32 {
+		#[cfg(debug_assertions)]
+		let handle = handle & SLOT_MASK;
+
+		handle
+	}
+
+	/// Returns the slot of a handle, or the current handle of the slot if the
+	/// given one is stale, i.e. its slot was freed since.
+	#[cfg(debug_assertions)]
+	fn slot(&self, handle: i32) -> Result<i32, i32> {
+		let slot = Self::slot_unchecked(handle);
+		let current = self.handle(slot);
+
+		if handle == current {
+			Ok(slot)
+		} else {
+			Err(current)
+		}
+	}
+
+	/// Frees the slot of a handle.
+	///
+	/// # Panics
+	///
+	/// If the handle is stale, i.e. its slot was freed since.
+	pub(crate) fn remove(index: i32) {
+		EXTERNREF_TABLE
+			.with(|table| table.try_borrow_mut().unwrap().remove_handle(index))
+			.unwrap_or_else(|current| stale(index, current));
+	}
+
+	#[cfg_attr(
+		not(debug_assertions),
+		expect(clippy::unnecessary_wraps, reason = "only fails with debug assertions")
+	)]
+	fn remove_handle(&mut self, index: i32) -> Result<(), i32> {
+		self.free.try_reserve(1).expect("failure to grow memory");
+
+		#[cfg(debug_assertions)]
+		let slot = {
+			let slot = self.slot(index)?;
+			let generation = &mut self.generations[usize::try_from(slot).unwrap()];
+			*generation = generation.wrapping_add(1) & GENERATION_MASK;
+			slot
+		};
+		#[cfg(not(debug_assertions))]
+		let slot = index;
+
+		let handle = self.handle(slot);
+		self.free.push(handle);
+		remove(slot);
+
+		#[cfg(feature = "leak-detection")]
+		self.locations.remove(&index);
+
+		Ok(())
+	}
+
+	#[cfg(feature = "leak-detection")]
+	fn insert_location(&mut self, index: i32, location: &'static Location<'static>) {
+		let id = self.next_id;
+		self.next_id += 1;
+		self.locations.insert(index, (id, location));
+	}
+
+	/// Records the call site that created the value in the given slot.
+	#[cfg(feature = "leak-detection")]
+	pub(crate) fn track(index: i32, location: &'static Location<'static>) {
+		EXTERNREF_TABLE.with(|table| {
+			table
+				.try_borrow_mut()
+				.unwrap()
+				.insert_location(index, location);
+		});
+	}
+
+	/// Stops reporting the value in the given slot as live, for values that
+	/// are intentionally kept alive until the thread exits.
+	#[cfg(feature = "leak-detection")]
+	pub(crate) fn untrack(index: i32) {
+		EXTERNREF_TABLE.with(|table| {
+			table.try_borrow_mut().unwrap().locations.remove(&index);
+		});
+	}
+
+	/// Make sure at least `len` free slots are available and export a pointer
+	/// and length to the current list.
 	///
 	/// # Safety
 	///
 	/// Reading from that pointer and length is only valid as long as the list
 	/// is not modified.
-	pub(crate) fn current_ptr() -> ExternrefTablePtr {
+	pub(crate) fn reserve(len: usize) -> ExternrefTablePtr {
 		EXTERNREF_TABLE.with(|table| {
-			let table = &table.try_borrow().unwrap().0;
+			let mut table = table.try_borrow_mut().unwrap();
+
+			if let Some(min) = len.checked_sub(table.free.len())
+				&& min > 0
+			{
+				table.grow(min);
+			}
 
 			ExternrefTablePtr {
-				ptr: PtrConst::new(table),
-				len: table.len().try_into().unwrap(),
+				ptr: PtrConst::new(&table.free),
+				len: table.free.len().try_into().unwrap(),
 			}
 		})
 	}
 
 	/// When using empty slots through [`ExternrefTablePtr`], we report back how
 	/// many we used.
+	#[cfg_attr(feature = "leak-detection", track_caller)]
 	pub(crate) fn report_used_slots(slots: usize) {
+		#[cfg(feature = "leak-detection")]
+		let location = Location::caller();
+
 		EXTERNREF_TABLE.with(|table| {
 			let mut table = table.try_borrow_mut().unwrap();
-			let new_len = table.0.len().saturating_sub(slots);
-			table.0.truncate(new_len);
+			let new_len = table.free.len().saturating_sub(slots);
+
+			#[cfg(feature = "leak-detection")]
+			for index in table.free.split_off(new_len) {
+				table.insert_location(index, location);
+			}
+
+			#[cfg(not(feature = "leak-detection"))]
+			table.free.truncate(new_len);
 		});
 	}
 }
 
-#[unsafe(export_name = "js_sys.externref.next")]
+/// How the `externref` table grows when it runs out of free slots.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Growth {
+	/// Double the number of slots, but add at least 8.
+	#[default]
+	Double,
+	/// Add a fixed number of slots.
+	Chunk(NonZeroU32),
+}
+
+/// Statistics of the `externref` table of the current thread.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Stats {
+	/// Slots holding a [`JsValue`](crate::JsValue).
+	pub live: u32,
+	/// Slots available for new values.
+	pub free: u32,
+	/// Sum of [`live`](Self::live) and [`free`](Self::free).
+	pub capacity: u32,
+}
+
+/// Sets how the `externref` table of the current thread grows.
+pub fn set_growth(growth: Growth) {
+	EXTERNREF_TABLE.with(|table| table.try_borrow_mut().unwrap().growth = growth);
+}
+
+#[must_use]
+pub fn stats() -> Stats {
+	EXTERNREF_TABLE.with(|table| {
+		let table = table.try_borrow().unwrap();
+		let capacity = u32::try_from(table.len - RESERVED).unwrap();
+		let free = u32::try_from(table.free.len()).unwrap();
+
+		Stats {
+			live: capacity - free,
+			free,
+			capacity,
+		}
+	})
+}
+
+/// Releases all trailing free slots of the `externref` table of the current
+/// thread and frees the memory used to track them.
+///
+/// Wasm tables can't actually shrink, released slots are reused before the
+/// table grows again. Free slots are reordered so lower slots are used
+/// first, giving later calls a better chance to release more slots.
+pub fn shrink() {
+	EXTERNREF_TABLE.with(|table| {
+		let mut table = table.try_borrow_mut().unwrap();
+		let table = &mut *table;
+
+		table
+			.free
+			.sort_unstable_by_key(|handle| Reverse(ExternrefTable::slot_unchecked(*handle)));
+
+		let trailing = table
+			.free
+			.iter()
+			.zip((RESERVED..table.len).rev())
+			.take_while(|(handle, expected)| ExternrefTable::slot_unchecked(**handle) == *expected)
+			.count();
+		table.free.drain(..trailing);
+		table.len -= i32::try_from(trailing).unwrap();
+		table.free.shrink_to_fit();
+	});
+}
+
+/// A [`JsValue`] alive in the `externref` table of the current thread.
+#[cfg(feature = "leak-detection")]
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct LiveHandle {
+	/// The result of the JS `typeof` operator on the value.
+	pub js_typeof: String,
+	/// The call site that created the value. This is the innermost caller not
+	/// annotated with `#[track_caller]`, which might be inside a function
+	/// wrapping a binding.
+	pub location: &'static Location<'static>,
+}
+
+#[cfg(feature = "leak-detection")]
+impl Display for LiveHandle {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "`{}` created at {}", self.js_typeof, self.location)
+	}
+}
+
+/// Returns all [`JsValue`]s alive in the `externref` table of the current
+/// thread, ordered by creation.
+#[cfg(feature = "leak-detection")]
+#[must_use]
+pub fn live_handles() -> Vec<LiveHandle> {
+	live_handles_since(0)
+}
+
+#[cfg(feature = "leak-detection")]
+fn live_handles_since(id: u64) -> Vec<LiveHandle> {
+	let mut handles: Vec<_> = EXTERNREF_TABLE.with(|table| {
+		table
+			.try_borrow()
+			.unwrap()
+			.locations
+			.iter()
+			.filter(|(_, (handle_id, _))| *handle_id >= id)
+			.map(|(index, (handle_id, location))| (*handle_id, *index, *location))
+			.collect()
+	});
+	handles.sort_unstable_by_key(|(id, ..)| *id);
+
+	handles
+		.into_iter()
+		.map(|(_, index, location)| {
+			// The handle is still owned elsewhere, so it must not be dropped.
+			let value = ManuallyDrop::new(JsValue::new(index));
+
+			LiveHandle {
+				js_typeof: String::from(&value.js_typeof()),
+				location,
+			}
+		})
+		.collect()
+}
+
+/// Marks a point in time to detect [`JsValue`]s created after it that are
+/// still alive.
+///
+/// Values cached in thread-locals are reported as well, except strings
+/// returned by [`JsString::intern()`](crate::JsString::intern).
+#[cfg(feature = "leak-detection")]
+#[derive(Clone, Copy, Debug)]
+pub struct Checkpoint(u64);
+
+#[cfg(feature = "leak-detection")]
+impl Checkpoint {
+	#[must_use]
+	pub fn new() -> Self {
+		EXTERNREF_TABLE.with(|table| Self(table.try_borrow().unwrap().next_id))
+	}
+
+	/// Returns all [`JsValue`]s created since this checkpoint that are still
+	/// alive, ordered by creation.
+	#[must_use]
+	pub fn live_handles(&self) -> Vec<LiveHandle> {
+		live_handles_since(self.0)
+	}
+
+	/// # Panics
+	///
+	/// If any [`JsValue`] created since this checkpoint is still alive.
+	#[track_caller]
+	pub fn assert_no_leaks(&self) {
+		let handles = self.live_handles();
+
+		if !handles.is_empty() {
+			let mut message = alloc::format!("leaked {} `JsValue`(s):", handles.len());
+
+			for handle in handles {
+				write!(message, "\n  {handle}").unwrap();
+			}
+
+			panic!("{message}");
+		}
+	}
+}
+
+#[cfg(feature = "leak-detection")]
+impl Default for Checkpoint {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[unsafe(export_name = js_bindgen::namespace!("externref.next"))]
 extern "C" fn next() -> i32 {
 	EXTERNREF_TABLE.with(|table| table.try_borrow_mut().unwrap().next())
 }
+
+/// Releases a slot returned by `externref.stack_insert`.
+pub(crate) fn stack_remove(index: i32) -> bool {
+	if (STACK_START..RESERVED).contains(&index) {
+		remove(index);
+		STACK.with(|stack| stack.set(stack.get() & !(1 << (index - STACK_START))));
+		true
+	} else {
+		false
+	}
+}
+
+/// Uses the slot above the highest occupied one, so slots are reused in LIFO
+/// order. Falls back to [`ExternrefTable`] if the stack is exhausted.
+#[unsafe(export_name = js_bindgen::namespace!("externref.stack_next"))]
+extern "C" fn stack_next() -> i32 {
+	STACK.with(|stack| {
+		let used = stack.get();
+		let top = i32::try_from(u64::BITS - used.leading_zeros()).unwrap();
+
+		if top < STACK_LEN {
+			stack.set(used | (1 << top));
+			STACK_START + top
+		} else {
+			next()
+		}
+	})
+}
+
+#[cfg(debug_assertions)]
+#[unsafe(export_name = js_bindgen::namespace!("externref.slot"))]
+extern "C" fn slot(handle: i32) -> i32 {
+	// Reserved slots never change.
+	if handle < RESERVED {
+		handle
+	} else {
+		EXTERNREF_TABLE
+			.with(|table| table.try_borrow().unwrap().slot(handle))
+			.unwrap_or_else(|current| stale(handle, current))
+	}
+}
+
+/// Must not be called while [`EXTERNREF_TABLE`] is borrowed, panic hooks might
+/// create new [`JsValue`](crate::JsValue)s.
+fn stale(handle: i32, current: i32) -> ! {
+	let slot = ExternrefTable::slot_unchecked(handle);
+
+	panic!(
+		"used stale `JsValue` handle {handle:#x}: slot {slot} was freed since, current handle is \
+		 {current:#x}"
+	)
+}
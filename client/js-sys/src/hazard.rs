@@ -38,6 +38,7 @@ pub unsafe trait Output {
 
 	type Type;
 
+	#[cfg_attr(feature = "leak-detection", track_caller)]
 	fn from_raw(raw: Self::Type) -> Self;
 }
 
@@ -1,23 +1,25 @@
 #![no_std]
 #![cfg_attr(target_feature = "atomics", feature(thread_local))]
-#![cfg_attr(
-	all(not(debug_assertions), target_arch = "wasm64"),
-	feature(simd_wasm64)
-)]
+#![cfg_attr(target_arch = "wasm64", feature(simd_wasm64))]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[macro_use]
 mod util;
 mod array;
 mod bigint;
-mod externref;
+mod error;
+pub mod externref;
 pub mod hazard;
 #[doc(hidden)]
 pub mod r#macro;
 mod number;
 mod numeric;
 mod panic;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod string;
 mod value;
 
@@ -25,12 +27,15 @@ pub use js_bindgen;
 #[cfg(feature = "macro")]
 pub use js_sys_macro::js_sys;
 
-pub use crate::array::{JsArray, TryFromJsArrayError};
-pub use crate::bigint::JsBigInt;
-pub use crate::number::JsNumber;
-pub use crate::panic::{UnwrapThrowExt, panic};
+pub use crate::array::{Iter as JsArrayIter, JsArray, TryFromJsArrayError};
+pub use crate::bigint::{JsBigInt, ParseJsBigIntError, TryFromJsBigIntError};
+pub use crate::error::{AggregateError, JsError, JsErrorSnapshot, RangeError, TypeError};
+pub use crate::number::{JsNumber, TryFromJsNumberError};
+#[cfg(feature = "std")]
+pub use crate::panic::set_panic_hook;
+pub use crate::panic::{UnwrapThrowExt, panic, panic_handler};
 pub use crate::string::JsString;
-pub use crate::value::JsValue;
+pub use crate::value::{JsRef, JsScope, JsValue};
 
 #[cfg(not(target_feature = "reference-types"))]
 compile_error!("`js-sys` requires the `reference-types` target feature");
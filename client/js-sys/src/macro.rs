@@ -157,7 +157,7 @@ pub use js_parameter;
 #[doc(hidden)]
 #[macro_export]
 macro_rules! js_output {
-	($start:literal, $direct_call:literal, $indirect_call:literal, $output:ty, $($input:ty),* $(,)?) => {{
+	($start:literal, $direct_call:expr, $indirect_call:expr, $output:ty, $($input:ty),* $(,)?) => {{
 		let indirect_condition = ::core::option::Option::is_some(&<$output as $crate::hazard::Output>::JS_CONV)
 			$(|| ::core::option::Option::is_some(&<$input as $crate::hazard::Input>::JS_CONV))*;
 
@@ -2,4 +2,129 @@
 #[path ="number.gen.rs"]
 mod number;
 
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
 pub use self::number::JsNumber;
+
+impl<T> JsNumber<T> {
+	#[must_use]
+	pub fn is_nan(&self) -> bool {
+		number::number_is_nan(self)
+	}
+
+	#[must_use]
+	pub fn is_finite(&self) -> bool {
+		number::number_is_finite(self)
+	}
+
+	#[must_use]
+	pub fn is_integer(&self) -> bool {
+		number::number_is_integer(self)
+	}
+
+	/// Returns `true` if the value is an integer in the range of
+	/// `±(2^53 - 1)`.
+	#[must_use]
+	pub fn is_safe_integer(&self) -> bool {
+		number::number_is_safe_integer(self)
+	}
+}
+
+impl<T> From<&JsNumber<T>> for f64 {
+	fn from(value: &JsNumber<T>) -> Self {
+		number::number_to_f64(value)
+	}
+}
+
+impl From<f64> for JsNumber {
+	fn from(value: f64) -> Self {
+		number::number_from_f64(value)
+	}
+}
+
+macro_rules! from {
+	($($ty:ty),*) => {$(
+		impl From<$ty> for JsNumber {
+			fn from(value: $ty) -> Self {
+				number::number_from_f64(value.into())
+			}
+		}
+	)*};
+}
+
+from!(f32, i8, i16, i32, u8, u16, u32);
+
+// `MAX as f64` is either exact or rounded up to `MAX + 1`, so `MAX + 1` is
+// always an exclusive upper bound. `MIN` is always exactly representable.
+
+macro_rules! try_from {
+	($($ty:ty),*) => {$(
+		impl TryFrom<$ty> for JsNumber {
+			type Error = TryFromJsNumberError;
+
+			#[allow(
+				clippy::allow_attributes,
+				clippy::cast_possible_truncation,
+				clippy::cast_precision_loss,
+				clippy::cast_sign_loss,
+				reason = "checked to be lossless"
+			)]
+			fn try_from(value: $ty) -> Result<Self, Self::Error> {
+				let float = value as f64;
+
+				if float < <$ty>::MAX as f64 + 1. && float as $ty == value {
+					Ok(number::number_from_f64(float))
+				} else {
+					Err(TryFromJsNumberError)
+				}
+			}
+		}
+	)*};
+}
+
+try_from!(i64, u64, i128, u128);
+
+macro_rules! try_into {
+	($($ty:ty),*) => {$(
+		impl<T> TryFrom<&JsNumber<T>> for $ty {
+			type Error = TryFromJsNumberError;
+
+			#[allow(
+				clippy::allow_attributes,
+				clippy::cast_lossless,
+				clippy::cast_possible_truncation,
+				clippy::cast_precision_loss,
+				clippy::cast_sign_loss,
+				clippy::float_cmp,
+				reason = "checked to be lossless"
+			)]
+			fn try_from(value: &JsNumber<T>) -> Result<Self, Self::Error> {
+				let value = f64::from(value);
+
+				if value >= Self::MIN as f64
+					&& value < Self::MAX as f64 + 1.
+					&& (value as Self) as f64 == value
+				{
+					Ok(value as Self)
+				} else {
+					Err(TryFromJsNumberError)
+				}
+			}
+		}
+	)*};
+}
+
+try_into!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TryFromJsNumberError;
+
+impl Display for TryFromJsNumberError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("out of range or lossy conversion attempted")
+	}
+}
+
+impl Error for TryFromJsNumberError {}
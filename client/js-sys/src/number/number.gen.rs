@@ -3,8 +3,9 @@
 #![allow(warnings)]
 
 use core::marker::PhantomData;
-use crate::JsValue;
-use crate::hazard::{Input, InputWatConv, InputJsConv, OutputJsConv, Output, JsCast, OutputWatConv};
+use core::fmt;
+use crate::{js_bindgen, r#macro, JsValue};
+use crate::hazard::{InputJsConv, OutputJsConv, OutputWatConv, Input, InputWatConv, Output, JsCast};
 
 #[repr(transparent)]
 pub struct JsNumber<T = f64> {
@@ -52,3 +53,249 @@ unsafe impl<T> Output for JsNumber<T> {
 		}
 	}
 }
+
+impl<T> fmt::Debug for JsNumber<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.value, f)
+	}
+}
+
+#[track_caller]
+pub(super) fn number_from_f64(value: f64) -> JsNumber {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"number_from_f64\" (func ${ns}.import.number_from_f64 (@sym (name \"{ns}.import.number_from_f64\")) (param {}) (result {}))){}",
+		"(func ${ns}.number_from_f64 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.number_from_f64 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < f64 >
+		(), interpolate r#macro::wat_output_import_type:: < JsNumber > (), interpolate
+		r#macro::wat_imports!((f64), JsNumber), interpolate r#macro::wat_indirect!(JsNumber),
+		interpolate < f64 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsNumber > (),
+		interpolate r#macro::wat_input!(f64), interpolate r#macro::wat_output!(JsNumber),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "number_from_f64",
+		required_embeds = [
+			r#macro::js_input_embed::<f64>(),
+			r#macro::js_output_embed::<JsNumber>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (f64), JsNumber),
+		interpolate r#macro::js_parameter!("value", f64),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.Number",
+			"globalThis.Number(value)",
+			JsNumber,
+			f64,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("number_from_f64")]
+		fn number_from_f64(value: <f64 as Input>::Type) -> <JsNumber as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { number_from_f64(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn number_to_f64<T>(value: &JsNumber<T>) -> f64 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"number_to_f64\" (func ${ns}.import.number_to_f64 (@sym (name \"{ns}.import.number_to_f64\")) (param {}) (result {}))){}",
+		"(func ${ns}.number_to_f64 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.number_to_f64 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsNumber > (), interpolate r#macro::wat_output_import_type:: < f64 > (), interpolate
+		r#macro::wat_imports!((& JsNumber), f64), interpolate r#macro::wat_indirect!(f64),
+		interpolate < & JsNumber as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < f64 >
+		(), interpolate r#macro::wat_input!(& JsNumber), interpolate r#macro::wat_output!(f64),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "number_to_f64",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsNumber>(),
+			r#macro::js_output_embed::<f64>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsNumber), f64),
+		interpolate r#macro::js_parameter!("value", &JsNumber),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.Number",
+			"globalThis.Number(value)",
+			f64,
+			&JsNumber,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("number_to_f64")]
+		fn number_to_f64(value: <&JsNumber as Input>::Type) -> <f64 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { number_to_f64(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn number_is_nan<T>(value: &JsNumber<T>) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"Number.number_is_nan\" (func ${ns}.import.Number.number_is_nan (@sym (name \"{ns}.import.Number.number_is_nan\")) (param {}) (result {}))){}",
+		"(func ${ns}.Number.number_is_nan (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.Number.number_is_nan (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsNumber > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsNumber), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsNumber as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsNumber), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "Number.number_is_nan",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsNumber>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsNumber), bool),
+		interpolate r#macro::js_parameter!("value", &JsNumber),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.Number.isNaN",
+			"globalThis.Number.isNaN(value)",
+			bool,
+			&JsNumber,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("Number.number_is_nan")]
+		fn number_is_nan(value: <&JsNumber as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { number_is_nan(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn number_is_finite<T>(value: &JsNumber<T>) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"Number.number_is_finite\" (func ${ns}.import.Number.number_is_finite (@sym (name \"{ns}.import.Number.number_is_finite\")) (param {}) (result {}))){}",
+		"(func ${ns}.Number.number_is_finite (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.Number.number_is_finite (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsNumber > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsNumber), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsNumber as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsNumber), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "Number.number_is_finite",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsNumber>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsNumber), bool),
+		interpolate r#macro::js_parameter!("value", &JsNumber),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.Number.isFinite",
+			"globalThis.Number.isFinite(value)",
+			bool,
+			&JsNumber,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("Number.number_is_finite")]
+		fn number_is_finite(value: <&JsNumber as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { number_is_finite(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn number_is_integer<T>(value: &JsNumber<T>) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"Number.number_is_integer\" (func ${ns}.import.Number.number_is_integer (@sym (name \"{ns}.import.Number.number_is_integer\")) (param {}) (result {}))){}",
+		"(func ${ns}.Number.number_is_integer (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.Number.number_is_integer (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsNumber > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsNumber), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsNumber as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsNumber), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "Number.number_is_integer",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsNumber>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsNumber), bool),
+		interpolate r#macro::js_parameter!("value", &JsNumber),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.Number.isInteger",
+			"globalThis.Number.isInteger(value)",
+			bool,
+			&JsNumber,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("Number.number_is_integer")]
+		fn number_is_integer(value: <&JsNumber as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { number_is_integer(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn number_is_safe_integer<T>(value: &JsNumber<T>) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"Number.number_is_safe_integer\" (func ${ns}.import.Number.number_is_safe_integer (@sym (name \"{ns}.import.Number.number_is_safe_integer\")) (param {}) (result {}))){}",
+		"(func ${ns}.Number.number_is_safe_integer (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.Number.number_is_safe_integer (@reloc){}", ")",
+		ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsNumber > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsNumber), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsNumber as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsNumber), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "Number.number_is_safe_integer",
+		required_embeds = [
+			r#macro::js_input_embed::<&JsNumber>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsNumber), bool),
+		interpolate r#macro::js_parameter!("value", &JsNumber),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			"globalThis.Number.isSafeInteger",
+			"globalThis.Number.isSafeInteger(value)",
+			bool,
+			&JsNumber,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("Number.number_is_safe_integer")]
+		fn number_is_safe_integer(value: <&JsNumber as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { number_is_safe_integer(Input::into_raw(value)) })
+}
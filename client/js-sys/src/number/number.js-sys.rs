@@ -1,4 +1,25 @@
 #[js_sys]
 extern "js-sys" {
 	pub type JsNumber<T = f64>;
+
+	#[js_sys(js_name = "Number")]
+	pub(super) fn number_from_f64(value: f64) -> JsNumber;
+
+	#[js_sys(js_name = "Number")]
+	pub(super) fn number_to_f64<T>(#[js_sys(type = &JsNumber)] value: &JsNumber<T>) -> f64;
+}
+
+#[js_sys(namespace = "Number")]
+extern "js-sys" {
+	#[js_sys(js_name = "isNaN")]
+	pub(super) fn number_is_nan<T>(#[js_sys(type = &JsNumber)] value: &JsNumber<T>) -> bool;
+
+	#[js_sys(js_name = "isFinite")]
+	pub(super) fn number_is_finite<T>(#[js_sys(type = &JsNumber)] value: &JsNumber<T>) -> bool;
+
+	#[js_sys(js_name = "isInteger")]
+	pub(super) fn number_is_integer<T>(#[js_sys(type = &JsNumber)] value: &JsNumber<T>) -> bool;
+
+	#[js_sys(js_name = "isSafeInteger")]
+	pub(super) fn number_is_safe_integer<T>(#[js_sys(type = &JsNumber)] value: &JsNumber<T>) -> bool;
 }
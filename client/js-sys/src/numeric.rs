@@ -101,8 +101,12 @@ unsafe impl Input for u128 {
 	const WAT_TYPE: &str = Self::Type::WAT_TYPE;
 	const WAT_CONV: Option<InputWatConv> = Self::Type::WAT_CONV;
 	const JS_CONV: Option<InputJsConv> = Some(InputJsConv {
-		embed: Some(("js_sys", "numeric.u128.decode")),
-		pre: " = this.#jsEmbed.js_sys['numeric.u128.decode'](",
+		embed: Some((js_bindgen::namespace!(), "numeric.u128.decode")),
+		pre: concat!(
+			" = this.#jsEmbed.",
+			js_bindgen::namespace!(),
+			"['numeric.u128.decode']("
+		),
 		post: Some(")"),
 	});
 
@@ -110,13 +114,14 @@ unsafe impl Input for u128 {
 
 	fn into_raw(self) -> Self::Type {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "numeric.u128.decode",
-			required_embeds = [("js_sys", "view.getBigUint64")],
+			required_embeds = [(js_bindgen::namespace!(), "view.getBigUint64")],
 			"(ptr) => {{",
-			"	const [lo, hi] = this.#jsEmbed.js_sys['view.getBigUint64'](ptr, 2)",
+			"	const [lo, hi] = this.#jsEmbed.{ns}['view.getBigUint64'](ptr, 2)",
 			"	return lo | (hi << 64n)",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		ExternValue::new(AlignedValue(self.to_le_bytes()))
@@ -128,18 +133,25 @@ unsafe impl Output for u128 {
 	const WAT_TYPE: &str = WAT_PTR_TYPE;
 	const WAT_CONV: Option<OutputWatConv> = Some(OutputWatConv {
 		import: Some(const_concat!(
-			"(import \"env\" \"js_sys.numeric.128\" (func $js_sys.numeric.128 (@sym) (param i64 \
-			 i64 ",
+			"(import \"env\" \"",
+			js_bindgen::namespace!("numeric.128"),
+			"\" (func $",
+			js_bindgen::namespace!("numeric.128"),
+			" (@sym) (param i64 i64 ",
 			WAT_PTR_TYPE,
 			")))"
 		)),
 		direct: false,
-		conv: "call $js_sys.numeric.128 (@reloc)",
+		conv: concat!("call $", js_bindgen::namespace!("numeric.128"), " (@reloc)"),
 		r#type: "i64 i64",
 	});
 	const JS_CONV: Option<OutputJsConv> = Some(OutputJsConv {
-		embed: Some(("js_sys", "numeric.128.encode")),
-		pre: "this.#jsEmbed.js_sys['numeric.128.encode'](",
+		embed: Some((js_bindgen::namespace!(), "numeric.128.encode")),
+		pre: concat!(
+			"this.#jsEmbed.",
+			js_bindgen::namespace!(),
+			"['numeric.128.encode']("
+		),
 		post: ")",
 	});
 
@@ -155,8 +167,12 @@ unsafe impl Input for i128 {
 	const WAT_TYPE: &str = Self::Type::WAT_TYPE;
 	const WAT_CONV: Option<InputWatConv> = Self::Type::WAT_CONV;
 	const JS_CONV: Option<InputJsConv> = Some(InputJsConv {
-		embed: Some(("js_sys", "numeric.i128.decode")),
-		pre: " = this.#jsEmbed.js_sys['numeric.i128.decode'](",
+		embed: Some((js_bindgen::namespace!(), "numeric.i128.decode")),
+		pre: concat!(
+			" = this.#jsEmbed.",
+			js_bindgen::namespace!(),
+			"['numeric.i128.decode']("
+		),
 		post: Some(")"),
 	});
 
@@ -164,17 +180,18 @@ unsafe impl Input for i128 {
 
 	fn into_raw(self) -> Self::Type {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "numeric.i128.decode",
 			required_embeds = [
-				("js_sys", "view.getBigUint64"),
-				("js_sys", "view.getBigInt64")
+				(js_bindgen::namespace!(), "view.getBigUint64"),
+				(js_bindgen::namespace!(), "view.getBigInt64")
 			],
 			"(ptr) => {{",
-			"	const [lo] = this.#jsEmbed.js_sys['view.getBigUint64'](ptr, 1)",
-			"	const [hi] = this.#jsEmbed.js_sys['view.getBigInt64'](ptr + 8, 1)",
+			"	const [lo] = this.#jsEmbed.{ns}['view.getBigUint64'](ptr, 1)",
+			"	const [hi] = this.#jsEmbed.{ns}['view.getBigInt64'](ptr + 8, 1)",
 			"	return lo | (hi << 64n)",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		ExternValue::new(AlignedValue(self.to_le_bytes()))
@@ -186,18 +203,25 @@ unsafe impl Output for i128 {
 	const WAT_TYPE: &str = WAT_PTR_TYPE;
 	const WAT_CONV: Option<OutputWatConv> = Some(OutputWatConv {
 		import: Some(const_concat!(
-			"(import \"env\" \"js_sys.numeric.128\" (func $js_sys.numeric.128 (@sym) (param i64 \
-			 i64 ",
+			"(import \"env\" \"",
+			js_bindgen::namespace!("numeric.128"),
+			"\" (func $",
+			js_bindgen::namespace!("numeric.128"),
+			" (@sym) (param i64 i64 ",
 			WAT_PTR_TYPE,
 			")))"
 		)),
 		direct: false,
-		conv: "call $js_sys.numeric.128 (@reloc)",
+		conv: concat!("call $", js_bindgen::namespace!("numeric.128"), " (@reloc)"),
 		r#type: "i64 i64",
 	});
 	const JS_CONV: Option<OutputJsConv> = Some(OutputJsConv {
-		embed: Some(("js_sys", "numeric.128.encode")),
-		pre: "this.#jsEmbed.js_sys['numeric.128.encode'](",
+		embed: Some((js_bindgen::namespace!(), "numeric.128.encode")),
+		pre: concat!(
+			"this.#jsEmbed.",
+			js_bindgen::namespace!(),
+			"['numeric.128.encode']("
+		),
 		post: ")",
 	});
 
@@ -216,7 +240,7 @@ const _: () = {
 };
 
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "numeric.128.encode",
 	"(value) => {{",
 	"	const lo = BigInt.asIntN(64, value)",
@@ -226,9 +250,10 @@ js_bindgen::embed_js!(
 );
 
 js_bindgen::unsafe_global_wat!(
-	"(func $js_sys.numeric.128 (@sym) (param $lo i64) (param $hi i64) (param $out {})",
+	"(func ${ns}.numeric.128 (@sym) (param $lo i64) (param $hi i64) (param $out {})",
 	"  (i64.store offset=0 local.get $out local.get $lo)",
 	"  (i64.store offset=8 local.get $out local.get $hi)",
 	")",
+	ns = interpolate js_bindgen::namespace!(),
 	interpolate WAT_PTR_TYPE,
 );
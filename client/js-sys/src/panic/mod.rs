@@ -1,10 +1,15 @@
-#[cfg(not(debug_assertions))]
+#[rustfmt::skip]
+#[path ="panic.gen.rs"]
+mod panic;
+
 use alloc::format;
-#[cfg(all(not(debug_assertions), target_arch = "wasm32"))]
+use alloc::string::ToString;
+#[cfg(target_arch = "wasm32")]
 use core::arch::wasm32 as wasm;
-#[cfg(all(not(debug_assertions), target_arch = "wasm64"))]
+#[cfg(target_arch = "wasm64")]
 use core::arch::wasm64 as wasm;
 use core::fmt::Debug;
+use core::panic::{Location, PanicInfo};
 
 pub trait UnwrapThrowExt<T> {
 	#[track_caller]
@@ -72,16 +77,45 @@ impl<T, E: Debug> UnwrapThrowExt<T> for Result<T, E> {
 	}
 }
 
+/// Throws a JS `Error` with the given message and the location of the caller.
 #[track_caller]
-#[cfg(debug_assertions)]
 pub fn panic(message: &str) -> ! {
-	// TODO: actually throw.
-	panic!("{message}");
+	let location = Location::caller();
+	throw(&format!("panicked at {location}:\n{message}"))
 }
 
-#[track_caller]
-#[cfg(not(debug_assertions))]
-pub fn panic(_: &str) -> ! {
-	// TODO: print message.
-	wasm::unreachable();
+/// Logs the panic to `console.error` and throws it as a JS `Error`.
+///
+/// Intended to be called from a `#[panic_handler]` in `no_std` binaries.
+pub fn panic_handler(info: &PanicInfo<'_>) -> ! {
+	let message = info.to_string();
+	panic::console_error(&message);
+	throw(&message)
+}
+
+/// Installs a panic hook that logs panics to `console.error` and throws them
+/// as a JS `Error`, like [`panic_handler()`].
+#[cfg(feature = "std")]
+pub fn set_panic_hook() {
+	use std::boxed::Box;
+
+	std::panic::set_hook(Box::new(|info| {
+		let message = info.to_string();
+		panic::console_error(&message);
+		throw(&message)
+	}));
+}
+
+fn throw(message: &str) -> ! {
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "panic.throw",
+		"(message) => {{",
+		"	throw new Error(message)",
+		"}}",
+	);
+
+	panic::panic_throw(message);
+	// `panic.throw` never returns.
+	wasm::unreachable()
 }
@@ -0,0 +1,77 @@
+//! This file was generated by `js-sys-bindgen`.
+
+#![allow(warnings)]
+
+use crate::{js_bindgen, r#macro};
+use crate::hazard::{Input, Output};
+
+pub(super) fn panic_throw(message: &str) {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"panic_throw\" (func ${ns}.import.panic_throw (@sym (name \"{ns}.import.panic_throw\")) (param {}))){}",
+		"(func ${ns}.panic_throw (@sym) (param $message {})", "  local.get $message{}",
+		"  call ${ns}.import.panic_throw (@reloc)", ")", ns = interpolate js_bindgen::namespace!(),
+		interpolate r#macro::wat_input_import_type:: < & str > (), interpolate
+		r#macro::wat_imports!((& str),), interpolate < & str as Input > ::WAT_TYPE, interpolate
+		r#macro::wat_input!(& str),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "panic_throw",
+		required_embeds = [
+			(js_bindgen::namespace!(), "panic.throw"),
+			r#macro::js_input_embed::<&str>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(message) => {\n", (&str)),
+		interpolate r#macro::js_parameter!("message", &str),
+		interpolate r#macro::js_select!(
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['panic.throw']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['panic.throw'](message)\n}",
+			),
+			(&str),
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("panic_throw")]
+		fn panic_throw(message: <&str as Input>::Type);
+	}
+
+	unsafe { panic_throw(Input::into_raw(message)) };
+}
+
+pub(super) fn console_error(message: &str) {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"console.console_error\" (func ${ns}.import.console.console_error (@sym (name \"{ns}.import.console.console_error\")) (param {}))){}",
+		"(func ${ns}.console.console_error (@sym) (param $message {})", "  local.get $message{}",
+		"  call ${ns}.import.console.console_error (@reloc)", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & str > (),
+		interpolate r#macro::wat_imports!((& str),), interpolate < & str as Input > ::WAT_TYPE,
+		interpolate r#macro::wat_input!(& str),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "console.console_error",
+		required_embeds = [r#macro::js_input_embed::<&str>()],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(message) => {\n", (&str)),
+		interpolate r#macro::js_parameter!("message", &str),
+		interpolate r#macro::js_select!(
+			"globalThis.console.error",
+			"globalThis.console.error(message)\n}",
+			(&str),
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("console.console_error")]
+		fn console_error(message: <&str as Input>::Type);
+	}
+
+	unsafe { console_error(Input::into_raw(message)) };
+}
@@ -0,0 +1,11 @@
+#[js_sys]
+extern "js-sys" {
+	#[js_sys(js_embed = "panic.throw")]
+	pub(super) fn panic_throw(message: &str);
+}
+
+#[js_sys(namespace = "console")]
+extern "js-sys" {
+	#[js_sys(js_name = "error")]
+	pub(super) fn console_error(message: &str);
+}
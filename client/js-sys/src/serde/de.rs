@@ -0,0 +1,288 @@
+use serde::de::{
+	self, DeserializeSeed, EnumAccess, Error as _, IntoDeserializer, MapAccess, SeqAccess,
+	VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+use super::{Error, Kind};
+use crate::hazard::JsCast;
+use crate::{JsArray, JsBigInt, JsValue};
+
+/// Deserializes Rust values from JS values.
+///
+/// Numbers and `BigInt`s are accepted for all numeric types as long as the
+/// value fits. Both plain objects and `Map`s are accepted for maps and structs.
+#[derive(Debug)]
+pub struct Deserializer {
+	value: JsValue,
+}
+
+impl Deserializer {
+	#[must_use]
+	pub fn new(value: &JsValue) -> Self {
+		Self {
+			value: value.clone(),
+		}
+	}
+
+	fn entries(&self) -> JsArray {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "serde.entries",
+			"(value) => (value instanceof Map ? [...value] : Object.entries(value)).flat()",
+		);
+
+		super::serde::serde_entries(&self.value)
+	}
+
+	fn unsupported(&self) -> Error {
+		Error::custom(format_args!(
+			"unsupported JS value of type `{}`",
+			self.value.js_typeof()
+		))
+	}
+}
+
+/// Returns the integer if `number` is a safe integer.
+#[expect(
+	clippy::cast_possible_truncation,
+	clippy::cast_precision_loss,
+	clippy::float_cmp,
+	reason = "checked to be lossless"
+)]
+fn safe_integer(number: f64) -> Option<i64> {
+	const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+	let integer = number as i64;
+
+	(integer as f64 == number && (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(&integer))
+		.then_some(integer)
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+	type Error = Error;
+
+	fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		match Kind::of(&self.value) {
+			Kind::Nullish => visitor.visit_unit(),
+			Kind::Boolean => visitor.visit_bool(self.value.as_bool().unwrap_or_default()),
+			Kind::Number => {
+				let number = self.value.as_f64().unwrap_or_default();
+
+				match safe_integer(number) {
+					Some(integer) => match u64::try_from(integer) {
+						Ok(integer) => visitor.visit_u64(integer),
+						Err(_) => visitor.visit_i64(integer),
+					},
+					None => visitor.visit_f64(number),
+				}
+			}
+			Kind::BigInt => {
+				let bigint = JsBigInt::unchecked_from_ref(&self.value);
+
+				if let Ok(integer) = u64::try_from(bigint) {
+					visitor.visit_u64(integer)
+				} else if let Ok(integer) = i64::try_from(bigint) {
+					visitor.visit_i64(integer)
+				} else if let Ok(integer) = u128::try_from(bigint) {
+					visitor.visit_u128(integer)
+				} else if let Ok(integer) = i128::try_from(bigint) {
+					visitor.visit_i128(integer)
+				} else {
+					Err(Error::custom("`BigInt` exceeds 128 bits"))
+				}
+			}
+			Kind::String => visitor.visit_string(self.value.as_string().unwrap_or_default()),
+			Kind::Array => {
+				visitor.visit_seq(SeqDeserializer::new(JsArray::unchecked_from(self.value)))
+			}
+			Kind::Map | Kind::Object => visitor.visit_map(MapDeserializer {
+				entries: SeqDeserializer::new(self.entries()),
+				value: None,
+			}),
+			Kind::Other => Err(self.unsupported()),
+		}
+	}
+
+	fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+		if Kind::of(&self.value) == Kind::Nullish {
+			visitor.visit_none()
+		} else {
+			visitor.visit_some(self)
+		}
+	}
+
+	fn deserialize_newtype_struct<V: Visitor<'de>>(
+		self,
+		_: &'static str,
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		visitor.visit_newtype_struct(self)
+	}
+
+	fn deserialize_enum<V: Visitor<'de>>(
+		self,
+		_: &'static str,
+		_: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		match Kind::of(&self.value) {
+			Kind::String => visitor.visit_enum(
+				self.value
+					.as_string()
+					.unwrap_or_default()
+					.into_deserializer(),
+			),
+			Kind::Map | Kind::Object => {
+				let entries = self.entries();
+
+				if entries.length() == 2 {
+					visitor.visit_enum(VariantDeserializer {
+						variant: entries.get(0).unwrap_or(JsValue::UNDEFINED),
+						value: entries.get(1).unwrap_or(JsValue::UNDEFINED),
+					})
+				} else {
+					Err(Error::custom(
+						"expected an enum variant object with a single key",
+					))
+				}
+			}
+			_ => Err(Error::custom(
+				"expected a string or an object with a single key",
+			)),
+		}
+	}
+
+	forward_to_deserialize_any! {
+		bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+		unit_struct seq tuple tuple_struct map struct identifier ignored_any
+	}
+}
+
+struct SeqDeserializer {
+	array: JsArray,
+	index: u32,
+	len: u32,
+}
+
+impl SeqDeserializer {
+	fn new(array: JsArray) -> Self {
+		Self {
+			len: array.length(),
+			array,
+			index: 0,
+		}
+	}
+
+	fn next(&mut self) -> Option<JsValue> {
+		if self.index < self.len {
+			let value = self.array.get(self.index).unwrap_or(JsValue::UNDEFINED);
+			self.index += 1;
+			Some(value)
+		} else {
+			None
+		}
+	}
+}
+
+impl<'de> SeqAccess<'de> for SeqDeserializer {
+	type Error = Error;
+
+	fn next_element_seed<T: DeserializeSeed<'de>>(
+		&mut self,
+		seed: T,
+	) -> Result<Option<T::Value>, Self::Error> {
+		self.next()
+			.map(|value| seed.deserialize(Deserializer { value }))
+			.transpose()
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		usize::try_from(self.len - self.index).ok()
+	}
+}
+
+/// Iterates over flattened key-value pairs.
+struct MapDeserializer {
+	entries: SeqDeserializer,
+	value: Option<JsValue>,
+}
+
+impl<'de> MapAccess<'de> for MapDeserializer {
+	type Error = Error;
+
+	fn next_key_seed<K: DeserializeSeed<'de>>(
+		&mut self,
+		seed: K,
+	) -> Result<Option<K::Value>, Self::Error> {
+		let Some(key) = self.entries.next() else {
+			return Ok(None);
+		};
+
+		self.value = self.entries.next();
+		seed.deserialize(Deserializer { value: key }).map(Some)
+	}
+
+	fn next_value_seed<V: DeserializeSeed<'de>>(
+		&mut self,
+		seed: V,
+	) -> Result<V::Value, Self::Error> {
+		let value = self
+			.value
+			.take()
+			.ok_or_else(|| Error::custom("`next_value_seed()` called before `next_key_seed()`"))?;
+		seed.deserialize(Deserializer { value })
+	}
+
+	fn size_hint(&self) -> Option<usize> {
+		self.entries.size_hint().map(|len| len / 2)
+	}
+}
+
+struct VariantDeserializer {
+	variant: JsValue,
+	value: JsValue,
+}
+
+impl<'de> EnumAccess<'de> for VariantDeserializer {
+	type Error = Error;
+	type Variant = Deserializer;
+
+	fn variant_seed<V: DeserializeSeed<'de>>(
+		self,
+		seed: V,
+	) -> Result<(V::Value, Self::Variant), Self::Error> {
+		let variant = seed.deserialize(Deserializer {
+			value: self.variant,
+		})?;
+
+		Ok((variant, Deserializer { value: self.value }))
+	}
+}
+
+impl<'de> VariantAccess<'de> for Deserializer {
+	type Error = Error;
+
+	fn unit_variant(self) -> Result<(), Self::Error> {
+		de::Deserialize::deserialize(self)
+	}
+
+	fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+		self,
+		seed: T,
+	) -> Result<T::Value, Self::Error> {
+		seed.deserialize(self)
+	}
+
+	fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, Self::Error> {
+		de::Deserializer::deserialize_seq(self, visitor)
+	}
+
+	fn struct_variant<V: Visitor<'de>>(
+		self,
+		_: &'static [&'static str],
+		visitor: V,
+	) -> Result<V::Value, Self::Error> {
+		de::Deserializer::deserialize_map(self, visitor)
+	}
+}
@@ -0,0 +1,115 @@
+//! Conversion between Rust types and JS values via [`serde`].
+//!
+//! Values are built directly from JS objects, arrays and `Map`s instead of
+//! going through JSON.
+
+#[rustfmt::skip]
+#[path ="serde.gen.rs"]
+mod serde;
+mod de;
+mod ser;
+
+use alloc::string::{String, ToString};
+use core::error;
+use core::fmt::{self, Display, Formatter};
+
+use ::serde::de::DeserializeOwned;
+use ::serde::{Serialize, de as serde_de, ser as serde_ser};
+
+pub use self::de::Deserializer;
+pub use self::ser::Serializer;
+use crate::JsValue;
+
+/// Serializes `value` with the default [`Serializer`].
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, Error> {
+	value.serialize(&Serializer::new())
+}
+
+/// Deserializes a `T` from `value`.
+pub fn from_value<T: DeserializeOwned>(value: &JsValue) -> Result<T, Error> {
+	T::deserialize(Deserializer::new(value))
+}
+
+/// Error returned when (de)serialization failed.
+#[derive(Debug)]
+pub struct Error(String);
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl error::Error for Error {}
+
+impl serde_ser::Error for Error {
+	fn custom<T: Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+impl serde_de::Error for Error {
+	fn custom<T: Display>(msg: T) -> Self {
+		Self(msg.to_string())
+	}
+}
+
+/// The kind of JS value, as returned by the `serde.kind` embed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+	Nullish,
+	Boolean,
+	Number,
+	BigInt,
+	String,
+	Array,
+	Map,
+	Object,
+	Other,
+}
+
+impl Kind {
+	fn of(value: &JsValue) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "serde.kind",
+			"(value) => {{",
+			"	switch (typeof value) {{",
+			"		case 'undefined':",
+			"			return 0",
+			"		case 'boolean':",
+			"			return 1",
+			"		case 'number':",
+			"			return 2",
+			"		case 'bigint':",
+			"			return 3",
+			"		case 'string':",
+			"			return 4",
+			"		case 'object':",
+			"			if (value === null)",
+			"				return 0",
+			"			else if (Array.isArray(value))",
+			"				return 5",
+			"			else if (value instanceof Map)",
+			"				return 6",
+			"			else",
+			"				return 7",
+			"		default:",
+			"			return 8",
+			"	}}",
+			"}}",
+		);
+
+		match serde::serde_kind(value) {
+			0 => Self::Nullish,
+			1 => Self::Boolean,
+			2 => Self::Number,
+			3 => Self::BigInt,
+			4 => Self::String,
+			5 => Self::Array,
+			6 => Self::Map,
+			7 => Self::Object,
+			_ => Self::Other,
+		}
+	}
+}
@@ -0,0 +1,445 @@
+use alloc::vec::Vec;
+use core::iter;
+
+use serde::Serialize;
+use serde::ser::{
+	self, Error as _, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+	SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+
+use super::{Error, Kind};
+use crate::{JsArray, JsNumber, JsValue};
+
+/// Serializes Rust values into JS values.
+///
+/// By default 64-bit integers are serialized as numbers, failing if they are
+/// not exactly representable, and maps as plain objects, failing for keys that
+/// are not strings or numbers. 128-bit integers are always serialized as
+/// `BigInt`s.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Serializer {
+	bigint: bool,
+	map: bool,
+}
+
+impl Serializer {
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			bigint: false,
+			map: false,
+		}
+	}
+
+	/// Serializes `i64` and `u64` as `BigInt`s instead of numbers.
+	#[must_use]
+	pub const fn large_integers_as_bigint(mut self, value: bool) -> Self {
+		self.bigint = value;
+		self
+	}
+
+	/// Serializes maps as `Map`s instead of plain objects, which supports keys
+	/// of any type.
+	#[must_use]
+	pub const fn maps_as_map(mut self, value: bool) -> Self {
+		self.map = value;
+		self
+	}
+
+	fn object() -> JsValue {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "serde.object.new",
+			"() => ({{}})"
+		);
+
+		super::serde::serde_object_new()
+	}
+
+	fn map() -> JsValue {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "serde.map.new",
+			"() => new Map()"
+		);
+
+		super::serde::serde_map_new()
+	}
+
+	fn set(target: &JsValue, key: &JsValue, value: &JsValue) {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "serde.set",
+			"(target, key, value) => {{",
+			"	if (target instanceof Map)",
+			"		target.set(key, value)",
+			"	else",
+			"		target[key] = value",
+			"}}",
+		);
+
+		super::serde::serde_set(target, key, value);
+	}
+
+	fn variant(variant: &'static str, value: &JsValue) -> JsValue {
+		let object = Self::object();
+		Self::set(&object, &JsValue::from(variant), value);
+		object
+	}
+}
+
+impl<'ser> ser::Serializer for &'ser Serializer {
+	type Ok = JsValue;
+	type Error = Error;
+
+	type SerializeSeq = ArraySerializer<'ser>;
+	type SerializeTuple = ArraySerializer<'ser>;
+	type SerializeTupleStruct = ArraySerializer<'ser>;
+	type SerializeTupleVariant = VariantSerializer<ArraySerializer<'ser>>;
+	type SerializeMap = MapSerializer<'ser>;
+	type SerializeStruct = ObjectSerializer<'ser>;
+	type SerializeStructVariant = VariantSerializer<ObjectSerializer<'ser>>;
+
+	fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+		if self.bigint {
+			Ok(JsValue::from(v))
+		} else {
+			JsNumber::try_from(v)
+				.map(JsValue::from)
+				.map_err(|_| Error::custom(format_args!("`{v}` is not representable as a number")))
+		}
+	}
+
+	fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+		if self.bigint {
+			Ok(JsValue::from(v))
+		} else {
+			JsNumber::try_from(v)
+				.map(JsValue::from)
+				.map_err(|_| Error::custom(format_args!("`{v}` is not representable as a number")))
+		}
+	}
+
+	fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v.encode_utf8(&mut [0; 4]) as &str))
+	}
+
+	fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(v))
+	}
+
+	fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+		let bytes: Vec<u32> = v.iter().copied().map(u32::from).collect();
+		Ok(JsArray::from(bytes.as_slice()).into())
+	}
+
+	fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::UNDEFINED)
+	}
+
+	fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+		value.serialize(self)
+	}
+
+	fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::UNDEFINED)
+	}
+
+	fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::UNDEFINED)
+	}
+
+	fn serialize_unit_variant(
+		self,
+		_: &'static str,
+		_: u32,
+		variant: &'static str,
+	) -> Result<Self::Ok, Self::Error> {
+		Ok(JsValue::from(variant))
+	}
+
+	fn serialize_newtype_struct<T: Serialize + ?Sized>(
+		self,
+		_: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error> {
+		value.serialize(self)
+	}
+
+	fn serialize_newtype_variant<T: Serialize + ?Sized>(
+		self,
+		_: &'static str,
+		_: u32,
+		variant: &'static str,
+		value: &T,
+	) -> Result<Self::Ok, Self::Error> {
+		Ok(Serializer::variant(variant, &value.serialize(self)?))
+	}
+
+	fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+		Ok(ArraySerializer {
+			ser: self,
+			array: iter::empty().collect(),
+		})
+	}
+
+	fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_struct(
+		self,
+		_: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeTupleStruct, Self::Error> {
+		self.serialize_seq(Some(len))
+	}
+
+	fn serialize_tuple_variant(
+		self,
+		_: &'static str,
+		_: u32,
+		variant: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeTupleVariant, Self::Error> {
+		Ok(VariantSerializer {
+			variant,
+			inner: self.serialize_seq(Some(len))?,
+		})
+	}
+
+	fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+		Ok(MapSerializer {
+			ser: self,
+			target: if self.map {
+				Serializer::map()
+			} else {
+				Serializer::object()
+			},
+			key: None,
+		})
+	}
+
+	fn serialize_struct(
+		self,
+		_: &'static str,
+		_: usize,
+	) -> Result<Self::SerializeStruct, Self::Error> {
+		Ok(ObjectSerializer {
+			ser: self,
+			object: Serializer::object(),
+		})
+	}
+
+	fn serialize_struct_variant(
+		self,
+		name: &'static str,
+		_: u32,
+		variant: &'static str,
+		len: usize,
+	) -> Result<Self::SerializeStructVariant, Self::Error> {
+		Ok(VariantSerializer {
+			variant,
+			inner: self.serialize_struct(name, len)?,
+		})
+	}
+}
+
+#[doc(hidden)]
+pub struct ArraySerializer<'ser> {
+	ser: &'ser Serializer,
+	array: JsArray,
+}
+
+impl SerializeSeq for ArraySerializer<'_> {
+	type Ok = JsValue;
+	type Error = Error;
+
+	fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+		self.array.push(&value.serialize(self.ser)?);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(self.array.into())
+	}
+}
+
+impl SerializeTuple for ArraySerializer<'_> {
+	type Ok = JsValue;
+	type Error = Error;
+
+	fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+		SerializeSeq::serialize_element(self, value)
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		SerializeSeq::end(self)
+	}
+}
+
+impl SerializeTupleStruct for ArraySerializer<'_> {
+	type Ok = JsValue;
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+		SerializeSeq::serialize_element(self, value)
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		SerializeSeq::end(self)
+	}
+}
+
+#[doc(hidden)]
+pub struct MapSerializer<'ser> {
+	ser: &'ser Serializer,
+	target: JsValue,
+	key: Option<JsValue>,
+}
+
+impl SerializeMap for MapSerializer<'_> {
+	type Ok = JsValue;
+	type Error = Error;
+
+	fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+		let key = key.serialize(self.ser)?;
+
+		if !self.ser.map && !matches!(Kind::of(&key), Kind::String | Kind::Number) {
+			return Err(Error::custom(
+				"object keys must be strings or numbers, use `Serializer::maps_as_map()` instead",
+			));
+		}
+
+		self.key = Some(key);
+		Ok(())
+	}
+
+	fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+		let key = self
+			.key
+			.take()
+			.ok_or_else(|| Error::custom("`serialize_value()` called before `serialize_key()`"))?;
+		Serializer::set(&self.target, &key, &value.serialize(self.ser)?);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(self.target)
+	}
+}
+
+#[doc(hidden)]
+pub struct ObjectSerializer<'ser> {
+	ser: &'ser Serializer,
+	object: JsValue,
+}
+
+impl SerializeStruct for ObjectSerializer<'_> {
+	type Ok = JsValue;
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(
+		&mut self,
+		key: &'static str,
+		value: &T,
+	) -> Result<(), Self::Error> {
+		Serializer::set(
+			&self.object,
+			&JsValue::from(key),
+			&value.serialize(self.ser)?,
+		);
+		Ok(())
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(self.object)
+	}
+}
+
+/// Wraps the serialized value in an object with the variant name as its key.
+#[doc(hidden)]
+pub struct VariantSerializer<S> {
+	variant: &'static str,
+	inner: S,
+}
+
+impl SerializeTupleVariant for VariantSerializer<ArraySerializer<'_>> {
+	type Ok = JsValue;
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+		SerializeSeq::serialize_element(&mut self.inner, value)
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(Serializer::variant(
+			self.variant,
+			&SerializeSeq::end(self.inner)?,
+		))
+	}
+}
+
+impl SerializeStructVariant for VariantSerializer<ObjectSerializer<'_>> {
+	type Ok = JsValue;
+	type Error = Error;
+
+	fn serialize_field<T: Serialize + ?Sized>(
+		&mut self,
+		key: &'static str,
+		value: &T,
+	) -> Result<(), Self::Error> {
+		SerializeStruct::serialize_field(&mut self.inner, key, value)
+	}
+
+	fn end(self) -> Result<Self::Ok, Self::Error> {
+		Ok(Serializer::variant(
+			self.variant,
+			&SerializeStruct::end(self.inner)?,
+		))
+	}
+}
@@ -0,0 +1,211 @@
+//! This file was generated by `js-sys-bindgen`.
+
+#![allow(warnings)]
+
+use crate::{js_bindgen, r#macro};
+use crate::hazard::{Input, Output};
+use crate::{JsArray, JsValue};
+
+#[track_caller]
+pub(super) fn serde_kind(value: &JsValue) -> u32 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"serde_kind\" (func ${ns}.import.serde_kind (@sym (name \"{ns}.import.serde_kind\")) (param {}) (result {}))){}",
+		"(func ${ns}.serde_kind (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.serde_kind (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+		interpolate r#macro::wat_output_import_type:: < u32 > (), interpolate
+		r#macro::wat_imports!((& JsValue), u32), interpolate r#macro::wat_indirect!(u32),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < u32 > (),
+		interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(u32),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "serde_kind",
+		required_embeds = [
+			(js_bindgen::namespace!(), "serde.kind"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<u32>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), u32),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.kind']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.kind'](value)"),
+			u32,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("serde_kind")]
+		fn serde_kind(value: <&JsValue as Input>::Type) -> <u32 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { serde_kind(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn serde_object_new() -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"serde_object_new\" (func ${ns}.import.serde_object_new (@sym (name \"{ns}.import.serde_object_new\")) (result {}))){}",
+		"(func ${ns}.serde_object_new (@sym) (param {}) (result {})",
+		"  call ${ns}.import.serde_object_new (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_output_import_type:: < JsValue > (),
+		interpolate r#macro::wat_imports!((), JsValue), interpolate r#macro::wat_indirect!(JsValue),
+		interpolate r#macro::wat_direct:: < JsValue > (), interpolate r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "serde_object_new",
+		required_embeds = [
+			(js_bindgen::namespace!(), "serde.object.new"),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}",
+		interpolate r#macro::js_select!("", "() => {\n\treturn ", (), JsValue),
+		interpolate r#macro::js_output!(
+			"",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.object.new']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.object.new']()"),
+			JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("serde_object_new")]
+		fn serde_object_new() -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { serde_object_new() })
+}
+
+#[track_caller]
+pub(super) fn serde_map_new() -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"serde_map_new\" (func ${ns}.import.serde_map_new (@sym (name \"{ns}.import.serde_map_new\")) (result {}))){}",
+		"(func ${ns}.serde_map_new (@sym) (param {}) (result {})",
+		"  call ${ns}.import.serde_map_new (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_output_import_type:: < JsValue > (),
+		interpolate r#macro::wat_imports!((), JsValue), interpolate r#macro::wat_indirect!(JsValue),
+		interpolate r#macro::wat_direct:: < JsValue > (), interpolate r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "serde_map_new",
+		required_embeds = [
+			(js_bindgen::namespace!(), "serde.map.new"),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}",
+		interpolate r#macro::js_select!("", "() => {\n\treturn ", (), JsValue),
+		interpolate r#macro::js_output!(
+			"",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.map.new']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.map.new']()"),
+			JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("serde_map_new")]
+		fn serde_map_new() -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { serde_map_new() })
+}
+
+pub(super) fn serde_set(target: &JsValue, key: &JsValue, value: &JsValue) {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"serde_set\" (func ${ns}.import.serde_set (@sym (name \"{ns}.import.serde_set\")) (param {} {} {}))){}",
+		"(func ${ns}.serde_set (@sym) (param $target {}) (param $key {}) (param $value {})",
+		"  local.get $target{}", "  local.get $key{}", "  local.get $value{}",
+		"  call ${ns}.import.serde_set (@reloc)", ")", ns = interpolate js_bindgen::namespace!(),
+		interpolate r#macro::wat_input_import_type:: < & JsValue > (), interpolate
+		r#macro::wat_input_import_type:: < & JsValue > (), interpolate
+		r#macro::wat_input_import_type:: < & JsValue > (), interpolate r#macro::wat_imports!((&
+		JsValue),), interpolate < & JsValue as Input > ::WAT_TYPE, interpolate < & JsValue as Input
+		> ::WAT_TYPE, interpolate < & JsValue as Input > ::WAT_TYPE, interpolate
+		r#macro::wat_input!(& JsValue), interpolate r#macro::wat_input!(& JsValue), interpolate
+		r#macro::wat_input!(& JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "serde_set",
+		required_embeds = [
+			(js_bindgen::namespace!(), "serde.set"),
+			r#macro::js_input_embed::<&JsValue>(),
+		],
+		"{}{}{}{}{}",
+		interpolate r#macro::js_select!("", "(target, key, value) => {\n", (&JsValue)),
+		interpolate r#macro::js_parameter!("target", &JsValue),
+		interpolate r#macro::js_parameter!("key", &JsValue),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_select!(
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.set']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['serde.set'](target, key, value)\n}",
+			),
+			(&JsValue),
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("serde_set")]
+		fn serde_set(
+			target: <&JsValue as Input>::Type,
+			key: <&JsValue as Input>::Type,
+			value: <&JsValue as Input>::Type,
+		);
+	}
+
+	unsafe { serde_set(Input::into_raw(target), Input::into_raw(key), Input::into_raw(value)) };
+}
+
+#[track_caller]
+pub(super) fn serde_entries(value: &JsValue) -> JsArray {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"serde_entries\" (func ${ns}.import.serde_entries (@sym (name \"{ns}.import.serde_entries\")) (param {}) (result {}))){}",
+		"(func ${ns}.serde_entries (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.serde_entries (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < JsArray > (), interpolate
+		r#macro::wat_imports!((& JsValue), JsArray), interpolate r#macro::wat_indirect!(JsArray),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsArray >
+		(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(JsArray),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "serde_entries",
+		required_embeds = [
+			(js_bindgen::namespace!(), "serde.entries"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<JsArray>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), JsArray),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.entries']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['serde.entries'](value)"),
+			JsArray,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("serde_entries")]
+		fn serde_entries(value: <&JsValue as Input>::Type) -> <JsArray as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { serde_entries(Input::into_raw(value)) })
+}
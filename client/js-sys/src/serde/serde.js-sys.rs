@@ -0,0 +1,19 @@
+use crate::{JsArray, JsValue};
+
+#[js_sys]
+extern "js-sys" {
+	#[js_sys(js_embed = "serde.kind")]
+	pub(super) fn serde_kind(value: &JsValue) -> u32;
+
+	#[js_sys(js_embed = "serde.object.new")]
+	pub(super) fn serde_object_new() -> JsValue;
+
+	#[js_sys(js_embed = "serde.map.new")]
+	pub(super) fn serde_map_new() -> JsValue;
+
+	#[js_sys(js_embed = "serde.set")]
+	pub(super) fn serde_set(target: &JsValue, key: &JsValue, value: &JsValue);
+
+	#[js_sys(js_embed = "serde.entries")]
+	pub(super) fn serde_entries(value: &JsValue) -> JsArray;
+}
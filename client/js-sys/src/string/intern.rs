@@ -0,0 +1,39 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::cell::RefCell;
+
+use super::JsString;
+#[cfg(feature = "leak-detection")]
+use crate::externref::ExternrefTable;
+#[cfg(feature = "leak-detection")]
+use crate::hazard::Input;
+
+thread_local! {
+	static INTERNED: RefCell<BTreeMap<&'static str, &'static JsString>> = RefCell::new(BTreeMap::new());
+}
+
+impl JsString {
+	/// Returns a [`JsString`] from the interning cache of the current thread.
+	///
+	/// The first call with a given string decodes it, subsequent calls return
+	/// the same value without touching the `externref` table. Use this for
+	/// strings that are converted repeatedly, e.g. property keys.
+	///
+	/// Interned strings are never freed and stay in the `externref` table
+	/// until the thread exits.
+	#[must_use]
+	pub fn intern(value: &'static str) -> &'static Self {
+		INTERNED.with(|interned| {
+			if let Some(string) = interned.try_borrow().unwrap().get(value) {
+				return *string;
+			}
+
+			// Decode outside of the borrow, `JsString` might intern as well.
+			let string: &Self = Box::leak(Box::new(Self::from(value)));
+			#[cfg(feature = "leak-detection")]
+			ExternrefTable::untrack(Input::into_raw(string));
+			interned.try_borrow_mut().unwrap().insert(value, string);
+			string
+		})
+	}
+}
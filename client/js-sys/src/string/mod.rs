@@ -1,3 +1,4 @@
+mod intern;
 #[rustfmt::skip]
 #[path ="string.gen.rs"]
 mod string;
@@ -7,15 +8,95 @@ use alloc::vec::Vec;
 use core::fmt::{self, Display, Formatter};
 
 pub use self::string::JsString;
-use crate::JsValue;
 use crate::hazard::{Input, InputJsConv, InputWatConv};
 use crate::util::{ExternSlice, PtrConst, PtrLength, PtrMut};
+use crate::{JsArray, JsRef, JsScope, JsValue};
 
 impl JsString {
 	#[must_use]
 	pub fn new(value: &JsValue) -> Self {
 		string::string_constructor(value)
 	}
+
+	/// Like [`From<&str>`](#impl-From<%26str>-for-JsString), but returns a
+	/// [`JsRef`] for values that are only passed on to the next call.
+	#[must_use]
+	pub fn scoped<'a>(_: &'a JsScope, value: &str) -> JsRef<'a, Self> {
+		// SAFETY: Parameters are correct.
+		unsafe {
+			string::string_decode_scoped(
+				PtrConst::new(value.as_bytes()),
+				PtrLength::new(value.as_bytes()),
+			)
+		}
+	}
+
+	/// Returns the length in UTF-16 code units.
+	#[must_use]
+	pub fn len_utf16(&self) -> u32 {
+		self.string_length()
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len_utf16() == 0
+	}
+
+	/// Returns the UTF-16 code unit at `index` or [`None`] if out of bounds.
+	#[must_use]
+	pub fn char_code_at(&self, index: u32) -> Option<u16> {
+		let code = self.string_char_code_at(index);
+
+		if code.is_nan() {
+			None
+		} else {
+			#[expect(
+				clippy::cast_possible_truncation,
+				clippy::cast_sign_loss,
+				reason = "always a UTF-16 code unit"
+			)]
+			Some(code as u16)
+		}
+	}
+
+	/// Returns the UTF-16 code units between `start` and `end`. Both are
+	/// clamped to the length of the string.
+	#[must_use]
+	pub fn slice(&self, start: u32, end: u32) -> Self {
+		self.string_slice(start, end)
+	}
+
+	/// Returns the UTF-16 index of the first occurrence of `search`.
+	#[must_use]
+	pub fn index_of(&self, search: &str) -> Option<u32> {
+		self.string_index_of(search, 0).try_into().ok()
+	}
+
+	#[must_use]
+	pub fn starts_with(&self, search: &str) -> bool {
+		self.string_starts_with(search)
+	}
+
+	#[must_use]
+	pub fn to_lower_case(&self) -> Self {
+		self.string_to_lower_case()
+	}
+
+	#[must_use]
+	pub fn split(&self, separator: &str) -> JsArray<Self> {
+		self.string_split(separator)
+	}
+
+	#[must_use]
+	pub fn concat(&self, other: &Self) -> Self {
+		self.string_concat(other)
+	}
+
+	/// Returns `false` if the string contains lone surrogates.
+	#[must_use]
+	pub fn is_well_formed(&self) -> bool {
+		self.string_is_well_formed()
+	}
 }
 
 impl Display for JsString {
@@ -27,13 +108,14 @@ impl Display for JsString {
 impl PartialEq<&str> for JsString {
 	fn eq(&self, other: &&str) -> bool {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "string.eq",
-			required_embeds = [("js_sys", "string.decode")],
+			required_embeds = [(js_bindgen::namespace!(), "string.decode")],
 			"(string, ptr, len) => {{",
-			"	const other = this.#jsEmbed.js_sys['string.decode'](ptr, len)",
+			"	const other = this.#jsEmbed.{ns}['string.decode'](ptr, len)",
 			"	return string === other",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		// SAFETY: Parameters are correct.
@@ -57,38 +139,67 @@ impl From<&str> for JsString {
 	fn from(value: &str) -> Self {
 		#[cfg(any(not(target_feature = "atomics"), js_sys_target_feature = "sab"))]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "string.decode",
+			required_embeds = [(js_bindgen::namespace!(), "string.decoder")],
 			"(ptr, len) => {{",
-			"	const decoder = new TextDecoder('utf-8', {{",
-			"		fatal: false,",
-			"		ignoreBOM: false,",
-			"	}})",
 			"	const view = new Uint8Array(this.#memory.buffer, ptr, len)",
-			"	return decoder.decode(view)",
+			"",
+			"	if (len <= 16) {{",
+			"		let string = ''",
+			"",
+			"		for (let index = 0; index < len; index++) {{",
+			"			const byte = view[index]",
+			"",
+			"			if (byte > 0x7F)",
+			"				return this.#jsEmbed.{ns}['string.decoder'].decode(view)",
+			"",
+			"			string += String.fromCharCode(byte)",
+			"		}}",
+			"",
+			"		return string",
+			"	}}",
+			"",
+			"	return this.#jsEmbed.{ns}['string.decoder'].decode(view)",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		#[cfg(all(target_feature = "atomics", not(js_sys_target_feature = "sab")))]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "string.decode",
-			required_embeds = [("js_sys", "string.sab")],
+			required_embeds = [
+				(js_bindgen::namespace!(), "string.decoder"),
+				(js_bindgen::namespace!(), "string.sab")
+			],
 			"(ptr, len) => {{",
-			"	const decoder = new TextDecoder('utf-8', {{",
-			"		fatal: false,",
-			"		ignoreBOM: false,",
-			"	}})",
-			"	let view",
-			"",
-			"	if (this.#jsEmbed.js_sys['string.sab']) {{",
-			"		view = new Uint8Array(this.#memory.buffer, ptr, len)",
-			"	}} else {{",
-			"		view = new Uint8Array(this.#memory.buffer).slice(ptr, ptr + len)",
+			"	let view = new Uint8Array(this.#memory.buffer, ptr, len)",
+			"",
+			"	if (len <= 16) {{",
+			"		let string = ''",
+			"",
+			"		for (let index = 0; index < len; index++) {{",
+			"			const byte = view[index]",
+			"",
+			"			if (byte > 0x7F) {{",
+			"				string = undefined",
+			"				break",
+			"			}}",
+			"",
+			"			string += String.fromCharCode(byte)",
+			"		}}",
+			"",
+			"		if (string !== undefined)",
+			"			return string",
 			"	}}",
 			"",
-			"	return decoder.decode(view)",
+			"	if (!this.#jsEmbed.{ns}['string.sab'])",
+			"		view = view.slice()",
+			"",
+			"	return this.#jsEmbed.{ns}['string.decoder'].decode(view)",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		// SAFETY: Parameters are correct.
@@ -104,71 +215,142 @@ impl From<&str> for JsString {
 impl From<&JsString> for String {
 	fn from(value: &JsString) -> Self {
 		js_bindgen::embed_js!(
-			module = "js_sys",
-			name = "string.utf8_length",
-			"(string) => new TextEncoder().encode(string).length",
+			module = js_bindgen::namespace!(),
+			name = "string.encode_ascii",
+			"(string, ptr, len) => {{",
+			"	const view = new Uint8Array(this.#memory.buffer, ptr, len)",
+			// The buffer might have more capacity than the string has code units.
+			"	const end = Math.min(len, string.length)",
+			"	let index = 0",
+			"",
+			"	for (; index < end; index++) {{",
+			"		const code = string.charCodeAt(index)",
+			"",
+			"		if (code > 0x7F)",
+			"			break",
+			"",
+			"		view[index] = code",
+			"	}}",
+			"",
+			"	return index",
+			"}}",
 		);
 
 		#[cfg(any(not(target_feature = "atomics"), js_sys_target_feature = "sab"))]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "string.encode",
-			"(string, ptr, len) => {{",
+			required_embeds = [(js_bindgen::namespace!(), "string.encoder")],
+			"(string, start, ptr, len) => {{",
 			"	const view = new Uint8Array(this.#memory.buffer, ptr, len)",
-			"	new TextEncoder().encodeInto(string, view)",
+			"	return this.#jsEmbed.{ns}['string.encoder'].encodeInto(string.slice(start), \
+			 view).written",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		#[cfg(all(target_feature = "atomics", not(js_sys_target_feature = "sab")))]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "string.encode",
-			required_embeds = [("js_sys", "string.sab")],
-			"(string, ptr, len) => {{",
-			"	if (this.#jsEmbed.js_sys['string.sab']) {{",
+			required_embeds = [
+				(js_bindgen::namespace!(), "string.encoder"),
+				(js_bindgen::namespace!(), "string.sab")
+			],
+			"(string, start, ptr, len) => {{",
+			"	const encoder = this.#jsEmbed.{ns}['string.encoder']",
+			"",
+			"	if (this.#jsEmbed.{ns}['string.sab']) {{",
 			"		const view = new Uint8Array(this.#memory.buffer, ptr, len)",
-			"		new TextEncoder().encodeInto(string, view)",
+			"		return encoder.encodeInto(string.slice(start), view).written",
 			"	}} else {{",
-			"		const bytes = new TextEncoder().encode(string)",
+			"		const bytes = encoder.encode(string.slice(start))",
 			"		new Uint8Array(this.#memory.buffer).set(bytes, ptr)",
+			"		return bytes.length",
 			"	}}",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
-		let len = string::string_utf8_length(value);
-		#[cfg(target_arch = "wasm32")]
-		assert!(
-			len < f64::from(u32::MAX),
-			"found string length bigger than `usize::MAX`"
-		);
-		#[expect(
-			clippy::cast_possible_truncation,
-			clippy::cast_sign_loss,
-			reason = "in practice this is memory constrained"
-		)]
-		let len = len as usize;
-
+		// Optimistically assume ASCII, which encodes every UTF-16 code unit to
+		// exactly one byte.
+		let len = value.len_utf16() as usize;
 		let mut vec = Vec::with_capacity(len);
 		// SAFETY: Parameters are correct.
-		unsafe {
-			string::string_encode(
+		let ascii = unsafe {
+			string::string_encode_ascii(
 				value,
-				PtrMut::new(&mut vec),
+				PtrMut::from_uninit_slice(vec.spare_capacity_mut()),
 				PtrLength::from_uninit_slice(vec.spare_capacity_mut()),
-			);
+			)
+		};
+		// SAFETY: Initialized by `string.encode_ascii`.
+		unsafe { vec.set_len(ascii as usize) };
+
+		if vec.len() != len {
+			// The remaining UTF-16 code units encode to at most three bytes each.
+			vec.reserve_exact((len - vec.len()) * 3);
+			// SAFETY: Parameters are correct.
+			let written = unsafe {
+				string::string_encode(
+					value,
+					ascii,
+					PtrMut::from_uninit_slice(vec.spare_capacity_mut()),
+					PtrLength::from_uninit_slice(vec.spare_capacity_mut()),
+				)
+			};
+			// SAFETY: Initialized by `string.encode`.
+			unsafe { vec.set_len(vec.len() + written as usize) };
 		}
 
-		// SAFETY:
+		// SAFETY: `TextEncoder` only outputs valid UTF-8.
+		unsafe { Self::from_utf8_unchecked(vec) }
+	}
+}
+
+impl From<&JsString> for Vec<u16> {
+	fn from(value: &JsString) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "string.encode_utf16",
+			required_embeds = [(js_bindgen::namespace!(), "view.setUint16")],
+			"(string, ptr) => {{",
+			"	const codes = new Array(string.length)",
+			"	for (let index = 0; index < string.length; index++) {{",
+			"		codes[index] = string.charCodeAt(index)",
+			"	}}",
+			"	this.#jsEmbed.{ns}['view.setUint16'](ptr, codes)",
+			"}}",
+			ns = interpolate js_bindgen::namespace!(),
+		);
+
+		let len = value.len_utf16() as usize;
+		let mut vec = Self::with_capacity(len);
+		// SAFETY: Parameters are correct.
 		unsafe {
+			string::string_encode_utf16(value, PtrMut::from_uninit_slice(vec.spare_capacity_mut()));
 			vec.set_len(len);
-			Self::from_utf8_unchecked(vec)
 		}
+
+		vec
 	}
 }
 
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "string.decoder",
+	"new TextDecoder('utf-8', {{ fatal: false, ignoreBOM: false }})",
+);
+
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "string.encoder",
+	"new TextEncoder()"
+);
+
 #[cfg(all(target_feature = "atomics", not(js_sys_target_feature = "sab")))]
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "string.sab",
 	"(() => {{",
 	"	if (this.#memory.buffer instanceof ArrayBuffer)",
@@ -189,8 +371,12 @@ unsafe impl Input for &str {
 	const WAT_TYPE: &'static str = Self::Type::WAT_TYPE;
 	const WAT_CONV: Option<InputWatConv> = Self::Type::WAT_CONV;
 	const JS_CONV: Option<InputJsConv> = Some(InputJsConv {
-		embed: Some(("js_sys", "string.rust.decode")),
-		pre: " = this.#jsEmbed.js_sys['string.rust.decode'](",
+		embed: Some((js_bindgen::namespace!(), "string.rust.decode")),
+		pre: concat!(
+			" = this.#jsEmbed.",
+			js_bindgen::namespace!(),
+			"['string.rust.decode']("
+		),
 		post: Some(")"),
 	});
 
@@ -198,13 +384,17 @@ unsafe impl Input for &str {
 
 	fn into_raw(self) -> Self::Type {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "string.rust.decode",
-			required_embeds = [("js_sys", "extern_ref"), ("js_sys", "string.decode")],
+			required_embeds = [
+				(js_bindgen::namespace!(), "extern_ref"),
+				(js_bindgen::namespace!(), "string.decode")
+			],
 			"(dataPtr) => {{",
-			"	const {{ ptr, len }} = this.#jsEmbed.js_sys['extern_ref'](dataPtr)",
-			"	return this.#jsEmbed.js_sys['string.decode'](ptr, len)",
+			"	const {{ ptr, len }} = this.#jsEmbed.{ns}['extern_ref'](dataPtr)",
+			"	return this.#jsEmbed.{ns}['string.decode'](ptr, len)",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		ExternSlice::new(self.as_bytes())
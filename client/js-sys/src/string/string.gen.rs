@@ -2,11 +2,13 @@
 
 #![allow(warnings)]
 
+use core::fmt;
 use crate::{js_bindgen, r#macro, JsValue};
 use crate::hazard::{InputJsConv, OutputJsConv, OutputWatConv, Input, InputWatConv, Output, JsCast};
+use crate::{JsArray, JsRef};
 use crate::util::{PtrConst, PtrLength, PtrMut};
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 #[repr(transparent)]
 pub struct JsString(JsValue);
 
@@ -48,20 +50,28 @@ unsafe impl Output for JsString {
 	}
 }
 
+impl fmt::Debug for JsString {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.0, f)
+	}
+}
+
+#[track_caller]
 pub(super) fn string_constructor(value: &JsValue) -> JsString {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"string_constructor\" (func $js_sys.import.string_constructor (@sym (name \"js_sys.import.string_constructor\")) (param {}) (result {}))){}",
-		"(func $js_sys.string_constructor (@sym) (param {}) (param $value {}) (result {})",
-		"  local.get $value{}", "  call $js_sys.import.string_constructor (@reloc){}", ")",
-		interpolate r#macro::wat_input_import_type:: < & JsValue > (), interpolate
-		r#macro::wat_output_import_type:: < JsString > (), interpolate r#macro::wat_imports!((&
-		JsValue), JsString), interpolate r#macro::wat_indirect!(JsString), interpolate < & JsValue
-		as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsString > (), interpolate
-		r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(JsString),
+		"(import \"{ns}\" \"string_constructor\" (func ${ns}.import.string_constructor (@sym (name \"{ns}.import.string_constructor\")) (param {}) (result {}))){}",
+		"(func ${ns}.string_constructor (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.string_constructor (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < JsString > (), interpolate
+		r#macro::wat_imports!((& JsValue), JsString), interpolate r#macro::wat_indirect!(JsString),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsString
+		> (), interpolate r#macro::wat_input!(& JsValue), interpolate
+		r#macro::wat_output!(JsString),
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "string_constructor",
 		required_embeds = [
 			r#macro::js_input_embed::<&JsValue>(),
@@ -80,20 +90,509 @@ pub(super) fn string_constructor(value: &JsValue) -> JsString {
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.string_constructor"]
+		#[link_name = js_bindgen::namespace!("string_constructor")]
 		fn string_constructor(value: <&JsValue as Input>::Type) -> <JsString as Output>::Type;
 	}
 
 	Output::from_raw(unsafe { string_constructor(Input::into_raw(value)) })
 }
 
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_length(self: &JsString) -> u32 {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_length\" (func ${ns}.import.string_length (@sym (name \"{ns}.import.string_length\")) (param {}) (result {}))){}",
+			"(func ${ns}.string_length (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.string_length (@reloc){}", ")", ns =
+			interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < u32 > (), interpolate
+			r#macro::wat_imports!((& JsValue), u32), interpolate r#macro::wat_indirect!(u32),
+			interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < u32 >
+			(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(u32),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_length",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<u32>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), u32),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.length",
+				"self.length",
+				u32,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_length")]
+			fn string_length(this: <&JsValue as Input>::Type) -> <u32 as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { string_length(Input::into_raw(self)) })
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_char_code_at(self: &JsString, index: u32) -> f64 {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_char_code_at\" (func ${ns}.import.string_char_code_at (@sym (name \"{ns}.import.string_char_code_at\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.string_char_code_at (@sym) (param {}) (param $self {}) (param $index {}) (result {})",
+			"  local.get $self{}", "  local.get $index{}",
+			"  call ${ns}.import.string_char_code_at (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+			r#macro::wat_output_import_type:: < f64 > (), interpolate r#macro::wat_imports!((&
+			JsValue, u32), f64), interpolate r#macro::wat_indirect!(f64), interpolate < & JsValue as
+			Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE, interpolate
+			r#macro::wat_direct:: < f64 > (), interpolate r#macro::wat_input!(& JsValue),
+			interpolate r#macro::wat_input!(u32), interpolate r#macro::wat_output!(f64),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_char_code_at",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<u32>(),
+				r#macro::js_output_embed::<f64>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, index) => ",
+				"(self, index) => {\n",
+				(&JsValue, u32),
+				f64,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("index", u32),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.charCodeAt(index)",
+				"self.charCodeAt(index)",
+				f64,
+				&JsValue,
+				u32,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_char_code_at")]
+			fn string_char_code_at(
+				this: <&JsValue as Input>::Type,
+				index: <u32 as Input>::Type,
+			) -> <f64 as Output>::Type;
+		}
+
+		Output::from_raw(unsafe {
+			string_char_code_at(Input::into_raw(self), Input::into_raw(index))
+		})
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_slice(self: &JsString, start: u32, end: u32) -> JsString {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_slice\" (func ${ns}.import.string_slice (@sym (name \"{ns}.import.string_slice\")) (param {} {} {}) (result {}))){}",
+			"(func ${ns}.string_slice (@sym) (param {}) (param $self {}) (param $start {}) (param $end {}) (result {})",
+			"  local.get $self{}", "  local.get $start{}", "  local.get $end{}",
+			"  call ${ns}.import.string_slice (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+			r#macro::wat_input_import_type:: < u32 > (), interpolate
+			r#macro::wat_output_import_type:: < JsString > (), interpolate r#macro::wat_imports!((&
+			JsValue, u32), JsString), interpolate r#macro::wat_indirect!(JsString), interpolate < &
+			JsValue as Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE, interpolate <
+			u32 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsString > (),
+			interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_input!(u32),
+			interpolate r#macro::wat_input!(u32), interpolate r#macro::wat_output!(JsString),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_slice",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<u32>(),
+				r#macro::js_output_embed::<JsString>(),
+			],
+			"{}{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, start, end) => ",
+				"(self, start, end) => {\n",
+				(&JsValue, u32),
+				JsString,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("start", u32),
+			interpolate r#macro::js_parameter!("end", u32),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.slice(start, end)",
+				"self.slice(start, end)",
+				JsString,
+				&JsValue,
+				u32,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_slice")]
+			fn string_slice(
+				this: <&JsValue as Input>::Type,
+				start: <u32 as Input>::Type,
+				end: <u32 as Input>::Type,
+			) -> <JsString as Output>::Type;
+		}
+
+		Output::from_raw(unsafe {
+			string_slice(Input::into_raw(self), Input::into_raw(start), Input::into_raw(end))
+		})
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_index_of(self: &JsString, search: &str, position: u32) -> i32 {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_index_of\" (func ${ns}.import.string_index_of (@sym (name \"{ns}.import.string_index_of\")) (param {} {} {}) (result {}))){}",
+			"(func ${ns}.string_index_of (@sym) (param {}) (param $self {}) (param $search {}) (param $position {}) (result {})",
+			"  local.get $self{}", "  local.get $search{}", "  local.get $position{}",
+			"  call ${ns}.import.string_index_of (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & str > (), interpolate
+			r#macro::wat_input_import_type:: < u32 > (), interpolate
+			r#macro::wat_output_import_type:: < i32 > (), interpolate r#macro::wat_imports!((&
+			JsValue, & str, u32), i32), interpolate r#macro::wat_indirect!(i32), interpolate < &
+			JsValue as Input > ::WAT_TYPE, interpolate < & str as Input > ::WAT_TYPE, interpolate <
+			u32 as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < i32 > (), interpolate
+			r#macro::wat_input!(& JsValue), interpolate r#macro::wat_input!(& str), interpolate
+			r#macro::wat_input!(u32), interpolate r#macro::wat_output!(i32),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_index_of",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<&str>(),
+				r#macro::js_input_embed::<u32>(),
+				r#macro::js_output_embed::<i32>(),
+			],
+			"{}{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, search, position) => ",
+				"(self, search, position) => {\n",
+				(&JsValue, &str, u32),
+				i32,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("search", &str),
+			interpolate r#macro::js_parameter!("position", u32),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.indexOf(search, position)",
+				"self.indexOf(search, position)",
+				i32,
+				&JsValue,
+				&str,
+				u32,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_index_of")]
+			fn string_index_of(
+				this: <&JsValue as Input>::Type,
+				search: <&str as Input>::Type,
+				position: <u32 as Input>::Type,
+			) -> <i32 as Output>::Type;
+		}
+
+		Output::from_raw(unsafe {
+			string_index_of(
+				Input::into_raw(self),
+				Input::into_raw(search),
+				Input::into_raw(position),
+			)
+		})
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_starts_with(self: &JsString, search: &str) -> bool {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_starts_with\" (func ${ns}.import.string_starts_with (@sym (name \"{ns}.import.string_starts_with\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.string_starts_with (@sym) (param {}) (param $self {}) (param $search {}) (result {})",
+			"  local.get $self{}", "  local.get $search{}",
+			"  call ${ns}.import.string_starts_with (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & str > (), interpolate
+			r#macro::wat_output_import_type:: < bool > (), interpolate r#macro::wat_imports!((&
+			JsValue, & str), bool), interpolate r#macro::wat_indirect!(bool), interpolate < &
+			JsValue as Input > ::WAT_TYPE, interpolate < & str as Input > ::WAT_TYPE, interpolate
+			r#macro::wat_direct:: < bool > (), interpolate r#macro::wat_input!(& JsValue),
+			interpolate r#macro::wat_input!(& str), interpolate r#macro::wat_output!(bool),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_starts_with",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<&str>(),
+				r#macro::js_output_embed::<bool>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, search) => ",
+				"(self, search) => {\n",
+				(&JsValue, &str),
+				bool,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("search", &str),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.startsWith(search)",
+				"self.startsWith(search)",
+				bool,
+				&JsValue,
+				&str,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_starts_with")]
+			fn string_starts_with(
+				this: <&JsValue as Input>::Type,
+				search: <&str as Input>::Type,
+			) -> <bool as Output>::Type;
+		}
+
+		Output::from_raw(unsafe {
+			string_starts_with(Input::into_raw(self), Input::into_raw(search))
+		})
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_to_lower_case(self: &JsString) -> JsString {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_to_lower_case\" (func ${ns}.import.string_to_lower_case (@sym (name \"{ns}.import.string_to_lower_case\")) (param {}) (result {}))){}",
+			"(func ${ns}.string_to_lower_case (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.string_to_lower_case (@reloc){}", ")", ns =
+			interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < JsString > (), interpolate
+			r#macro::wat_imports!((& JsValue), JsString), interpolate
+			r#macro::wat_indirect!(JsString), interpolate < & JsValue as Input > ::WAT_TYPE,
+			interpolate r#macro::wat_direct:: < JsString > (), interpolate r#macro::wat_input!(&
+			JsValue), interpolate r#macro::wat_output!(JsString),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_to_lower_case",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<JsString>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), JsString),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.toLowerCase()",
+				"self.toLowerCase()",
+				JsString,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_to_lower_case")]
+			fn string_to_lower_case(this: <&JsValue as Input>::Type) -> <JsString as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { string_to_lower_case(Input::into_raw(self)) })
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_split(self: &JsString, separator: &str) -> JsArray<JsString> {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_split\" (func ${ns}.import.string_split (@sym (name \"{ns}.import.string_split\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.string_split (@sym) (param {}) (param $self {}) (param $separator {}) (result {})",
+			"  local.get $self{}", "  local.get $separator{}",
+			"  call ${ns}.import.string_split (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & str > (), interpolate
+			r#macro::wat_output_import_type:: < JsArray < JsString > > (), interpolate
+			r#macro::wat_imports!((& JsValue, & str), JsArray < JsString >), interpolate
+			r#macro::wat_indirect!(JsArray < JsString >), interpolate < & JsValue as Input >
+			::WAT_TYPE, interpolate < & str as Input > ::WAT_TYPE, interpolate r#macro::wat_direct::
+			< JsArray < JsString > > (), interpolate r#macro::wat_input!(& JsValue), interpolate
+			r#macro::wat_input!(& str), interpolate r#macro::wat_output!(JsArray < JsString >),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_split",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<&str>(),
+				r#macro::js_output_embed::<JsArray<JsString>>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, separator) => ",
+				"(self, separator) => {\n",
+				(&JsValue, &str),
+				JsArray<JsString>,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("separator", &str),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.split(separator)",
+				"self.split(separator)",
+				JsArray<JsString>,
+				&JsValue,
+				&str,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_split")]
+			fn string_split(
+				this: <&JsValue as Input>::Type,
+				separator: <&str as Input>::Type,
+			) -> <JsArray<JsString> as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { string_split(Input::into_raw(self), Input::into_raw(separator)) })
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_concat(self: &JsString, other: &JsString) -> JsString {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_concat\" (func ${ns}.import.string_concat (@sym (name \"{ns}.import.string_concat\")) (param {} {}) (result {}))){}",
+			"(func ${ns}.string_concat (@sym) (param {}) (param $self {}) (param $other {}) (result {})",
+			"  local.get $self{}", "  local.get $other{}",
+			"  call ${ns}.import.string_concat (@reloc){}", ")", ns = interpolate
+			js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+			interpolate r#macro::wat_input_import_type:: < & JsString > (), interpolate
+			r#macro::wat_output_import_type:: < JsString > (), interpolate r#macro::wat_imports!((&
+			JsValue, & JsString), JsString), interpolate r#macro::wat_indirect!(JsString),
+			interpolate < & JsValue as Input > ::WAT_TYPE, interpolate < & JsString as Input >
+			::WAT_TYPE, interpolate r#macro::wat_direct:: < JsString > (), interpolate
+			r#macro::wat_input!(& JsValue), interpolate r#macro::wat_input!(& JsString), interpolate
+			r#macro::wat_output!(JsString),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_concat",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_input_embed::<&JsString>(),
+				r#macro::js_output_embed::<JsString>(),
+			],
+			"{}{}{}{}",
+			interpolate r#macro::js_select!(
+				"(self, other) => ",
+				"(self, other) => {\n",
+				(&JsValue, &JsString),
+				JsString,
+			),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_parameter!("other", &JsString),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.concat(other)",
+				"self.concat(other)",
+				JsString,
+				&JsValue,
+				&JsString,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_concat")]
+			fn string_concat(
+				this: <&JsValue as Input>::Type,
+				other: <&JsString as Input>::Type,
+			) -> <JsString as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { string_concat(Input::into_raw(self), Input::into_raw(other)) })
+	}
+}
+
+impl JsString {
+	#[track_caller]
+	pub(super) fn string_is_well_formed(self: &JsString) -> bool {
+		js_bindgen::unsafe_global_wat! {
+			"(import \"{ns}\" \"string_is_well_formed\" (func ${ns}.import.string_is_well_formed (@sym (name \"{ns}.import.string_is_well_formed\")) (param {}) (result {}))){}",
+			"(func ${ns}.string_is_well_formed (@sym) (param {}) (param $self {}) (result {})",
+			"  local.get $self{}", "  call ${ns}.import.string_is_well_formed (@reloc){}", ")", ns =
+			interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+			JsValue > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+			r#macro::wat_imports!((& JsValue), bool), interpolate r#macro::wat_indirect!(bool),
+			interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool
+			> (), interpolate r#macro::wat_input!(& JsValue), interpolate
+			r#macro::wat_output!(bool),
+		}
+
+		js_bindgen::import_js! {
+			module = js_bindgen::namespace!(),
+			name = "string_is_well_formed",
+			required_embeds = [
+				r#macro::js_input_embed::<&JsValue>(),
+				r#macro::js_output_embed::<bool>(),
+			],
+			"{}{}{}",
+			interpolate r#macro::js_select!("(self) => ", "(self) => {\n", (&JsValue), bool),
+			interpolate r#macro::js_parameter!("self", &JsValue),
+			interpolate r#macro::js_output!(
+				"\treturn ",
+				"self.isWellFormed()",
+				"self.isWellFormed()",
+				bool,
+				&JsValue,
+			),
+		}
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("string_is_well_formed")]
+			fn string_is_well_formed(this: <&JsValue as Input>::Type) -> <bool as Output>::Type;
+		}
+
+		Output::from_raw(unsafe { string_is_well_formed(Input::into_raw(self)) })
+	}
+}
+
+#[track_caller]
 pub(super) unsafe fn string_eq(string: &JsString, array: PtrConst<u8>, len: PtrLength<u8>) -> bool {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"string_eq\" (func $js_sys.import.string_eq (@sym (name \"js_sys.import.string_eq\")) (param {} {} {}) (result {}))){}",
-		"(func $js_sys.string_eq (@sym) (param {}) (param $string {}) (param $array {}) (param $len {}) (result {})",
+		"(import \"{ns}\" \"string_eq\" (func ${ns}.import.string_eq (@sym (name \"{ns}.import.string_eq\")) (param {} {} {}) (result {}))){}",
+		"(func ${ns}.string_eq (@sym) (param {}) (param $string {}) (param $array {}) (param $len {}) (result {})",
 		"  local.get $string{}", "  local.get $array{}", "  local.get $len{}",
-		"  call $js_sys.import.string_eq (@reloc){}", ")", interpolate
-		r#macro::wat_input_import_type:: < & JsString > (), interpolate
+		"  call ${ns}.import.string_eq (@reloc){}", ")", ns = interpolate js_bindgen::namespace!(),
+		interpolate r#macro::wat_input_import_type:: < & JsString > (), interpolate
 		r#macro::wat_input_import_type:: < PtrConst < u8 > > (), interpolate
 		r#macro::wat_input_import_type:: < PtrLength < u8 > > (), interpolate
 		r#macro::wat_output_import_type:: < bool > (), interpolate r#macro::wat_imports!((&
@@ -106,10 +605,10 @@ pub(super) unsafe fn string_eq(string: &JsString, array: PtrConst<u8>, len: PtrL
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "string_eq",
 		required_embeds = [
-			("js_sys", "string.eq"),
+			(js_bindgen::namespace!(), "string.eq"),
 			r#macro::js_input_embed::<&JsString>(),
 			r#macro::js_input_embed::<PtrConst<u8>>(),
 			r#macro::js_input_embed::<PtrLength<u8>>(),
@@ -127,8 +626,12 @@ pub(super) unsafe fn string_eq(string: &JsString, array: PtrConst<u8>, len: PtrL
 		interpolate r#macro::js_parameter!("len", PtrLength<u8>),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['string.eq']",
-			"this.#jsEmbed.js_sys['string.eq'](string, array, len)",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['string.eq']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['string.eq'](string, array, len)",
+			),
 			bool,
 			&JsString,
 			PtrConst<u8>,
@@ -137,7 +640,7 @@ pub(super) unsafe fn string_eq(string: &JsString, array: PtrConst<u8>, len: PtrL
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.string_eq"]
+		#[link_name = js_bindgen::namespace!("string_eq")]
 		fn string_eq(
 			string: <&JsString as Input>::Type,
 			array: <PtrConst<u8> as Input>::Type,
@@ -150,14 +653,15 @@ pub(super) unsafe fn string_eq(string: &JsString, array: PtrConst<u8>, len: PtrL
 	})
 }
 
+#[track_caller]
 pub(super) unsafe fn string_decode(array: PtrConst<u8>, len: PtrLength<u8>) -> JsString {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"string_decode\" (func $js_sys.import.string_decode (@sym (name \"js_sys.import.string_decode\")) (param {} {}) (result {}))){}",
-		"(func $js_sys.string_decode (@sym) (param {}) (param $array {}) (param $len {}) (result {})",
+		"(import \"{ns}\" \"string_decode\" (func ${ns}.import.string_decode (@sym (name \"{ns}.import.string_decode\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.string_decode (@sym) (param {}) (param $array {}) (param $len {}) (result {})",
 		"  local.get $array{}", "  local.get $len{}",
-		"  call $js_sys.import.string_decode (@reloc){}", ")", interpolate
-		r#macro::wat_input_import_type:: < PtrConst < u8 > > (), interpolate
-		r#macro::wat_input_import_type:: < PtrLength < u8 > > (), interpolate
+		"  call ${ns}.import.string_decode (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < PtrConst < u8 > >
+		(), interpolate r#macro::wat_input_import_type:: < PtrLength < u8 > > (), interpolate
 		r#macro::wat_output_import_type:: < JsString > (), interpolate
 		r#macro::wat_imports!((PtrConst < u8 >, PtrLength < u8 >), JsString), interpolate
 		r#macro::wat_indirect!(JsString), interpolate < PtrConst < u8 > as Input > ::WAT_TYPE,
@@ -167,10 +671,10 @@ pub(super) unsafe fn string_decode(array: PtrConst<u8>, len: PtrLength<u8>) -> J
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "string_decode",
 		required_embeds = [
-			("js_sys", "string.decode"),
+			(js_bindgen::namespace!(), "string.decode"),
 			r#macro::js_input_embed::<PtrConst<u8>>(),
 			r#macro::js_input_embed::<PtrLength<u8>>(),
 			r#macro::js_output_embed::<JsString>(),
@@ -186,8 +690,12 @@ pub(super) unsafe fn string_decode(array: PtrConst<u8>, len: PtrLength<u8>) -> J
 		interpolate r#macro::js_parameter!("len", PtrLength<u8>),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['string.decode']",
-			"this.#jsEmbed.js_sys['string.decode'](array, len)",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['string.decode']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['string.decode'](array, len)",
+			),
 			JsString,
 			PtrConst<u8>,
 			PtrLength<u8>,
@@ -195,7 +703,7 @@ pub(super) unsafe fn string_decode(array: PtrConst<u8>, len: PtrLength<u8>) -> J
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.string_decode"]
+		#[link_name = js_bindgen::namespace!("string_decode")]
 		fn string_decode(
 			array: <PtrConst<u8> as Input>::Type,
 			len: <PtrLength<u8> as Input>::Type,
@@ -205,95 +713,269 @@ pub(super) unsafe fn string_decode(array: PtrConst<u8>, len: PtrLength<u8>) -> J
 	Output::from_raw(unsafe { string_decode(Input::into_raw(array), Input::into_raw(len)) })
 }
 
-pub(super) fn string_utf8_length(string: &JsString) -> f64 {
+#[track_caller]
+pub(super) unsafe fn string_decode_scoped(
+	array: PtrConst<u8>,
+	len: PtrLength<u8>,
+) -> JsRef<'static, JsString> {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"string_utf8_length\" (func $js_sys.import.string_utf8_length (@sym (name \"js_sys.import.string_utf8_length\")) (param {}) (result {}))){}",
-		"(func $js_sys.string_utf8_length (@sym) (param {}) (param $string {}) (result {})",
-		"  local.get $string{}", "  call $js_sys.import.string_utf8_length (@reloc){}", ")",
-		interpolate r#macro::wat_input_import_type:: < & JsString > (), interpolate
-		r#macro::wat_output_import_type:: < f64 > (), interpolate r#macro::wat_imports!((&
-		JsString), f64), interpolate r#macro::wat_indirect!(f64), interpolate < & JsString as Input
-		> ::WAT_TYPE, interpolate r#macro::wat_direct:: < f64 > (), interpolate
-		r#macro::wat_input!(& JsString), interpolate r#macro::wat_output!(f64),
+		"(import \"{ns}\" \"string_decode_scoped\" (func ${ns}.import.string_decode_scoped (@sym (name \"{ns}.import.string_decode_scoped\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.string_decode_scoped (@sym) (param {}) (param $array {}) (param $len {}) (result {})",
+		"  local.get $array{}", "  local.get $len{}",
+		"  call ${ns}.import.string_decode_scoped (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < PtrConst < u8 > >
+		(), interpolate r#macro::wat_input_import_type:: < PtrLength < u8 > > (), interpolate
+		r#macro::wat_output_import_type:: < JsRef < 'static, JsString > > (), interpolate
+		r#macro::wat_imports!((PtrConst < u8 >, PtrLength < u8 >), JsRef < 'static, JsString >),
+		interpolate r#macro::wat_indirect!(JsRef < 'static, JsString >), interpolate < PtrConst < u8
+		> as Input > ::WAT_TYPE, interpolate < PtrLength < u8 > as Input > ::WAT_TYPE, interpolate
+		r#macro::wat_direct:: < JsRef < 'static, JsString > > (), interpolate
+		r#macro::wat_input!(PtrConst < u8 >), interpolate r#macro::wat_input!(PtrLength < u8 >),
+		interpolate r#macro::wat_output!(JsRef < 'static, JsString >),
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
-		name = "string_utf8_length",
+		module = js_bindgen::namespace!(),
+		name = "string_decode_scoped",
 		required_embeds = [
-			("js_sys", "string.utf8_length"),
-			r#macro::js_input_embed::<&JsString>(),
-			r#macro::js_output_embed::<f64>(),
+			(js_bindgen::namespace!(), "string.decode"),
+			r#macro::js_input_embed::<PtrConst<u8>>(),
+			r#macro::js_input_embed::<PtrLength<u8>>(),
+			r#macro::js_output_embed::<JsRef<'static, JsString>>(),
 		],
-		"{}{}{}",
-		interpolate r#macro::js_select!("", "(string) => {\n", (&JsString), f64),
-		interpolate r#macro::js_parameter!("string", &JsString),
+		"{}{}{}{}",
+		interpolate r#macro::js_select!(
+			"",
+			"(array, len) => {\n",
+			(PtrConst<u8>, PtrLength<u8>),
+			JsRef<'static, JsString>,
+		),
+		interpolate r#macro::js_parameter!("array", PtrConst<u8>),
+		interpolate r#macro::js_parameter!("len", PtrLength<u8>),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['string.utf8_length']",
-			"this.#jsEmbed.js_sys['string.utf8_length'](string)",
-			f64,
-			&JsString,
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['string.decode']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['string.decode'](array, len)",
+			),
+			JsRef<'static, JsString>,
+			PtrConst<u8>,
+			PtrLength<u8>,
 		),
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.string_utf8_length"]
-		fn string_utf8_length(string: <&JsString as Input>::Type) -> <f64 as Output>::Type;
+		#[link_name = js_bindgen::namespace!("string_decode_scoped")]
+		fn string_decode_scoped(
+			array: <PtrConst<u8> as Input>::Type,
+			len: <PtrLength<u8> as Input>::Type,
+		) -> <JsRef<'static, JsString> as Output>::Type;
 	}
 
-	Output::from_raw(unsafe { string_utf8_length(Input::into_raw(string)) })
+	Output::from_raw(unsafe { string_decode_scoped(Input::into_raw(array), Input::into_raw(len)) })
 }
 
-pub(super) unsafe fn string_encode(string: &JsString, array: PtrMut<u8>, len: PtrLength<u8>) {
+#[track_caller]
+pub(super) unsafe fn string_encode_ascii(
+	string: &JsString,
+	array: PtrMut<u8>,
+	len: PtrLength<u8>,
+) -> u32 {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"string_encode\" (func $js_sys.import.string_encode (@sym (name \"js_sys.import.string_encode\")) (param {} {} {}))){}",
-		"(func $js_sys.string_encode (@sym) (param $string {}) (param $array {}) (param $len {})",
+		"(import \"{ns}\" \"string_encode_ascii\" (func ${ns}.import.string_encode_ascii (@sym (name \"{ns}.import.string_encode_ascii\")) (param {} {} {}) (result {}))){}",
+		"(func ${ns}.string_encode_ascii (@sym) (param {}) (param $string {}) (param $array {}) (param $len {}) (result {})",
 		"  local.get $string{}", "  local.get $array{}", "  local.get $len{}",
-		"  call $js_sys.import.string_encode (@reloc)", ")", interpolate
-		r#macro::wat_input_import_type:: < & JsString > (), interpolate
-		r#macro::wat_input_import_type:: < PtrMut < u8 > > (), interpolate
+		"  call ${ns}.import.string_encode_ascii (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsString > (),
+		interpolate r#macro::wat_input_import_type:: < PtrMut < u8 > > (), interpolate
 		r#macro::wat_input_import_type:: < PtrLength < u8 > > (), interpolate
-		r#macro::wat_imports!((& JsString, PtrMut < u8 >, PtrLength < u8 >),), interpolate < &
-		JsString as Input > ::WAT_TYPE, interpolate < PtrMut < u8 > as Input > ::WAT_TYPE,
-		interpolate < PtrLength < u8 > as Input > ::WAT_TYPE, interpolate r#macro::wat_input!(&
-		JsString), interpolate r#macro::wat_input!(PtrMut < u8 >), interpolate
-		r#macro::wat_input!(PtrLength < u8 >),
+		r#macro::wat_output_import_type:: < u32 > (), interpolate r#macro::wat_imports!((& JsString,
+		PtrMut < u8 >, PtrLength < u8 >), u32), interpolate r#macro::wat_indirect!(u32), interpolate
+		< & JsString as Input > ::WAT_TYPE, interpolate < PtrMut < u8 > as Input > ::WAT_TYPE,
+		interpolate < PtrLength < u8 > as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: <
+		u32 > (), interpolate r#macro::wat_input!(& JsString), interpolate
+		r#macro::wat_input!(PtrMut < u8 >), interpolate r#macro::wat_input!(PtrLength < u8 >),
+		interpolate r#macro::wat_output!(u32),
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
-		name = "string_encode",
+		module = js_bindgen::namespace!(),
+		name = "string_encode_ascii",
 		required_embeds = [
-			("js_sys", "string.encode"),
+			(js_bindgen::namespace!(), "string.encode_ascii"),
 			r#macro::js_input_embed::<&JsString>(),
 			r#macro::js_input_embed::<PtrMut<u8>>(),
 			r#macro::js_input_embed::<PtrLength<u8>>(),
+			r#macro::js_output_embed::<u32>(),
 		],
 		"{}{}{}{}{}",
 		interpolate r#macro::js_select!(
 			"",
 			"(string, array, len) => {\n",
 			(&JsString, PtrMut<u8>, PtrLength<u8>),
+			u32,
 		),
 		interpolate r#macro::js_parameter!("string", &JsString),
 		interpolate r#macro::js_parameter!("array", PtrMut<u8>),
 		interpolate r#macro::js_parameter!("len", PtrLength<u8>),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['string.encode_ascii']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['string.encode_ascii'](string, array, len)",
+			),
+			u32,
+			&JsString,
+			PtrMut<u8>,
+			PtrLength<u8>,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("string_encode_ascii")]
+		fn string_encode_ascii(
+			string: <&JsString as Input>::Type,
+			array: <PtrMut<u8> as Input>::Type,
+			len: <PtrLength<u8> as Input>::Type,
+		) -> <u32 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe {
+		string_encode_ascii(Input::into_raw(string), Input::into_raw(array), Input::into_raw(len))
+	})
+}
+
+#[track_caller]
+pub(super) unsafe fn string_encode(
+	string: &JsString,
+	start: u32,
+	array: PtrMut<u8>,
+	len: PtrLength<u8>,
+) -> u32 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"string_encode\" (func ${ns}.import.string_encode (@sym (name \"{ns}.import.string_encode\")) (param {} {} {} {}) (result {}))){}",
+		"(func ${ns}.string_encode (@sym) (param {}) (param $string {}) (param $start {}) (param $array {}) (param $len {}) (result {})",
+		"  local.get $string{}", "  local.get $start{}", "  local.get $array{}",
+		"  local.get $len{}", "  call ${ns}.import.string_encode (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsString > (),
+		interpolate r#macro::wat_input_import_type:: < u32 > (), interpolate
+		r#macro::wat_input_import_type:: < PtrMut < u8 > > (), interpolate
+		r#macro::wat_input_import_type:: < PtrLength < u8 > > (), interpolate
+		r#macro::wat_output_import_type:: < u32 > (), interpolate r#macro::wat_imports!((& JsString,
+		u32, PtrMut < u8 >, PtrLength < u8 >), u32), interpolate r#macro::wat_indirect!(u32),
+		interpolate < & JsString as Input > ::WAT_TYPE, interpolate < u32 as Input > ::WAT_TYPE,
+		interpolate < PtrMut < u8 > as Input > ::WAT_TYPE, interpolate < PtrLength < u8 > as Input >
+		::WAT_TYPE, interpolate r#macro::wat_direct:: < u32 > (), interpolate r#macro::wat_input!(&
+		JsString), interpolate r#macro::wat_input!(u32), interpolate r#macro::wat_input!(PtrMut < u8
+		>), interpolate r#macro::wat_input!(PtrLength < u8 >), interpolate
+		r#macro::wat_output!(u32),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "string_encode",
+		required_embeds = [
+			(js_bindgen::namespace!(), "string.encode"),
+			r#macro::js_input_embed::<&JsString>(),
+			r#macro::js_input_embed::<u32>(),
+			r#macro::js_input_embed::<PtrMut<u8>>(),
+			r#macro::js_input_embed::<PtrLength<u8>>(),
+			r#macro::js_output_embed::<u32>(),
+		],
+		"{}{}{}{}{}{}",
 		interpolate r#macro::js_select!(
-			"this.#jsEmbed.js_sys['string.encode']",
-			"this.#jsEmbed.js_sys['string.encode'](string, array, len)\n}",
-			(&JsString, PtrMut<u8>, PtrLength<u8>),
+			"",
+			"(string, start, array, len) => {\n",
+			(&JsString, u32, PtrMut<u8>, PtrLength<u8>),
+			u32,
+		),
+		interpolate r#macro::js_parameter!("string", &JsString),
+		interpolate r#macro::js_parameter!("start", u32),
+		interpolate r#macro::js_parameter!("array", PtrMut<u8>),
+		interpolate r#macro::js_parameter!("len", PtrLength<u8>),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['string.encode']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['string.encode'](string, start, array, len)",
+			),
+			u32,
+			&JsString,
+			u32,
+			PtrMut<u8>,
+			PtrLength<u8>,
 		),
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.string_encode"]
+		#[link_name = js_bindgen::namespace!("string_encode")]
 		fn string_encode(
 			string: <&JsString as Input>::Type,
+			start: <u32 as Input>::Type,
 			array: <PtrMut<u8> as Input>::Type,
 			len: <PtrLength<u8> as Input>::Type,
+		) -> <u32 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe {
+		string_encode(
+			Input::into_raw(string),
+			Input::into_raw(start),
+			Input::into_raw(array),
+			Input::into_raw(len),
+		)
+	})
+}
+
+pub(super) unsafe fn string_encode_utf16(string: &JsString, array: PtrMut<u16>) {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"string_encode_utf16\" (func ${ns}.import.string_encode_utf16 (@sym (name \"{ns}.import.string_encode_utf16\")) (param {} {}))){}",
+		"(func ${ns}.string_encode_utf16 (@sym) (param $string {}) (param $array {})",
+		"  local.get $string{}", "  local.get $array{}",
+		"  call ${ns}.import.string_encode_utf16 (@reloc)", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsString > (),
+		interpolate r#macro::wat_input_import_type:: < PtrMut < u16 > > (), interpolate
+		r#macro::wat_imports!((& JsString, PtrMut < u16 >),), interpolate < & JsString as Input >
+		::WAT_TYPE, interpolate < PtrMut < u16 > as Input > ::WAT_TYPE, interpolate
+		r#macro::wat_input!(& JsString), interpolate r#macro::wat_input!(PtrMut < u16 >),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "string_encode_utf16",
+		required_embeds = [
+			(js_bindgen::namespace!(), "string.encode_utf16"),
+			r#macro::js_input_embed::<&JsString>(),
+			r#macro::js_input_embed::<PtrMut<u16>>(),
+		],
+		"{}{}{}{}",
+		interpolate r#macro::js_select!("", "(string, array) => {\n", (&JsString, PtrMut<u16>)),
+		interpolate r#macro::js_parameter!("string", &JsString),
+		interpolate r#macro::js_parameter!("array", PtrMut<u16>),
+		interpolate r#macro::js_select!(
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['string.encode_utf16']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['string.encode_utf16'](string, array)\n}",
+			),
+			(&JsString, PtrMut<u16>),
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("string_encode_utf16")]
+		fn string_encode_utf16(
+			string: <&JsString as Input>::Type,
+			array: <PtrMut<u16> as Input>::Type,
 		);
 	}
 
-	unsafe { string_encode(Input::into_raw(string), Input::into_raw(array), Input::into_raw(len)) };
+	unsafe { string_encode_utf16(Input::into_raw(string), Input::into_raw(array)) };
 }
@@ -1,13 +1,41 @@
+use crate::{JsArray, JsRef};
 use crate::util::{PtrConst, PtrLength, PtrMut};
 
 #[js_sys]
 extern "js-sys" {
-	#[derive(Clone, Debug)]
+	#[derive(Clone)]
 	pub type JsString;
 
 	#[js_sys(js_name = "String")]
 	pub(super) fn string_constructor(value: &JsValue) -> JsString;
 
+	#[js_sys(property, js_name = "length")]
+	pub(super) fn string_length(self: &JsString) -> u32;
+
+	#[js_sys(js_name = "charCodeAt")]
+	pub(super) fn string_char_code_at(self: &JsString, index: u32) -> f64;
+
+	#[js_sys(js_name = "slice")]
+	pub(super) fn string_slice(self: &JsString, start: u32, end: u32) -> JsString;
+
+	#[js_sys(js_name = "indexOf")]
+	pub(super) fn string_index_of(self: &JsString, search: &str, position: u32) -> i32;
+
+	#[js_sys(js_name = "startsWith")]
+	pub(super) fn string_starts_with(self: &JsString, search: &str) -> bool;
+
+	#[js_sys(js_name = "toLowerCase")]
+	pub(super) fn string_to_lower_case(self: &JsString) -> JsString;
+
+	#[js_sys(js_name = "split")]
+	pub(super) fn string_split(self: &JsString, separator: &str) -> JsArray<JsString>;
+
+	#[js_sys(js_name = "concat")]
+	pub(super) fn string_concat(self: &JsString, other: &JsString) -> JsString;
+
+	#[js_sys(js_name = "isWellFormed")]
+	pub(super) fn string_is_well_formed(self: &JsString) -> bool;
+
 	#[js_sys(js_embed = "string.eq")]
 	pub(super) unsafe fn string_eq(
 		string: &JsString,
@@ -18,9 +46,27 @@ extern "js-sys" {
 	#[js_sys(js_embed = "string.decode")]
 	pub(super) unsafe fn string_decode(array: PtrConst<u8>, len: PtrLength<u8>) -> JsString;
 
-	#[js_sys(js_embed = "string.utf8_length")]
-	pub(super) fn string_utf8_length(string: &JsString) -> f64;
+	#[js_sys(js_embed = "string.decode")]
+	pub(super) unsafe fn string_decode_scoped(
+		array: PtrConst<u8>,
+		len: PtrLength<u8>,
+	) -> JsRef<'static, JsString>;
+
+	#[js_sys(js_embed = "string.encode_ascii")]
+	pub(super) unsafe fn string_encode_ascii(
+		string: &JsString,
+		array: PtrMut<u8>,
+		len: PtrLength<u8>,
+	) -> u32;
 
 	#[js_sys(js_embed = "string.encode")]
-	pub(super) unsafe fn string_encode(string: &JsString, array: PtrMut<u8>, len: PtrLength<u8>);
+	pub(super) unsafe fn string_encode(
+		string: &JsString,
+		start: u32,
+		array: PtrMut<u8>,
+		len: PtrLength<u8>,
+	) -> u32;
+
+	#[js_sys(js_embed = "string.encode_utf16")]
+	pub(super) unsafe fn string_encode_utf16(string: &JsString, array: PtrMut<u16>);
 }
@@ -6,8 +6,10 @@ use crate::hazard::{Input, InputJsConv, InputWatConv};
 
 macro_rules! thread_local {
 	($($vis:vis static $name:ident: $ty:ty = $value:expr;)*) => {
-		#[cfg_attr(target_feature = "atomics", thread_local)]
-		$($vis static $name: $crate::util::LocalKey<$ty> = $crate::util::LocalKey::new($value);)*
+		$(
+			#[cfg_attr(target_feature = "atomics", thread_local)]
+			$vis static $name: $crate::util::LocalKey<$ty> = $crate::util::LocalKey::new($value);
+		)*
 	};
 }
 
@@ -85,13 +87,14 @@ const _: () = {
 };
 
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "extern_ref",
-	required_embeds = [("js_sys", ExternSlice::<()>::VIEW_FN)],
+	required_embeds = [(js_bindgen::namespace!(), ExternSlice::<()>::VIEW_FN)],
 	"(refPtr) => {{",
-	"	const [ptr, len] = this.#jsEmbed.js_sys['{}'](refPtr, 2)",
+	"	const [ptr, len] = this.#jsEmbed.{ns}['{}'](refPtr, 2)",
 	"	return {{ ptr, len }}",
 	"}}",
+	ns = interpolate js_bindgen::namespace!(),
 	interpolate ExternSlice::<()>::VIEW_FN,
 );
 
@@ -247,7 +250,7 @@ unsafe impl<T> Input for PtrLength<T> {
 
 #[cfg(not(any(js_sys_assume_endianness = "little", js_sys_assume_endianness = "big")))]
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "isLittleEndian",
 	"(() => {{",
 	"	const buffer = new ArrayBuffer(2)",
@@ -261,7 +264,7 @@ js_bindgen::embed_js!(
 	not(js_sys_assume_endianness = "little")
 ))]
 js_bindgen::embed_js!(
-	module = "js_sys",
+	module = js_bindgen::namespace!(),
 	name = "view.DataView",
 	"new DataView(this.#memory.toResizableBuffer())",
 );
@@ -270,7 +273,7 @@ macro_rules! buffer {
 	($type:literal, $size:literal) => {
 		#[cfg(all(js_sys_target_feature = "unstable-rab", not(js_sys_assume_endianness = "big")))]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = concat!("view.", $type),
 			"new {}Array(this.#memory.toResizableBuffer())",
 			interpolate $type,
@@ -278,14 +281,14 @@ macro_rules! buffer {
 
 		#[cfg(not(any(js_sys_assume_endianness = "little", js_sys_assume_endianness = "big")))]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = concat!("view.get", $type),
 			required_embeds = [
-				("js_sys", "isLittleEndian"),
+				(js_bindgen::namespace!(), "isLittleEndian"),
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", concat!("view.", $type)),
+				(js_bindgen::namespace!(), concat!("view.", $type)),
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", "view.DataView")
+				(js_bindgen::namespace!(), "view.DataView")
 			],
 			"(ptr, count) => {{",
 			#[cfg(debug_assertions)]
@@ -293,7 +296,7 @@ macro_rules! buffer {
 			#[cfg(debug_assertions)]
 			"		throw new WebAssembly.RuntimeError(`non-aligned pointer: ${{ptr}}`)",
 			"",
-			"	if (this.#jsEmbed.js_sys.isLittleEndian) {{",
+			"	if (this.#jsEmbed.{ns}.isLittleEndian) {{",
 			#[cfg(js_sys_target_feature = "unstable-rab")]
 			"		const base = ptr / {size}",
 			"		const view = {buffer}",
@@ -307,13 +310,14 @@ macro_rules! buffer {
 			"		return out",
 			"	}}",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 			size = const $size,
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			buffer = interpolate concat!("this.#jsEmbed.js_sys['view.", $type, "'].subarray(base, base + count)"),
+			buffer = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.", $type, "'].subarray(base, base + count)"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			buffer = interpolate concat!("new ", $type, "Array(this.#memory.buffer, ptr, count)"),
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			data = interpolate "this.#jsEmbed.js_sys['view.DataView']",
+			data = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.DataView']"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			data = interpolate "new DataView(this.#memory.buffer)",
 			type = interpolate $type,
@@ -321,11 +325,11 @@ macro_rules! buffer {
 
 		#[cfg(js_sys_assume_endianness = "little")]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = concat!("view.get", $type),
 			required_embeds = [
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", concat!("view.", $type)),
+				(js_bindgen::namespace!(), concat!("view.", $type)),
 			],
 			"(ptr, count) => {{",
 			#[cfg(debug_assertions)]
@@ -340,18 +344,18 @@ macro_rules! buffer {
 			"}}",
 			size = const $size,
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			buffer = interpolate concat!("this.#jsEmbed.js_sys['view.", $type, "'].subarray(base, base + count)"),
+			buffer = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.", $type, "'].subarray(base, base + count)"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			buffer = interpolate concat!("new ", $type, "Array(this.#memory.buffer, ptr, count)"),
 		);
 
 		#[cfg(js_sys_assume_endianness = "big")]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = concat!("view.get", $type),
 			required_embeds = [
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", "view.DataView")
+				(js_bindgen::namespace!(), "view.DataView")
 			],
 			"(ptr, count) => {{",
 			#[cfg(debug_assertions)]
@@ -368,7 +372,7 @@ macro_rules! buffer {
 			"}}",
 			size = const $size,
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			data = interpolate "this.#jsEmbed.js_sys['view.DataView']",
+			data = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.DataView']"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			data = interpolate "new DataView(this.#memory.buffer)",
 			type = interpolate $type,
@@ -376,14 +380,14 @@ macro_rules! buffer {
 
 		#[cfg(not(any(js_sys_assume_endianness = "little", js_sys_assume_endianness = "big")))]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = concat!("view.set", $type),
 			required_embeds = [
-				("js_sys", "isLittleEndian"),
+				(js_bindgen::namespace!(), "isLittleEndian"),
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", concat!("view.", $type)),
+				(js_bindgen::namespace!(), concat!("view.", $type)),
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", "view.DataView")
+				(js_bindgen::namespace!(), "view.DataView")
 			],
 			"(ptr, array) => {{",
 			#[cfg(debug_assertions)]
@@ -391,7 +395,7 @@ macro_rules! buffer {
 			#[cfg(debug_assertions)]
 			"		throw new WebAssembly.RuntimeError(`non-aligned pointer: ${{ptr}}`)",
 			"",
-			"	if (this.#jsEmbed.js_sys.isLittleEndian) {{",
+			"	if (this.#jsEmbed.{ns}.isLittleEndian) {{",
 			"		{buffer}.set(array, ptr / {size})",
 			"	}} else {{",
 			"		const view = {data}",
@@ -400,13 +404,14 @@ macro_rules! buffer {
 			"		}}",
 			"	}}",
 			"}}",
+			ns = interpolate js_bindgen::namespace!(),
 			size = const $size,
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			buffer = interpolate concat!("this.#jsEmbed.js_sys['view.", $type, "']"),
+			buffer = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.", $type, "']"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			buffer = interpolate concat!("new ", $type, "Array(this.#memory.buffer)"),
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			data = interpolate "this.#jsEmbed.js_sys['view.DataView']",
+			data = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.DataView']"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			data = interpolate "new DataView(this.#memory.buffer)",
 			type = interpolate $type,
@@ -414,11 +419,11 @@ macro_rules! buffer {
 
 		#[cfg(js_sys_assume_endianness = "little")]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = concat!("view.set", $type),
 			required_embeds = [
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", concat!("view.", $type)),
+				(js_bindgen::namespace!(), concat!("view.", $type)),
 			],
 			"(ptr, array) => {{",
 			#[cfg(debug_assertions)]
@@ -430,18 +435,18 @@ macro_rules! buffer {
 			"}}",
 			size = const $size,
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			buffer = interpolate concat!("this.#jsEmbed.js_sys['view.", $type, "']"),
+			buffer = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.", $type, "']"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			buffer = interpolate concat!("new ", $type, "Array(this.#memory.buffer)"),
 		);
 
 		#[cfg(js_sys_assume_endianness = "big")]
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = concat!("view.set", $type),
 			required_embeds = [
 				#[cfg(js_sys_target_feature = "unstable-rab")]
-				("js_sys", "view.DataView")
+				(js_bindgen::namespace!(), "view.DataView")
 			],
 			"(ptr, array) => {{",
 			#[cfg(debug_assertions)]
@@ -456,7 +461,7 @@ macro_rules! buffer {
 			"}}",
 			size = const $size,
 			#[cfg(js_sys_target_feature = "unstable-rab")]
-			data = interpolate "this.#jsEmbed.js_sys['view.DataView']",
+			data = interpolate concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['view.DataView']"),
 			#[cfg(not(js_sys_target_feature = "unstable-rab"))]
 			data = interpolate "new DataView(this.#memory.buffer)",
 			type = interpolate $type,
@@ -464,6 +469,7 @@ macro_rules! buffer {
 	};
 }
 
+buffer!("Uint16", 2_usize);
 buffer!("Uint32", 4_usize);
 buffer!("Int32", 4_usize);
 buffer!("Float64", 8_usize);
@@ -0,0 +1,108 @@
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+
+use super::JsValue;
+use crate::externref;
+#[cfg(feature = "leak-detection")]
+use crate::externref::ExternrefTable;
+use crate::hazard::{JsCast, Output, OutputWatConv};
+
+/// A temporary JS value, borrowed from a [`JsScope`] for `'a`.
+///
+/// Instead of going through the free list of the `externref` table, values
+/// are stored in a small stack of reserved slots that is reused in LIFO
+/// order. This makes them cheap to create and drop, which is useful for
+/// values that are returned only to be passed on to the next call. When the
+/// stack is exhausted, values fall back to the regular table.
+///
+/// Use [`JsRef::into_owned()`] to keep a value around.
+pub struct JsRef<'a, T: JsCast = JsValue> {
+	value: ManuallyDrop<T>,
+	_scope: PhantomData<&'a JsScope>,
+}
+
+/// Scope that [`JsRef`]s are borrowed from, see [`JsScope::run()`].
+pub struct JsScope(());
+
+impl JsScope {
+	/// Calls `f` with a scope to create [`JsRef`]s in. They can't outlive the
+	/// call, so their slots are released in LIFO order.
+	pub fn run<R>(f: impl FnOnce(&Self) -> R) -> R {
+		f(&Self(()))
+	}
+}
+
+impl<T: JsCast> JsRef<'_, T> {
+	/// Moves the value into a regular slot of the `externref` table.
+	#[must_use]
+	pub fn into_owned(self) -> T {
+		T::unchecked_from(JsValue::from_ref(&*self.value).clone())
+	}
+}
+
+impl<T: JsCast> Deref for JsRef<'_, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.value
+	}
+}
+
+impl<T: JsCast> AsRef<JsValue> for JsRef<'_, T> {
+	fn as_ref(&self) -> &JsValue {
+		JsValue::from_ref(&*self.value)
+	}
+}
+
+impl<T: JsCast + Debug> Debug for JsRef<'_, T> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("JsRef").field(&*self.value).finish()
+	}
+}
+
+impl<T: JsCast> Drop for JsRef<'_, T> {
+	fn drop(&mut self) {
+		if !externref::stack_remove(JsValue::from_ref(&*self.value).index) {
+			// SAFETY: Not accessed after.
+			unsafe { ManuallyDrop::drop(&mut self.value) };
+		}
+	}
+}
+
+// SAFETY: Only differs from `JsValue` in where it is stored.
+unsafe impl<T: JsCast> Output for JsRef<'_, T> {
+	const WAT_TYPE: &'static str = "i32";
+	const WAT_CONV: Option<OutputWatConv> = Some(OutputWatConv {
+		import: Some(concat!(
+			"(import \"env\" \"",
+			js_bindgen::namespace!("externref.stack_insert"),
+			"\" (func $",
+			js_bindgen::namespace!("externref.stack_insert"),
+			" (@sym) (param externref) (result i32)))",
+		)),
+		direct: true,
+		conv: concat!(
+			"call $",
+			js_bindgen::namespace!("externref.stack_insert"),
+			" (@reloc)"
+		),
+		r#type: "externref",
+	});
+
+	type Type = i32;
+
+	fn from_raw(raw: Self::Type) -> Self {
+		// The stack was exhausted and a regular slot was used instead.
+		#[cfg(feature = "leak-detection")]
+		if raw >= externref::RESERVED {
+			ExternrefTable::track(raw, core::panic::Location::caller());
+		}
+
+		Self {
+			value: ManuallyDrop::new(T::unchecked_from(JsValue::new(raw))),
+			_scope: PhantomData,
+		}
+	}
+}
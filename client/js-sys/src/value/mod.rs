@@ -1,15 +1,21 @@
+mod js_ref;
 #[rustfmt::skip]
 #[path ="value.gen.rs"]
 mod value;
 
+use alloc::string::String;
+use core::fmt::{self, Debug, Display, Formatter};
 use core::marker::PhantomData;
 use core::mem::MaybeUninit;
-use core::slice;
+#[cfg(feature = "leak-detection")]
+use core::panic::Location;
+use core::{ptr, slice};
 
-use crate::externref::EXTERNREF_TABLE;
+pub use self::js_ref::{JsRef, JsScope};
+use crate::externref::ExternrefTable;
 use crate::hazard::{Input, InputWatConv, JsCast, Output, OutputWatConv};
+use crate::{JsBigInt, JsNumber, JsString};
 
-#[derive(Debug)]
 #[repr(transparent)]
 pub struct JsValue {
 	index: i32,
@@ -20,13 +26,164 @@ impl JsValue {
 	pub const UNDEFINED: Self = Self::new(0);
 	pub const NULL: Self = Self::new(1);
 
-	const fn new(index: i32) -> Self {
+	pub(crate) const fn new(index: i32) -> Self {
 		Self {
 			index,
 			_local: PhantomData,
 		}
 	}
 
+	/// Checks the reserved slot first and otherwise doesn't call into JS.
+	#[must_use]
+	pub fn is_null(&self) -> bool {
+		js_bindgen::unsafe_global_wat!(
+			"(import \"env\" \"{ns}.externref.get\" (func ${ns}.externref.get (@sym) (param \
+			 i32) (result externref)))",
+			"(func ${ns}.js_value.is_null (@sym) (param $index i32) (result i32)",
+			"  local.get $index",
+			"  call ${ns}.externref.get (@reloc)",
+			"  ref.is_null",
+			")",
+			ns = interpolate js_bindgen::namespace!(),
+		);
+
+		unsafe extern "C" {
+			#[link_name = js_bindgen::namespace!("js_value.is_null")]
+			safe fn is_null(index: i32) -> bool;
+		}
+
+		match self.index {
+			0 => false,
+			1 => true,
+			index => is_null(index),
+		}
+	}
+
+	/// Only calls into JS if this isn't a reserved slot.
+	#[must_use]
+	pub fn is_undefined(&self) -> bool {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.is_undefined",
+			"(value) => value === undefined",
+		);
+
+		match self.index {
+			0 => true,
+			1 => false,
+			_ => value::js_value_is_undefined(self),
+		}
+	}
+
+	#[must_use]
+	pub fn is_number(&self) -> bool {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.is_number",
+			"(value) => typeof value === 'number'",
+		);
+
+		self.index > 1 && value::js_value_is_number(self)
+	}
+
+	#[must_use]
+	pub fn is_string(&self) -> bool {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.is_string",
+			"(value) => typeof value === 'string'",
+		);
+
+		self.index > 1 && value::js_value_is_string(self)
+	}
+
+	/// Returns `false` for `null`, unlike the JS `typeof` operator.
+	#[must_use]
+	pub fn is_object(&self) -> bool {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.is_object",
+			"(value) => typeof value === 'object' && value !== null",
+		);
+
+		self.index > 1 && value::js_value_is_object(self)
+	}
+
+	#[must_use]
+	pub fn is_function(&self) -> bool {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.is_function",
+			"(value) => typeof value === 'function'",
+		);
+
+		self.index > 1 && value::js_value_is_function(self)
+	}
+
+	/// Returns the result of the JS `typeof` operator.
+	#[must_use]
+	pub fn js_typeof(&self) -> JsString {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.typeof",
+			"(value) => typeof value",
+		);
+
+		value::js_value_typeof(self)
+	}
+
+	/// Returns [`None`] if this is not a JS `number`.
+	#[must_use]
+	pub fn as_f64(&self) -> Option<f64> {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.as_f64",
+			"(value) => typeof value === 'number' ? value : NaN",
+		);
+
+		if self.index <= 1 {
+			return None;
+		}
+
+		let value = value::js_value_as_f64(self);
+
+		// Only disambiguate `NaN` with a second call.
+		(!value.is_nan() || self.is_number()).then_some(value)
+	}
+
+	/// Returns [`None`] if this is not a JS `boolean`.
+	#[must_use]
+	pub fn as_bool(&self) -> Option<bool> {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.as_bool",
+			"(value) => typeof value === 'boolean' ? +value : 2",
+		);
+
+		if self.index <= 1 {
+			return None;
+		}
+
+		match value::js_value_as_bool(self) {
+			0 => Some(false),
+			1 => Some(true),
+			_ => None,
+		}
+	}
+
+	/// Returns [`None`] if this is not a JS `string`.
+	#[must_use]
+	pub fn as_string(&self) -> Option<String> {
+		self.is_string()
+			.then(|| String::from(JsString::unchecked_from_ref(self)))
+	}
+
+	pub(crate) fn from_ref<T: JsCast>(value: &T) -> &Self {
+		let ptr: *const Self = ptr::from_ref(value).cast();
+		// SAFETY: `JsCast` assumes that `T` is `#[transparent]` over a `JsValue`.
+		unsafe { &*ptr }
+	}
+
 	pub fn from_slice<T: JsCast>(slice: &[T]) -> &[Self] {
 		let ptr: *const Self = slice.as_ptr().cast();
 		// SAFETY: `JsCast` assumes that `T` is `#[transparent]` over a `JsValue`.
@@ -59,26 +216,31 @@ impl JsValue {
 }
 
 impl Clone for JsValue {
+	#[cfg_attr(feature = "leak-detection", track_caller)]
 	fn clone(&self) -> Self {
 		js_bindgen::unsafe_global_wat!(
-			"(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param \
+			"(import \"env\" \"{ns}.externref.get\" (func ${ns}.externref.get (@sym) (param \
 			 i32) (result externref)))",
-			"(import \"env\" \"js_sys.externref.insert\" (func $js_sys.externref.insert (@sym) \
+			"(import \"env\" \"{ns}.externref.insert\" (func ${ns}.externref.insert (@sym) \
 			 (param externref) (result i32)))",
-			"(func $js_sys.js_value.clone (@sym) (param $index i32) (result i32)",
+			"(func ${ns}.js_value.clone (@sym) (param $index i32) (result i32)",
 			"  local.get $index",
-			"  call $js_sys.externref.get (@reloc)",
-			"  call $js_sys.externref.insert (@reloc)",
+			"  call ${ns}.externref.get (@reloc)",
+			"  call ${ns}.externref.insert (@reloc)",
 			")",
+			ns = interpolate js_bindgen::namespace!(),
 		);
 
 		unsafe extern "C" {
-			#[link_name = "js_sys.js_value.clone"]
+			#[link_name = js_bindgen::namespace!("js_value.clone")]
 			safe fn clone(size: i32) -> i32;
 		}
 
 		if self.index > 1 {
-			Self::new(clone(self.index))
+			let index = clone(self.index);
+			#[cfg(feature = "leak-detection")]
+			ExternrefTable::track(index, Location::caller());
+			Self::new(index)
 		} else {
 			Self::new(self.index)
 		}
@@ -88,7 +250,7 @@ impl Clone for JsValue {
 impl Drop for JsValue {
 	fn drop(&mut self) {
 		if self.index > 1 {
-			EXTERNREF_TABLE.with(|table| table.try_borrow_mut().unwrap().remove(self.index));
+			ExternrefTable::remove(self.index);
 		}
 	}
 }
@@ -97,11 +259,18 @@ impl Drop for JsValue {
 unsafe impl Input for &JsValue {
 	const WAT_TYPE: &'static str = "i32";
 	const WAT_CONV: Option<InputWatConv> = Some(InputWatConv {
-		import: Some(
-			"(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param \
-			 i32) (result externref)))",
+		import: Some(concat!(
+			"(import \"env\" \"",
+			js_bindgen::namespace!("externref.get"),
+			"\" (func $",
+			js_bindgen::namespace!("externref.get"),
+			" (@sym) (param i32) (result externref)))",
+		)),
+		conv: concat!(
+			"call $",
+			js_bindgen::namespace!("externref.get"),
+			" (@reloc)"
 		),
-		conv: "call $js_sys.externref.get (@reloc)",
 		r#type: "externref",
 	});
 
@@ -119,26 +288,136 @@ unsafe impl JsCast for JsValue {}
 unsafe impl Output for JsValue {
 	const WAT_TYPE: &str = "i32";
 	const WAT_CONV: Option<OutputWatConv> = Some(OutputWatConv {
-		import: Some(
-			"(import \"env\" \"js_sys.externref.insert\" (func $js_sys.externref.insert (@sym) \
-			 (param externref) (result i32)))",
-		),
+		import: Some(concat!(
+			"(import \"env\" \"",
+			js_bindgen::namespace!("externref.insert"),
+			"\" (func $",
+			js_bindgen::namespace!("externref.insert"),
+			" (@sym) (param externref) (result i32)))",
+		)),
 		direct: true,
-		conv: "call $js_sys.externref.insert (@reloc)",
+		conv: concat!(
+			"call $",
+			js_bindgen::namespace!("externref.insert"),
+			" (@reloc)"
+		),
 		r#type: "externref",
 	});
 
 	type Type = i32;
 
 	fn from_raw(raw: Self::Type) -> Self {
+		#[cfg(feature = "leak-detection")]
+		if raw > 1 {
+			ExternrefTable::track(raw, Location::caller());
+		}
+
 		Self::new(raw)
 	}
 }
 
+/// Describes the JS value similar to Node's `util.inspect()`, e.g. strings are
+/// quoted and nested objects and long arrays are truncated.
+impl Debug for JsValue {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.debug",
+			"(value) => {{",
+			"	const MAX_DEPTH = 2",
+			"	const MAX_ITEMS = 100",
+			"	const MAX_STRING = 10000",
+			"	const seen = new Set()",
+			"	const property = (key) =>",
+			"		/^[A-Za-z_$][\\w$]*$/.test(key) ? key : JSON.stringify(key)",
+			"	const inspect = (value, depth) => {{",
+			"		switch (typeof value) {{",
+			"			case 'string':",
+			"				if (value.length <= MAX_STRING)",
+			"					return JSON.stringify(value)",
+			"				const string = JSON.stringify(value.slice(0, MAX_STRING))",
+			"				return `${{string}}... ${{value.length - MAX_STRING}} more characters`",
+			"			case 'bigint':",
+			"				return `${{value}}n`",
+			"			case 'symbol':",
+			"				return value.toString()",
+			"			case 'function':",
+			"				return `[Function: ${{value.name || '(anonymous)'}}]`",
+			"			case 'object':",
+			"				if (value === null)",
+			"					return 'null'",
+			"				break",
+			"			default:",
+			"				return Object.is(value, -0) ? '-0' : String(value)",
+			"		}}",
+			"		if (seen.has(value))",
+			"			return '[Circular]'",
+			"		let name",
+			"		try {{",
+			"			name = Object.getPrototypeOf(value)?.constructor?.name",
+			"		}} catch {{}}",
+			"		if (value instanceof Error)",
+			"			return `${{name ?? 'Error'}}: ${{value.message}}`",
+			"		if (depth > MAX_DEPTH)",
+			"			return `[${{name || 'Object'}}]`",
+			"		seen.add(value)",
+			"		try {{",
+			"			const items = []",
+			"			let length",
+			"			const list =",
+			"				Array.isArray(value) || (ArrayBuffer.isView(value) && 'length' in value)",
+			"			if (list) {{",
+			"				length = value.length",
+			"				for (let i = 0; i < Math.min(length, MAX_ITEMS); i++)",
+			"					items.push(inspect(value[i], depth + 1))",
+			"			}} else if (value instanceof Map) {{",
+			"				length = value.size",
+			"				for (const [k, v] of value) {{",
+			"					if (items.length === MAX_ITEMS)",
+			"						break",
+			"					items.push(`${{inspect(k, depth + 1)}} => ${{inspect(v, depth + 1)}}`)",
+			"				}}",
+			"			}} else if (value instanceof Set) {{",
+			"				length = value.size",
+			"				for (const v of value) {{",
+			"					if (items.length === MAX_ITEMS)",
+			"						break",
+			"					items.push(inspect(v, depth + 1))",
+			"				}}",
+			"			}} else {{",
+			"				const keys = Object.keys(value)",
+			"				length = keys.length",
+			"				for (const k of keys.slice(0, MAX_ITEMS))",
+			"					items.push(`${{property(k)}}: ${{inspect(value[k], depth + 1)}}`)",
+			"			}}",
+			"			if (length > MAX_ITEMS)",
+			"				items.push(`... ${{length - MAX_ITEMS}} more items`)",
+			"			const body = items.length === 0 ? '' : ` ${{items.join(', ')}} `",
+			"			if (list)",
+			"				return name === 'Array' ? `[${{body}}]` : `${{name}}(${{length}}) [${{body}}]`",
+			"			if (value instanceof Map || value instanceof Set)",
+			"				return `${{name}}(${{length}}) {{${{body}}}}`",
+			"			if (name === 'Object')",
+			"				return `{{${{body}}}}`",
+			"			return `${{name ?? '[Object: null prototype]'}} {{${{body}}}}`",
+			"		}} catch {{",
+			"			return `[${{name || 'Object'}}]`",
+			"		}} finally {{",
+			"			seen.delete(value)",
+			"		}}",
+			"	}}",
+			"	return inspect(value, 0)",
+			"}}",
+		);
+
+		Display::fmt(&value::js_value_debug(self), f)
+	}
+}
+
 impl PartialEq for JsValue {
 	fn eq(&self, other: &Self) -> bool {
 		js_bindgen::embed_js!(
-			module = "js_sys",
+			module = js_bindgen::namespace!(),
 			name = "js_value.partial_eq",
 			"(value1, value2) => value1 === value2",
 		);
@@ -146,3 +425,40 @@ impl PartialEq for JsValue {
 		value::js_value_partial_eq(self, other)
 	}
 }
+
+impl From<bool> for JsValue {
+	fn from(value: bool) -> Self {
+		js_bindgen::embed_js!(
+			module = js_bindgen::namespace!(),
+			name = "js_value.from_bool",
+			"(value) => value",
+		);
+
+		value::js_value_from_bool(value)
+	}
+}
+
+impl From<&str> for JsValue {
+	fn from(value: &str) -> Self {
+		JsString::from(value).into()
+	}
+}
+
+impl From<&String> for JsValue {
+	fn from(value: &String) -> Self {
+		JsString::from(value.as_str()).into()
+	}
+}
+
+macro_rules! from {
+	($js:ident: $($ty:ty),*) => {$(
+		impl From<$ty> for JsValue {
+			fn from(value: $ty) -> Self {
+				$js::from(value).into()
+			}
+		}
+	)*};
+}
+
+from!(JsNumber: f32, f64, i8, i16, i32, u8, u16, u32);
+from!(JsBigInt: i64, u64, i128, u128);
@@ -5,16 +5,18 @@
 use crate::{js_bindgen, r#macro};
 use crate::hazard::{Input, Output};
 use super::JsValue;
+use crate::JsString;
 use crate::util::PtrLength;
 
+#[track_caller]
 pub(super) fn js_value_partial_eq(value1: &JsValue, value2: &JsValue) -> bool {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"js_sys\" \"js_value_partial_eq\" (func $js_sys.import.js_value_partial_eq (@sym (name \"js_sys.import.js_value_partial_eq\")) (param {} {}) (result {}))){}",
-		"(func $js_sys.js_value_partial_eq (@sym) (param {}) (param $value1 {}) (param $value2 {}) (result {})",
+		"(import \"{ns}\" \"js_value_partial_eq\" (func ${ns}.import.js_value_partial_eq (@sym (name \"{ns}.import.js_value_partial_eq\")) (param {} {}) (result {}))){}",
+		"(func ${ns}.js_value_partial_eq (@sym) (param {}) (param $value1 {}) (param $value2 {}) (result {})",
 		"  local.get $value1{}", "  local.get $value2{}",
-		"  call $js_sys.import.js_value_partial_eq (@reloc){}", ")", interpolate
-		r#macro::wat_input_import_type:: < & JsValue > (), interpolate
-		r#macro::wat_input_import_type:: < & JsValue > (), interpolate
+		"  call ${ns}.import.js_value_partial_eq (@reloc){}", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+		interpolate r#macro::wat_input_import_type:: < & JsValue > (), interpolate
 		r#macro::wat_output_import_type:: < bool > (), interpolate r#macro::wat_imports!((&
 		JsValue), bool), interpolate r#macro::wat_indirect!(bool), interpolate < & JsValue as Input
 		> ::WAT_TYPE, interpolate < & JsValue as Input > ::WAT_TYPE, interpolate
@@ -23,10 +25,10 @@ pub(super) fn js_value_partial_eq(value1: &JsValue, value2: &JsValue) -> bool {
 	}
 
 	js_bindgen::import_js! {
-		module = "js_sys",
+		module = js_bindgen::namespace!(),
 		name = "js_value_partial_eq",
 		required_embeds = [
-			("js_sys", "js_value.partial_eq"),
+			(js_bindgen::namespace!(), "js_value.partial_eq"),
 			r#macro::js_input_embed::<&JsValue>(),
 			r#macro::js_output_embed::<bool>(),
 		],
@@ -36,15 +38,19 @@ pub(super) fn js_value_partial_eq(value1: &JsValue, value2: &JsValue) -> bool {
 		interpolate r#macro::js_parameter!("value2", &JsValue),
 		interpolate r#macro::js_output!(
 			"\treturn ",
-			"this.#jsEmbed.js_sys['js_value.partial_eq']",
-			"this.#jsEmbed.js_sys['js_value.partial_eq'](value1, value2)",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.partial_eq']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.partial_eq'](value1, value2)",
+			),
 			bool,
 			&JsValue,
 		),
 	}
 
 	unsafe extern "C" {
-		#[link_name = "js_sys.js_value_partial_eq"]
+		#[link_name = js_bindgen::namespace!("js_value_partial_eq")]
 		fn js_value_partial_eq(
 			value1: <&JsValue as Input>::Type,
 			value2: <&JsValue as Input>::Type,
@@ -55,3 +61,451 @@ pub(super) fn js_value_partial_eq(value1: &JsValue, value2: &JsValue) -> bool {
 		js_value_partial_eq(Input::into_raw(value1), Input::into_raw(value2))
 	})
 }
+
+#[track_caller]
+pub(super) fn js_value_is_undefined(value: &JsValue) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_is_undefined\" (func ${ns}.import.js_value_is_undefined (@sym (name \"{ns}.import.js_value_is_undefined\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_is_undefined (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_is_undefined (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsValue), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_is_undefined",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.is_undefined"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), bool),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.is_undefined']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.is_undefined'](value)",
+			),
+			bool,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_is_undefined")]
+		fn js_value_is_undefined(value: <&JsValue as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_is_undefined(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_is_number(value: &JsValue) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_is_number\" (func ${ns}.import.js_value_is_number (@sym (name \"{ns}.import.js_value_is_number\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_is_number (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_is_number (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsValue), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_is_number",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.is_number"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), bool),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.is_number']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.is_number'](value)",
+			),
+			bool,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_is_number")]
+		fn js_value_is_number(value: <&JsValue as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_is_number(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_is_string(value: &JsValue) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_is_string\" (func ${ns}.import.js_value_is_string (@sym (name \"{ns}.import.js_value_is_string\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_is_string (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_is_string (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsValue), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_is_string",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.is_string"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), bool),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.is_string']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.is_string'](value)",
+			),
+			bool,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_is_string")]
+		fn js_value_is_string(value: <&JsValue as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_is_string(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_is_object(value: &JsValue) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_is_object\" (func ${ns}.import.js_value_is_object (@sym (name \"{ns}.import.js_value_is_object\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_is_object (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_is_object (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsValue), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_is_object",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.is_object"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), bool),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.is_object']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.is_object'](value)",
+			),
+			bool,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_is_object")]
+		fn js_value_is_object(value: <&JsValue as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_is_object(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_is_function(value: &JsValue) -> bool {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_is_function\" (func ${ns}.import.js_value_is_function (@sym (name \"{ns}.import.js_value_is_function\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_is_function (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_is_function (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < bool > (), interpolate
+		r#macro::wat_imports!((& JsValue), bool), interpolate r#macro::wat_indirect!(bool),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < bool >
+		(), interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(bool),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_is_function",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.is_function"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<bool>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), bool),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.is_function']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.is_function'](value)",
+			),
+			bool,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_is_function")]
+		fn js_value_is_function(value: <&JsValue as Input>::Type) -> <bool as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_is_function(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_debug(value: &JsValue) -> JsString {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_debug\" (func ${ns}.import.js_value_debug (@sym (name \"{ns}.import.js_value_debug\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_debug (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_debug (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < JsString > (), interpolate
+		r#macro::wat_imports!((& JsValue), JsString), interpolate r#macro::wat_indirect!(JsString),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsString
+		> (), interpolate r#macro::wat_input!(& JsValue), interpolate
+		r#macro::wat_output!(JsString),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_debug",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.debug"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<JsString>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), JsString),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.debug']"),
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.debug'](value)"),
+			JsString,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_debug")]
+		fn js_value_debug(value: <&JsValue as Input>::Type) -> <JsString as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_debug(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_typeof(value: &JsValue) -> JsString {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_typeof\" (func ${ns}.import.js_value_typeof (@sym (name \"{ns}.import.js_value_typeof\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_typeof (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_typeof (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < JsString > (), interpolate
+		r#macro::wat_imports!((& JsValue), JsString), interpolate r#macro::wat_indirect!(JsString),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsString
+		> (), interpolate r#macro::wat_input!(& JsValue), interpolate
+		r#macro::wat_output!(JsString),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_typeof",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.typeof"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<JsString>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), JsString),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.typeof']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.typeof'](value)",
+			),
+			JsString,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_typeof")]
+		fn js_value_typeof(value: <&JsValue as Input>::Type) -> <JsString as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_typeof(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_as_f64(value: &JsValue) -> f64 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_as_f64\" (func ${ns}.import.js_value_as_f64 (@sym (name \"{ns}.import.js_value_as_f64\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_as_f64 (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_as_f64 (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < f64 > (), interpolate
+		r#macro::wat_imports!((& JsValue), f64), interpolate r#macro::wat_indirect!(f64),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < f64 > (),
+		interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(f64),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_as_f64",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.as_f64"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<f64>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), f64),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.as_f64']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.as_f64'](value)",
+			),
+			f64,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_as_f64")]
+		fn js_value_as_f64(value: <&JsValue as Input>::Type) -> <f64 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_as_f64(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_as_bool(value: &JsValue) -> u32 {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_as_bool\" (func ${ns}.import.js_value_as_bool (@sym (name \"{ns}.import.js_value_as_bool\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_as_bool (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_as_bool (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < &
+		JsValue > (), interpolate r#macro::wat_output_import_type:: < u32 > (), interpolate
+		r#macro::wat_imports!((& JsValue), u32), interpolate r#macro::wat_indirect!(u32),
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < u32 > (),
+		interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_output!(u32),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_as_bool",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.as_bool"),
+			r#macro::js_input_embed::<&JsValue>(),
+			r#macro::js_output_embed::<u32>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (&JsValue), u32),
+		interpolate r#macro::js_parameter!("value", &JsValue),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.as_bool']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.as_bool'](value)",
+			),
+			u32,
+			&JsValue,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_as_bool")]
+		fn js_value_as_bool(value: <&JsValue as Input>::Type) -> <u32 as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_as_bool(Input::into_raw(value)) })
+}
+
+#[track_caller]
+pub(super) fn js_value_from_bool(value: bool) -> JsValue {
+	js_bindgen::unsafe_global_wat! {
+		"(import \"{ns}\" \"js_value_from_bool\" (func ${ns}.import.js_value_from_bool (@sym (name \"{ns}.import.js_value_from_bool\")) (param {}) (result {}))){}",
+		"(func ${ns}.js_value_from_bool (@sym) (param {}) (param $value {}) (result {})",
+		"  local.get $value{}", "  call ${ns}.import.js_value_from_bool (@reloc){}", ")", ns =
+		interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < bool >
+		(), interpolate r#macro::wat_output_import_type:: < JsValue > (), interpolate
+		r#macro::wat_imports!((bool), JsValue), interpolate r#macro::wat_indirect!(JsValue),
+		interpolate < bool as Input > ::WAT_TYPE, interpolate r#macro::wat_direct:: < JsValue > (),
+		interpolate r#macro::wat_input!(bool), interpolate r#macro::wat_output!(JsValue),
+	}
+
+	js_bindgen::import_js! {
+		module = js_bindgen::namespace!(),
+		name = "js_value_from_bool",
+		required_embeds = [
+			(js_bindgen::namespace!(), "js_value.from_bool"),
+			r#macro::js_input_embed::<bool>(),
+			r#macro::js_output_embed::<JsValue>(),
+		],
+		"{}{}{}",
+		interpolate r#macro::js_select!("", "(value) => {\n", (bool), JsValue),
+		interpolate r#macro::js_parameter!("value", bool),
+		interpolate r#macro::js_output!(
+			"\treturn ",
+			::core::concat!("this.#jsEmbed.", js_bindgen::namespace!(), "['js_value.from_bool']"),
+			::core::concat!(
+				"this.#jsEmbed.",
+				js_bindgen::namespace!(),
+				"['js_value.from_bool'](value)",
+			),
+			JsValue,
+			bool,
+		),
+	}
+
+	unsafe extern "C" {
+		#[link_name = js_bindgen::namespace!("js_value_from_bool")]
+		fn js_value_from_bool(value: <bool as Input>::Type) -> <JsValue as Output>::Type;
+	}
+
+	Output::from_raw(unsafe { js_value_from_bool(Input::into_raw(value)) })
+}
@@ -1,8 +1,39 @@
 use super::JsValue;
+use crate::JsString;
 use crate::util::PtrLength;
 
 #[js_sys]
 extern "js-sys" {
 	#[js_sys(js_embed = "js_value.partial_eq")]
 	pub(super) fn js_value_partial_eq(value1: &JsValue, value2: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "js_value.is_undefined")]
+	pub(super) fn js_value_is_undefined(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "js_value.is_number")]
+	pub(super) fn js_value_is_number(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "js_value.is_string")]
+	pub(super) fn js_value_is_string(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "js_value.is_object")]
+	pub(super) fn js_value_is_object(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "js_value.is_function")]
+	pub(super) fn js_value_is_function(value: &JsValue) -> bool;
+
+	#[js_sys(js_embed = "js_value.debug")]
+	pub(super) fn js_value_debug(value: &JsValue) -> JsString;
+
+	#[js_sys(js_embed = "js_value.typeof")]
+	pub(super) fn js_value_typeof(value: &JsValue) -> JsString;
+
+	#[js_sys(js_embed = "js_value.as_f64")]
+	pub(super) fn js_value_as_f64(value: &JsValue) -> f64;
+
+	#[js_sys(js_embed = "js_value.as_bool")]
+	pub(super) fn js_value_as_bool(value: &JsValue) -> u32;
+
+	#[js_sys(js_embed = "js_value.from_bool")]
+	pub(super) fn js_value_from_bool(value: bool) -> JsValue;
 }
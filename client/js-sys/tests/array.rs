@@ -1,9 +1,13 @@
 use core::array;
 
 use js_bindgen_test::test;
-use js_sys::{JsArray, JsValue, js_sys};
+use js_sys::{JsArray, JsString, JsValue, js_sys};
 
-js_bindgen::embed_js!(module = "array", name = "test", "(value) => value");
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "test",
+	"(value) => value"
+);
 
 #[test]
 fn js_value() {
@@ -48,3 +52,63 @@ fn u32() {
 	let returned_array: [u32; 42] = ffi_array.to_array().unwrap();
 	assert_eq!(rust_array, returned_array);
 }
+
+#[test]
+fn manipulation() {
+	let array: JsArray<JsString> = ["a", "b", "c"].into_iter().map(JsString::from).collect();
+	assert_eq!(array.length(), 3);
+	assert_eq!(array.get(1).unwrap(), "b");
+	assert!(array.get(3).is_none());
+
+	array.set(1, &JsString::from("d"));
+	assert_eq!(array.get(1).unwrap(), "d");
+	assert_eq!(array.push(&JsString::from("e")), 4);
+	assert_eq!(array.pop().unwrap(), "e");
+
+	let removed = array.splice(0, 1, &[JsString::from("f"), JsString::from("g")]);
+	assert_eq!(removed.length(), 1);
+	assert_eq!(removed.get(0).unwrap(), "a");
+
+	let strings: Vec<String> = array.iter().map(|string| String::from(&string)).collect();
+	assert_eq!(strings, ["f", "g", "d", "c"]);
+
+	let slice = array.slice(1, 3);
+	assert_eq!(slice.length(), 2);
+	assert_eq!(slice.get(0).unwrap(), "g");
+
+	let concat = slice.concat(&array);
+	assert_eq!(concat.length(), 6);
+	assert_eq!(concat.get(2).unwrap(), "f");
+
+	let empty: JsArray<JsString> = JsArray::from_iter([]);
+	assert!(empty.pop().is_none());
+}
+
+#[test]
+fn search() {
+	let value = JsString::from("b");
+	let array: JsArray<JsString> = ["a", "b"].into_iter().map(JsString::from).collect();
+	assert!(array.includes(&value));
+	assert_eq!(array.index_of(&value), Some(1));
+	assert!(!array.includes(&JsString::from("c")));
+	assert_eq!(array.index_of(&JsString::from("c")), None);
+}
+
+#[test]
+fn iter() {
+	let rust_array: [u32; 200] = array::from_fn(|i| i.try_into().unwrap());
+	let js_array: JsArray<JsValue> = rust_array
+		.iter()
+		.map(|value| JsValue::from(JsString::from(value.to_string().as_str())))
+		.collect();
+
+	let mut count = 0;
+
+	for (index, value) in js_array.iter().enumerate() {
+		assert_eq!(JsString::new(&value), index.to_string());
+		count += 1;
+	}
+
+	assert_eq!(count, rust_array.len());
+	assert_eq!((&js_array).into_iter().count(), rust_array.len());
+}
@@ -0,0 +1,41 @@
+use js_bindgen_test::test;
+use js_sys::{JsString, js_sys};
+
+#[js_sys]
+extern "js-sys" {
+	#[js_sys(js_embed = "greet")]
+	fn greet(name: &str) -> JsString;
+
+	#[js_sys(js_embed = "noop")]
+	fn noop();
+}
+
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "greeting",
+	"'Hello, '"
+);
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "greet",
+	required_embeds = [(js_bindgen::namespace!(), "greeting")],
+	"(name) => this.#jsEmbed.{ns}['greeting'] + name",
+	ns = interpolate js_bindgen::namespace!(),
+);
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "noop",
+	"() => {{}}"
+);
+
+/// Bindings have to find embeds in the namespace of this crate when linking.
+#[test]
+fn namespace() {
+	assert_eq!(String::from(&greet("World!")), "Hello, World!");
+}
+
+/// Bindings without parameters are generated without conversions.
+#[test]
+fn no_parameters() {
+	noop();
+}
@@ -0,0 +1,69 @@
+use js_bindgen_test::test;
+use js_sys::{AggregateError, JsArray, JsError, JsErrorSnapshot, JsValue, RangeError, TypeError};
+
+#[test]
+fn error() {
+	let error = JsError::new("test");
+	assert_eq!(error.name(), "Error");
+	assert_eq!(error.message(), "test");
+	assert_eq!(error.cause(), JsValue::UNDEFINED);
+	assert_eq!(error.to_string(), "Error: test");
+}
+
+#[test]
+fn type_error() {
+	let error = TypeError::new("test");
+	assert_eq!(error.name(), "TypeError");
+	assert_eq!(error.message(), "test");
+	assert_eq!(error.to_string(), "TypeError: test");
+
+	let error: JsError = error.into();
+	assert_eq!(error.name(), "TypeError");
+}
+
+#[test]
+fn range_error() {
+	let error = RangeError::new("test");
+	assert_eq!(error.name(), "RangeError");
+	assert_eq!(error.to_string(), "RangeError: test");
+}
+
+#[test]
+fn aggregate_error() {
+	let errors = JsArray::from(&[JsValue::from(JsError::new("inner"))]);
+	let error = AggregateError::new(&errors, "test");
+	assert_eq!(error.name(), "AggregateError");
+	assert_eq!(error.message(), "test");
+	assert_eq!(error.errors().length(), 1);
+}
+
+#[test]
+fn error_trait() {
+	fn fail() -> Result<(), Box<dyn core::error::Error>> {
+		Err(TypeError::new("test"))?;
+		Ok(())
+	}
+
+	assert_eq!(fail().unwrap_err().to_string(), "TypeError: test");
+}
+
+#[test]
+fn snapshot() {
+	fn fail() -> anyhow::Result<()> {
+		Err(JsErrorSnapshot::from(&*TypeError::new("test")))?;
+		Ok(())
+	}
+
+	let error = fail().unwrap_err();
+	assert_eq!(error.to_string(), "TypeError: test");
+
+	let snapshot = error.downcast_ref::<JsErrorSnapshot>().unwrap();
+	assert_eq!(snapshot.name(), "TypeError");
+	assert_eq!(snapshot.message(), "test");
+}
+
+#[test]
+#[should_panic = "Hello, World!"]
+fn panic() {
+	js_sys::panic("Hello, World!");
+}
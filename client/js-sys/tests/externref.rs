@@ -0,0 +1,145 @@
+use std::num::NonZeroU32;
+
+use js_bindgen_test::test;
+use js_sys::externref::{self, Growth};
+use js_sys::{JsScope, JsString, JsValue};
+
+#[test]
+fn growth() {
+	externref::set_growth(Growth::Chunk(NonZeroU32::new(16).unwrap()));
+
+	// Use up all free slots.
+	let mut values = Vec::new();
+
+	while externref::stats().free > 0 {
+		values.push(JsValue::from(1));
+	}
+
+	let before = externref::stats();
+	values.push(JsValue::from(1));
+	let stats = externref::stats();
+	assert_eq!(stats.live, before.live + 1);
+	assert_eq!(stats.free, 15);
+	assert_eq!(stats.capacity, before.capacity + 16);
+
+	drop(values);
+	externref::set_growth(Growth::Double);
+}
+
+#[test]
+fn shrink() {
+	let before = externref::stats();
+	let values: Vec<JsValue> = (0..100).map(JsValue::from).collect();
+
+	let stats = externref::stats();
+	assert_eq!(stats.live, before.live + 100);
+	assert!(stats.capacity >= before.live + 100);
+
+	drop(values);
+	assert_eq!(externref::stats().live, before.live);
+
+	externref::shrink();
+	let stats = externref::stats();
+	assert_eq!(stats.live, before.live);
+	assert!(stats.capacity <= before.capacity);
+
+	// Released slots are reused.
+	let values: Vec<JsValue> = (0..100).map(JsValue::from).collect();
+	assert_eq!(externref::stats().live, before.live + 100);
+	drop(values);
+}
+
+#[test]
+fn scoped() {
+	let before = externref::stats();
+
+	JsScope::run(|scope| {
+		let string = JsString::scoped(scope, "Hello");
+		assert_eq!(*string, "Hello");
+		assert_eq!(externref::stats(), before);
+
+		let owned = string.into_owned();
+		assert_eq!(owned, "Hello");
+		assert_eq!(externref::stats().live, before.live + 1);
+		drop(owned);
+
+		// Exhausting the stack falls back to the table.
+		let mut strings: Vec<_> = (0..65).map(|_| JsString::scoped(scope, "Hello")).collect();
+		assert_eq!(externref::stats().live, before.live + 1);
+		assert!(strings.iter().all(|string| **string == "Hello"));
+
+		// The top of the stack is reused.
+		strings.truncate(63);
+		strings.push(JsString::scoped(scope, "Hello"));
+		assert_eq!(externref::stats().live, before.live);
+
+		drop(strings);
+	});
+
+	assert_eq!(externref::stats().live, before.live);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "used stale `JsValue` handle"]
+fn stale_handle() {
+	let value = JsValue::from(1);
+	// SAFETY: Deliberately creating a copy to use after free.
+	let stale = unsafe { std::ptr::read(&raw const value) };
+	drop(value);
+
+	// Reuse the freed slot.
+	let _value = JsValue::from(2);
+	let _ = stale.is_number();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "used stale `JsValue` handle"]
+fn stale_handle_drop() {
+	let value = JsValue::from(1);
+	// SAFETY: Deliberately creating a copy to free twice.
+	let stale = unsafe { std::ptr::read(&raw const value) };
+	drop(value);
+
+	let _value = JsValue::from(2);
+	drop(stale);
+}
+
+#[cfg(feature = "leak-detection")]
+#[test]
+fn leak_detection() {
+	use js_sys::externref::Checkpoint;
+
+	let value = JsValue::from(1);
+	let checkpoint = Checkpoint::new();
+	let clone = value.clone();
+	let line = line!() - 1;
+
+	let handles = checkpoint.live_handles();
+	assert_eq!(handles.len(), 1);
+	assert_eq!(handles[0].js_typeof, "number");
+	assert_eq!(handles[0].location.file(), file!());
+	assert_eq!(handles[0].location.line(), line);
+	assert!(
+		externref::live_handles()
+			.iter()
+			.any(|handle| handle.location.line() == line)
+	);
+
+	drop(clone);
+	checkpoint.assert_no_leaks();
+	drop(value);
+}
+
+#[cfg(feature = "leak-detection")]
+#[test]
+#[should_panic = "leaked 1 `JsValue`(s)"]
+fn leak_detection_panic() {
+	use js_sys::externref::Checkpoint;
+
+	let checkpoint = Checkpoint::new();
+	let value = JsValue::from(1);
+	checkpoint.assert_no_leaks();
+	drop(value);
+}
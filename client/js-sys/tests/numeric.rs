@@ -2,7 +2,11 @@ use js_bindgen_test::test;
 use js_sys::{JsBigInt, JsNumber, JsString, js_sys};
 use paste::paste;
 
-js_bindgen::embed_js!(module = "numeric", name = "test", "(value) => value");
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "test",
+	"(value) => value"
+);
 
 #[test]
 fn bool() {
@@ -74,3 +78,74 @@ unsigned!(JsBigInt, u64, u128);
 
 signed!(JsNumber, i8, i16, i32);
 signed!(JsBigInt, i64, i128);
+
+#[test]
+fn number() {
+	let number = JsNumber::from(1.5);
+	assert!(!number.is_nan());
+	assert!(number.is_finite());
+	assert!(!number.is_integer());
+	assert_eq!(JsString::new(number.as_ref()), "1.5");
+	assert!(i32::try_from(&number).is_err());
+
+	let number = JsNumber::from(f64::NAN);
+	assert!(number.is_nan());
+	assert!(!number.is_finite());
+
+	let number = JsNumber::try_from(1_u64 << 53).unwrap();
+	assert!(number.is_integer());
+	assert!(!number.is_safe_integer());
+	assert_eq!(u64::try_from(&number).unwrap(), 1 << 53);
+	assert!(u32::try_from(&number).is_err());
+
+	assert!(JsNumber::try_from((1_u64 << 53) + 1).is_err());
+	assert!(JsNumber::try_from(u64::MAX).is_err());
+	assert!(JsNumber::try_from(i64::MIN).is_ok());
+	assert!(u8::try_from(&JsNumber::from(-1)).is_err());
+	assert_eq!(i8::try_from(&JsNumber::from(-128)).unwrap(), -128);
+}
+
+#[test]
+fn bigint() {
+	let bigint = JsBigInt::from(u128::MAX);
+	assert_eq!(u128::try_from(&bigint).unwrap(), u128::MAX);
+	assert!(i128::try_from(&bigint).is_err());
+	assert!(u64::try_from(&bigint).is_err());
+	assert_eq!(bigint.to_string(16), "ffffffffffffffffffffffffffffffff");
+
+	let bigint = JsBigInt::from(i64::MIN);
+	assert_eq!(i64::try_from(&bigint).unwrap(), i64::MIN);
+	assert!(u64::try_from(&bigint).is_err());
+	assert_eq!(bigint.to_string(10), i64::MIN.to_string());
+
+	let bigint: JsBigInt = "12345678901234567890".parse().unwrap();
+	assert_eq!(u64::try_from(&bigint).unwrap(), 12_345_678_901_234_567_890);
+	assert!(JsNumber::try_from(&bigint).is_err());
+	assert!("1.5".parse::<JsBigInt>().is_err());
+
+	let number = JsNumber::try_from(&JsBigInt::from(42)).unwrap();
+	assert_eq!(JsString::new(number.as_ref()), "42");
+	assert!(JsBigInt::try_from(&JsNumber::from(1.5)).is_err());
+	assert_eq!(JsBigInt::try_from(&number).unwrap(), JsBigInt::from(42));
+}
+
+#[test]
+fn bigint_arithmetic() {
+	let a = JsBigInt::from(u64::MAX);
+	let b = JsBigInt::from(2);
+	assert_eq!(
+		u128::try_from(&(&a + &b)).unwrap(),
+		u128::from(u64::MAX) + 2
+	);
+	assert_eq!(
+		u128::try_from(&(&a * &b)).unwrap(),
+		u128::from(u64::MAX) * 2
+	);
+	assert_eq!(
+		i128::try_from(&(&b - &a)).unwrap(),
+		2 - i128::from(u64::MAX)
+	);
+	assert_eq!(u64::try_from(&(&a / &b)).unwrap(), u64::MAX / 2);
+	assert_eq!(u64::try_from(&(&a % &b)).unwrap(), 1);
+	assert_eq!(i64::try_from(&-b).unwrap(), -2);
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use js_bindgen_test::test;
+use js_sys::hazard::JsCast;
+use js_sys::serde::{Serializer, from_value, to_value};
+use js_sys::{JsArray, JsBigInt, JsString, JsValue};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Test {
+	number: u32,
+	string: String,
+	option: Option<bool>,
+	list: Vec<i8>,
+	variant: Variant,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Variant {
+	Unit,
+	Newtype(f64),
+	Tuple(u8, u8),
+	Struct { value: String },
+}
+
+#[test]
+fn round_trip() {
+	let value = Test {
+		number: 42,
+		string: "test".into(),
+		option: None,
+		list: vec![-1, 0, 1],
+		variant: Variant::Struct {
+			value: "test".into(),
+		},
+	};
+
+	let js = to_value(&value).unwrap();
+	assert!(js.is_object());
+	assert_eq!(from_value::<Test>(&js).unwrap(), value);
+
+	for variant in [Variant::Unit, Variant::Newtype(1.5), Variant::Tuple(1, 2)] {
+		let js = to_value(&variant).unwrap();
+		assert_eq!(from_value::<Variant>(&js).unwrap(), variant);
+	}
+}
+
+#[test]
+#[expect(clippy::float_cmp, reason = "exact value")]
+fn primitives() {
+	assert_eq!(to_value(&true).unwrap(), JsValue::from(true));
+	assert_eq!(to_value("test").unwrap(), JsValue::from("test"));
+	assert!(to_value(&()).unwrap().is_undefined());
+
+	let array: JsArray = JsArray::unchecked_from(to_value(&[1, 2, 3]).unwrap());
+	assert_eq!(array.length(), 3);
+
+	assert_eq!(from_value::<u8>(&JsValue::from(1.)).unwrap(), 1);
+	assert!(from_value::<u8>(&JsValue::from(256.)).is_err());
+	assert!(from_value::<u8>(&JsValue::from(1.5)).is_err());
+	assert_eq!(from_value::<f64>(&JsValue::from(1.5)).unwrap(), 1.5);
+	assert_eq!(from_value::<Option<u8>>(&JsValue::NULL).unwrap(), None);
+}
+
+#[test]
+fn large_integers() {
+	assert!(to_value(&u64::MAX).is_err());
+	assert!(to_value(&(1_u64 << 53)).is_ok());
+
+	let serializer = Serializer::new().large_integers_as_bigint(true);
+	let js = u64::MAX.serialize(&serializer).unwrap();
+	assert_eq!(
+		JsBigInt::unchecked_from(js.clone()),
+		JsBigInt::from(u64::MAX)
+	);
+	assert_eq!(from_value::<u64>(&js).unwrap(), u64::MAX);
+
+	let js = to_value(&i128::MIN).unwrap();
+	assert_eq!(from_value::<i128>(&js).unwrap(), i128::MIN);
+}
+
+#[test]
+fn maps() {
+	let map = BTreeMap::from([(1, "a"), (2, "b")]);
+
+	let js = to_value(&map).unwrap();
+	assert_eq!(JsString::new(&js), "[object Object]");
+	assert_eq!(
+		from_value::<BTreeMap<String, String>>(&js).unwrap(),
+		BTreeMap::from([("1".into(), "a".into()), ("2".into(), "b".into())])
+	);
+
+	let serializer = Serializer::new().maps_as_map(true);
+	let js = map.serialize(&serializer).unwrap();
+	assert_eq!(JsString::new(&js), "[object Map]");
+	assert_eq!(
+		from_value::<BTreeMap<u8, String>>(&js).unwrap(),
+		BTreeMap::from([(1, "a".into()), (2, "b".into())])
+	);
+
+	let map = BTreeMap::from([((1, 2), 3)]);
+	assert!(to_value(&map).is_err());
+	assert!(map.serialize(&serializer).is_ok());
+}
@@ -7,10 +7,74 @@ extern "js-sys" {
 	fn test(value: &str) -> JsString;
 }
 
-js_bindgen::embed_js!(module = "string", name = "test", "(value) => value");
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "test",
+	"(value) => value"
+);
 
 #[test]
 fn rust_string() {
 	let string = test("Hello, World!");
 	assert_eq!(String::from(&string), "Hello, World!");
 }
+
+#[test]
+fn utf16() {
+	let string = JsString::from("a😀");
+	assert_eq!(string.len_utf16(), 3);
+	assert_eq!(string.char_code_at(0), Some(u16::from(b'a')));
+	assert_eq!(string.char_code_at(3), None);
+	assert_eq!(
+		Vec::<u16>::from(&string),
+		"a😀".encode_utf16().collect::<Vec<_>>()
+	);
+	assert!(string.is_well_formed());
+	assert!(!string.slice(0, 2).is_well_formed());
+}
+
+#[test]
+fn search() {
+	let string = JsString::from("Hello, World!");
+	assert_eq!(string.slice(7, 12), "World");
+	assert_eq!(string.index_of("World"), Some(7));
+	assert_eq!(string.index_of("world"), None);
+	assert!(string.starts_with("Hello"));
+	assert_eq!(string.to_lower_case(), "hello, world!");
+	assert_eq!(string.concat(&JsString::from("?")), "Hello, World!?");
+}
+
+#[test]
+fn split() {
+	let string = JsString::from("a,b,c");
+	let parts: [JsString; 3] = string.split(",").to_array().unwrap();
+	assert_eq!(parts[0], "a");
+	assert_eq!(parts[1], "b");
+	assert_eq!(parts[2], "c");
+}
+
+#[test]
+fn intern() {
+	let string = JsString::intern("Hello, World!");
+	assert_eq!(*string, "Hello, World!");
+	assert!(core::ptr::eq(JsString::intern("Hello, World!"), string));
+}
+
+#[test]
+fn roundtrip() {
+	for value in [
+		"",
+		"a",
+		"Hello, World!",
+		"Hello, World! Hello, World!",
+		"ä",
+		"Hello, Wörld!",
+		"Hello, World! Hello, Wörld!",
+		"😀",
+		"a😀b😀c",
+	] {
+		let string = JsString::from(value);
+		assert_eq!(string, value);
+		assert_eq!(String::from(&string), value);
+	}
+}
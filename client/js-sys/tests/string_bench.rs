@@ -0,0 +1,80 @@
+//! Micro benchmarks for [`JsString`] conversions.
+//!
+//! Run with `cargo test --test string_bench -- --ignored --nocapture`.
+
+use std::hint::black_box;
+
+use js_bindgen_test::test;
+use js_sys::{JsString, js_sys};
+
+#[js_sys]
+extern "js-sys" {
+	#[js_sys(js_embed = "now")]
+	fn now() -> f64;
+}
+
+js_bindgen::embed_js!(
+	module = js_bindgen::namespace!(),
+	name = "now",
+	"() => performance.now()"
+);
+
+const ITERATIONS: u32 = 10_000;
+
+fn bench(name: &str, mut f: impl FnMut()) {
+	for _ in 0..ITERATIONS / 10 {
+		f();
+	}
+
+	let start = now();
+
+	for _ in 0..ITERATIONS {
+		f();
+	}
+
+	let elapsed = now() - start;
+	let message = format!(
+		"{name}: {:.0} ns/iter",
+		elapsed * 1_000_000. / f64::from(ITERATIONS)
+	);
+	web_sys::console::log(&[JsString::from(message.as_str())]);
+}
+
+const SHORT_ASCII: &str = "Hello, World!";
+const SHORT_UTF8: &str = "Hello, Wörld!";
+const LONG_ASCII: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod \
+                          tempor incididunt ut labore et dolore magna aliqua.";
+const LONG_UTF8: &str = "Lörem ipsüm dolor sit ämet, consectetur adipiscing elit, sed do eiusmod \
+                         tempor incididunt ut labore et dolore mägna aliqua. 😀";
+
+#[test]
+#[ignore = "benchmark"]
+fn decode() {
+	for (name, value) in [
+		("decode short ASCII", SHORT_ASCII),
+		("decode short UTF-8", SHORT_UTF8),
+		("decode long ASCII", LONG_ASCII),
+		("decode long UTF-8", LONG_UTF8),
+	] {
+		bench(name, || {
+			black_box(JsString::from(black_box(value)));
+		});
+	}
+}
+
+#[test]
+#[ignore = "benchmark"]
+fn encode() {
+	for (name, value) in [
+		("encode short ASCII", SHORT_ASCII),
+		("encode short UTF-8", SHORT_UTF8),
+		("encode long ASCII", LONG_ASCII),
+		("encode long UTF-8", LONG_UTF8),
+	] {
+		let string = JsString::from(value);
+
+		bench(name, || {
+			black_box(String::from(black_box(&string)));
+		});
+	}
+}
@@ -1,5 +1,5 @@
 use js_bindgen_test::test;
-use js_sys::{JsString, JsValue};
+use js_sys::{JsString, JsValue, js_sys};
 
 #[test]
 fn undefined() {
@@ -23,3 +23,132 @@ fn clone() {
 	let value = value.clone();
 	assert_eq!(value, "Hello, World!");
 }
+
+#[test]
+fn predicates() {
+	#[js_sys]
+	extern "js-sys" {
+		#[js_sys(js_embed = "null")]
+		fn null() -> JsValue;
+
+		#[js_sys(js_embed = "undefined")]
+		fn undefined() -> JsValue;
+
+		#[js_sys(js_embed = "object")]
+		fn object() -> JsValue;
+
+		#[js_sys(js_embed = "function")]
+		fn function() -> JsValue;
+	}
+
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "null",
+		"() => null"
+	);
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "undefined",
+		"() => undefined"
+	);
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "object",
+		"() => ({{}})"
+	);
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "function",
+		"() => () => {{}}"
+	);
+
+	assert!(JsValue::NULL.is_null());
+	assert!(!JsValue::NULL.is_undefined());
+	assert!(null().is_null());
+	assert!(!null().is_object());
+	assert_eq!(null().js_typeof(), "object");
+
+	assert!(JsValue::UNDEFINED.is_undefined());
+	assert!(!JsValue::UNDEFINED.is_null());
+	assert!(undefined().is_undefined());
+	assert!(!undefined().is_null());
+	assert_eq!(undefined().js_typeof(), "undefined");
+
+	assert!(object().is_object());
+	assert!(!object().is_function());
+	assert!(function().is_function());
+	assert!(!function().is_object());
+	assert_eq!(function().js_typeof(), "function");
+
+	let string = JsValue::from("Hello, World!");
+	assert!(string.is_string());
+	assert!(!string.is_object());
+	assert_eq!(string.as_string().unwrap(), "Hello, World!");
+	assert!(JsValue::from(1).as_string().is_none());
+}
+
+#[test]
+fn primitives() {
+	assert_eq!(JsValue::from(true).as_bool(), Some(true));
+	assert_eq!(JsValue::from(false).as_bool(), Some(false));
+	assert_eq!(JsValue::from(0).as_bool(), None);
+	assert_eq!(JsValue::NULL.as_bool(), None);
+
+	assert_eq!(JsValue::from(42_u8).as_f64(), Some(42.));
+	assert_eq!(JsValue::from(-1.5).as_f64(), Some(-1.5));
+	assert!(JsValue::from(f64::NAN).as_f64().unwrap().is_nan());
+	assert_eq!(JsValue::from("42").as_f64(), None);
+	assert_eq!(JsValue::UNDEFINED.as_f64(), None);
+
+	assert!(JsValue::from(1).is_number());
+	assert!(!JsValue::from(1_i64).is_number());
+	assert_eq!(JsValue::from(1_i64).js_typeof(), "bigint");
+	assert_eq!(JsValue::from(true).js_typeof(), "boolean");
+}
+
+#[test]
+fn debug() {
+	#[js_sys]
+	extern "js-sys" {
+		#[js_sys(js_embed = "nested")]
+		fn nested() -> JsValue;
+
+		#[js_sys(js_embed = "circular")]
+		fn circular() -> JsValue;
+
+		#[js_sys(js_embed = "long")]
+		fn long() -> JsValue;
+	}
+
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "nested",
+		"() => ({{ a: [1, 'b', null], 'c-d': new Map([[1n, {{ e: {{ f: {{}} }} }}]]) }})",
+	);
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "circular",
+		"() => {{ const value = new class Foo {{}}(); value.self = value; return value }}",
+	);
+	js_bindgen::embed_js!(
+		module = js_bindgen::namespace!(),
+		name = "long",
+		"() => Array.from({{ length: 102 }}, (_, i) => i)",
+	);
+
+	assert_eq!(format!("{:?}", JsValue::UNDEFINED), "undefined");
+	assert_eq!(format!("{:?}", JsValue::NULL), "null");
+	assert_eq!(format!("{:?}", JsValue::from(-0.)), "-0");
+	assert_eq!(format!("{:?}", JsValue::from(1_i64)), "1n");
+	assert_eq!(format!("{:?}", JsString::from("a\"b")), r#""a\"b""#);
+	assert_eq!(
+		format!("{:?}", nested()),
+		r#"{ a: [ 1, "b", null ], "c-d": Map(1) { 1n => { e: [Object] } } }"#
+	);
+	assert_eq!(format!("{:?}", circular()), "Foo { self: [Circular] }");
+	assert!(
+		format!("{:?}", long()).ends_with(", 98, 99, ... 2 more items ]"),
+		"{:?}",
+		long()
+	);
+}
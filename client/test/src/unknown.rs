@@ -2,15 +2,23 @@ use std::panic::{self, PanicHookInfo};
 use std::sync::Once;
 
 pub use js_bindgen_test_macro::test;
-use js_sys::{JsString, js_sys};
+use js_sys::{JsString, JsValue, js_sys};
 
 #[js_sys]
 extern "js-sys" {
-	#[js_sys(js_import)]
+	#[js_sys(js_import = "js_bindgen_test")]
 	fn set_message(message: &JsString);
 
-	#[js_sys(js_import)]
+	#[js_sys(js_import = "js_bindgen_test")]
 	fn set_payload(payload: &JsString);
+
+	/// Makes the test runner await `promise` after the test has returned,
+	/// failing the test if it rejects.
+	///
+	/// Allows tests to wait for other threads without blocking, which browsers
+	/// don't allow on the main thread.
+	#[js_sys(js_import = "js_bindgen_test")]
+	pub fn wait_for(promise: &JsValue);
 }
 
 #[doc(hidden)]
@@ -39,3 +47,14 @@ pub fn set_panic_hook() {
 		}));
 	});
 }
+
+#[doc(hidden)]
+pub fn run_test(test: fn()) {
+	#[cfg(feature = "leak-detection")]
+	let checkpoint = js_sys::externref::Checkpoint::new();
+
+	test();
+
+	#[cfg(feature = "leak-detection")]
+	checkpoint.assert_no_leaks();
+}
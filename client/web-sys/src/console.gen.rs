@@ -9,14 +9,19 @@ use js_sys::hazard::JsCast;
 
 pub fn log0() {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"web_sys\" \"console.log0\" (func $web_sys.import.console.log0 (@sym (name \"web_sys.import.console.log0\"))))",
-		"(func $web_sys.console.log0 (@sym)", "  call $web_sys.import.console.log0 (@reloc)", ")",
+		"(import \"{ns}\" \"console.log0\" (func ${ns}.import.console.log0 (@sym (name \"{ns}.import.console.log0\"))))",
+		"(func ${ns}.console.log0 (@sym)", "  call ${ns}.import.console.log0 (@reloc)", ")", ns =
+		interpolate js_bindgen::namespace!(),
 	}
 
-	js_bindgen::import_js! (module = "web_sys", name = "console.log0", "globalThis.console.log");
+	js_bindgen::import_js!(
+		module = js_bindgen::namespace!(),
+		name = "console.log0",
+		"globalThis.console.log",
+	);
 
 	unsafe extern "C" {
-		#[link_name = "web_sys.console.log0"]
+		#[link_name = js_bindgen::namespace!("console.log0")]
 		fn log0();
 	}
 
@@ -25,16 +30,16 @@ pub fn log0() {
 
 pub fn log<T: JsCast>(data: &[T]) {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"web_sys\" \"console.log\" (func $web_sys.import.console.log (@sym (name \"web_sys.import.console.log\")) (param {}))){}",
-		"(func $web_sys.console.log (@sym) (param $data {})", "  local.get $data{}",
-		"  call $web_sys.import.console.log (@reloc)", ")", interpolate
-		r#macro::wat_input_import_type:: < & [JsValue] > (), interpolate r#macro::wat_imports!((&
-		[JsValue]),), interpolate < & [JsValue] as Input > ::WAT_TYPE, interpolate
-		r#macro::wat_input!(& [JsValue]),
+		"(import \"{ns}\" \"console.log\" (func ${ns}.import.console.log (@sym (name \"{ns}.import.console.log\")) (param {}))){}",
+		"(func ${ns}.console.log (@sym) (param $data {})", "  local.get $data{}",
+		"  call ${ns}.import.console.log (@reloc)", ")", ns = interpolate js_bindgen::namespace!(),
+		interpolate r#macro::wat_input_import_type:: < & [JsValue] > (), interpolate
+		r#macro::wat_imports!((& [JsValue]),), interpolate < & [JsValue] as Input > ::WAT_TYPE,
+		interpolate r#macro::wat_input!(& [JsValue]),
 	}
 
 	js_bindgen::import_js! {
-		module = "web_sys",
+		module = js_bindgen::namespace!(),
 		name = "console.log",
 		required_embeds = [r#macro::js_input_embed::<&[JsValue]>()],
 		"{}{}{}",
@@ -48,7 +53,7 @@ pub fn log<T: JsCast>(data: &[T]) {
 	}
 
 	unsafe extern "C" {
-		#[link_name = "web_sys.console.log"]
+		#[link_name = js_bindgen::namespace!("console.log")]
 		fn log(data: <&[JsValue] as Input>::Type);
 	}
 
@@ -57,19 +62,18 @@ pub fn log<T: JsCast>(data: &[T]) {
 
 pub fn log2(data1: &JsValue, data2: &JsValue) {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"web_sys\" \"console.log2\" (func $web_sys.import.console.log2 (@sym (name \"web_sys.import.console.log2\")) (param {} {}))){}",
-		"(func $web_sys.console.log2 (@sym) (param $data1 {}) (param $data2 {})",
-		"  local.get $data1{}", "  local.get $data2{}",
-		"  call $web_sys.import.console.log2 (@reloc)", ")", interpolate
-		r#macro::wat_input_import_type:: < & JsValue > (), interpolate
-		r#macro::wat_input_import_type:: < & JsValue > (), interpolate r#macro::wat_imports!((&
-		JsValue),), interpolate < & JsValue as Input > ::WAT_TYPE, interpolate < & JsValue as Input
-		> ::WAT_TYPE, interpolate r#macro::wat_input!(& JsValue), interpolate r#macro::wat_input!(&
-		JsValue),
+		"(import \"{ns}\" \"console.log2\" (func ${ns}.import.console.log2 (@sym (name \"{ns}.import.console.log2\")) (param {} {}))){}",
+		"(func ${ns}.console.log2 (@sym) (param $data1 {}) (param $data2 {})",
+		"  local.get $data1{}", "  local.get $data2{}", "  call ${ns}.import.console.log2 (@reloc)",
+		")", ns = interpolate js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type::
+		< & JsValue > (), interpolate r#macro::wat_input_import_type:: < & JsValue > (), interpolate
+		r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as Input > ::WAT_TYPE,
+		interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_input!(& JsValue),
+		interpolate r#macro::wat_input!(& JsValue),
 	}
 
 	js_bindgen::import_js! {
-		module = "web_sys",
+		module = js_bindgen::namespace!(),
 		name = "console.log2",
 		required_embeds = [r#macro::js_input_embed::<&JsValue>()],
 		"{}{}{}{}",
@@ -84,7 +88,7 @@ pub fn log2(data1: &JsValue, data2: &JsValue) {
 	}
 
 	unsafe extern "C" {
-		#[link_name = "web_sys.console.log2"]
+		#[link_name = js_bindgen::namespace!("console.log2")]
 		fn log2(data1: <&JsValue as Input>::Type, data2: <&JsValue as Input>::Type);
 	}
 
@@ -93,16 +97,16 @@ pub fn log2(data1: &JsValue, data2: &JsValue) {
 
 pub fn error(data: &JsValue) {
 	js_bindgen::unsafe_global_wat! {
-		"(import \"web_sys\" \"console.error\" (func $web_sys.import.console.error (@sym (name \"web_sys.import.console.error\")) (param {}))){}",
-		"(func $web_sys.console.error (@sym) (param $data {})", "  local.get $data{}",
-		"  call $web_sys.import.console.error (@reloc)", ")", interpolate
-		r#macro::wat_input_import_type:: < & JsValue > (), interpolate r#macro::wat_imports!((&
-		JsValue),), interpolate < & JsValue as Input > ::WAT_TYPE, interpolate r#macro::wat_input!(&
-		JsValue),
+		"(import \"{ns}\" \"console.error\" (func ${ns}.import.console.error (@sym (name \"{ns}.import.console.error\")) (param {}))){}",
+		"(func ${ns}.console.error (@sym) (param $data {})", "  local.get $data{}",
+		"  call ${ns}.import.console.error (@reloc)", ")", ns = interpolate
+		js_bindgen::namespace!(), interpolate r#macro::wat_input_import_type:: < & JsValue > (),
+		interpolate r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as Input >
+		::WAT_TYPE, interpolate r#macro::wat_input!(& JsValue),
 	}
 
 	js_bindgen::import_js! {
-		module = "web_sys",
+		module = js_bindgen::namespace!(),
 		name = "console.error",
 		required_embeds = [r#macro::js_input_embed::<&JsValue>()],
 		"{}{}{}",
@@ -116,7 +120,7 @@ pub fn error(data: &JsValue) {
 	}
 
 	unsafe extern "C" {
-		#[link_name = "web_sys.console.error"]
+		#[link_name = js_bindgen::namespace!("console.error")]
 		fn error(data: <&JsValue as Input>::Type);
 	}
 
@@ -73,8 +73,6 @@ impl JsSys {
 					None
 				};
 
-			let crate_ = package.name.replace('-', "_");
-
 			let base = if num_packages > 1 {
 				metadata.workspace_root.as_std_path()
 			} else {
@@ -101,7 +99,6 @@ impl JsSys {
 					base,
 					global_args,
 					package: &package.name,
-					crate_: &crate_,
 					js_sys: js_sys.as_ref(),
 				};
 
@@ -149,7 +146,6 @@ struct State<'a> {
 	base: &'a Path,
 	global_args: GlobalArgs,
 	package: &'a str,
-	crate_: &'a str,
 	js_sys: Option<&'a syn::Path>,
 }
 
@@ -215,7 +211,7 @@ impl State<'_> {
 	) -> Result<Option<String>> {
 		let input = ReadFile::new(entry)?;
 		let input = str::from_utf8(&input)?;
-		let output = match js_sys_bindgen::file(input, self.crate_, Some(js_sys.clone())) {
+		let output = match js_sys_bindgen::file(input, Some(js_sys.clone())) {
 			Ok(output) => output,
 			Err(error) => {
 				let path = relative_entry.to_string_lossy();
@@ -1,8 +1,17 @@
+/** Exports of the Wasm module, typed in generated declarations. */
+export type Exports = WebAssembly.Exports;
+export type Instance = WebAssembly.Instance & {
+    readonly exports: Exports;
+};
 export declare class JsBindgen {
     #private;
     constructor(module: WebAssembly.Module, memory?: WebAssembly.Memory);
     get importObject(): WebAssembly.Imports;
     extendImportObject(imports: WebAssembly.Imports): void;
-    instantiate(): Promise<WebAssembly.Instance>;
-    static instantiateStreaming(...args: Parameters<typeof fetch> | []): Promise<WebAssembly.Instance>;
+    instantiate(): Promise<Instance>;
+    /** Blocks while instantiating, which browsers only allow for small modules or in workers. */
+    instantiateSync(): Instance;
+    static instantiateStreaming(...args: Parameters<typeof fetch> | []): Promise<Instance>;
+    /** Reads the Wasm file synchronously if no `bytes` are passed and the target supports it. */
+    static instantiateBytes(bytes?: BufferSource): Instance;
 }
@@ -54,14 +54,24 @@ export class JsBindgen {
         }
         return WebAssembly.instantiate(this.#module, this.#importObject).then(instance => {
             this.#finished = true;
+            callStart(instance);
             return instance;
         });
     }
+    /** Blocks while instantiating, which browsers only allow for small modules or in workers. */
+    instantiateSync() {
+        if (this.#finished) {
+            throw new Error("create a new `JsBindgen` class");
+        }
+        const instance = new WebAssembly.Instance(this.#module, this.#importObject);
+        this.#finished = true;
+        callStart(instance);
+        return instance;
+    }
     static async instantiateStreaming(...args) {
         let response;
         if (args.length === 0) {
-            const url = import.meta.url.replace(/\.mjs$/, ".wasm");
-            response = fetch(url);
+            response = JBG_PLACEHOLDER_WASM_RESPONSE();
         }
         else {
             response = fetch(...args);
@@ -69,4 +79,63 @@ export class JsBindgen {
         const module = await WebAssembly.compileStreaming(response);
         return new JsBindgen(module).instantiate();
     }
+    /** Reads the Wasm file synchronously if no `bytes` are passed and the target supports it. */
+    static instantiateBytes(bytes) {
+        const module = new WebAssembly.Module(bytes ?? JBG_PLACEHOLDER_WASM_BYTES());
+        return new JsBindgen(module).instantiateSync();
+    }
+}
+/**
+ * Modules with shared memory export their start function instead, so workers can set up their
+ * stack before any code runs. Must match `js-bindgen-ld`.
+ */
+function callStart(instance) {
+    const start = instance.exports["__js_bindgen_start"];
+    start?.();
+}
+// Must match `js-sys-threads`.
+const THREAD_FINISHED = 1;
+const THREAD_PANICKED = 2;
+async function startThread(event) {
+    const thread = event.data.jsBindgenThread;
+    if (!thread) {
+        return;
+    }
+    globalThis.removeEventListener("message", startThread);
+    let result = THREAD_PANICKED;
+    try {
+        const importObject = new JsBindgen(thread.module, thread.memory).importObject;
+        // Imports added with `extendImportObject()` on the main thread can't be sent to workers.
+        for (const { module, name, kind } of WebAssembly.Module.imports(thread.module)) {
+            if (kind === "function") {
+                const imports = (importObject[module] ??= {});
+                imports[name] ??= () => {
+                    throw new Error(`\`${module}:${name}\` is not available in workers`);
+                };
+            }
+        }
+        const instance = await WebAssembly.instantiate(thread.module, importObject);
+        const stackPointer = instance.exports["__stack_pointer"];
+        const tlsSize = instance.exports["__tls_size"];
+        const tlsAlign = instance.exports["__tls_align"];
+        const run = instance.exports[thread.run];
+        // Wasm64 uses `BigInt`s for pointers.
+        const ptr = typeof stackPointer.value === "bigint" ? BigInt : Number;
+        stackPointer.value = ptr(thread.stack);
+        callStart(instance);
+        run(ptr(thread.context), ptr(tlsSize.value), ptr(tlsAlign.value));
+        result = THREAD_FINISHED;
+    }
+    finally {
+        // Only signal the result after Wasm stopped using the stack of this thread.
+        const state = new Int32Array(thread.memory.buffer);
+        Atomics.store(state, thread.state / 4, result);
+        Atomics.notify(state, thread.state / 4);
+        globalThis.close();
+    }
+}
+if (JBG_PLACEHOLDER_THREADS &&
+    typeof WorkerGlobalScope !== "undefined" &&
+    globalThis instanceof WorkerGlobalScope) {
+    globalThis.addEventListener("message", startThread);
 }
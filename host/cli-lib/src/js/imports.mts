@@ -2,6 +2,13 @@ declare const JBG_PLACEHOLDER_MEMORY: WebAssembly.Memory
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
 declare const JBG_PLACEHOLDER_JS_EMBED: Record<string, Record<string, any>>
 declare const JBG_PLACEHOLDER_IMPORT_OBJECT: WebAssembly.Imports
+declare const JBG_PLACEHOLDER_WASM_RESPONSE: () => Promise<Response>
+declare const JBG_PLACEHOLDER_WASM_BYTES: () => BufferSource
+declare const JBG_PLACEHOLDER_THREADS: boolean
+
+/** Exports of the Wasm module, typed in generated declarations. */
+export type Exports = WebAssembly.Exports
+export type Instance = WebAssembly.Instance & { readonly exports: Exports }
 
 export class JsBindgen {
 	#finished = false
@@ -61,25 +68,35 @@ export class JsBindgen {
 		}
 	}
 
-	async instantiate(): Promise<WebAssembly.Instance> {
+	async instantiate(): Promise<Instance> {
 		if (this.#finished) {
 			throw new Error("create a new `JsBindgen` class")
 		}
 
 		return WebAssembly.instantiate(this.#module, this.#importObject).then(instance => {
 			this.#finished = true
-			return instance
+			callStart(instance)
+			return instance as Instance
 		})
 	}
 
-	static async instantiateStreaming(
-		...args: Parameters<typeof fetch> | []
-	): Promise<WebAssembly.Instance> {
+	/** Blocks while instantiating, which browsers only allow for small modules or in workers. */
+	instantiateSync(): Instance {
+		if (this.#finished) {
+			throw new Error("create a new `JsBindgen` class")
+		}
+
+		const instance = new WebAssembly.Instance(this.#module, this.#importObject)
+		this.#finished = true
+		callStart(instance)
+		return instance as Instance
+	}
+
+	static async instantiateStreaming(...args: Parameters<typeof fetch> | []): Promise<Instance> {
 		let response
 
 		if (args.length === 0) {
-			const url = import.meta.url.replace(/\.mjs$/, ".wasm")
-			response = fetch(url)
+			response = JBG_PLACEHOLDER_WASM_RESPONSE()
 		} else {
 			response = fetch(...args)
 		}
@@ -88,4 +105,87 @@ export class JsBindgen {
 
 		return new JsBindgen(module).instantiate()
 	}
+
+	/** Reads the Wasm file synchronously if no `bytes` are passed and the target supports it. */
+	static instantiateBytes(bytes?: BufferSource): Instance {
+		const module = new WebAssembly.Module(bytes ?? JBG_PLACEHOLDER_WASM_BYTES())
+
+		return new JsBindgen(module).instantiateSync()
+	}
+}
+
+/**
+ * Modules with shared memory export their start function instead, so workers can set up their
+ * stack before any code runs. Must match `js-bindgen-ld`.
+ */
+function callStart(instance: WebAssembly.Instance) {
+	const start = instance.exports["__js_bindgen_start"] as (() => void) | undefined
+	start?.()
+}
+
+type ThreadMessage = {
+	module: WebAssembly.Module
+	memory: WebAssembly.Memory
+	stack: number
+	context: number
+	state: number
+	/** Name of the export running the thread, namespaced by `js-sys-threads`. */
+	run: string
+}
+
+// Must match `js-sys-threads`.
+const THREAD_FINISHED = 1
+const THREAD_PANICKED = 2
+
+async function startThread(event: MessageEvent<{ jsBindgenThread?: ThreadMessage }>) {
+	const thread = event.data.jsBindgenThread
+
+	if (!thread) {
+		return
+	}
+
+	globalThis.removeEventListener("message", startThread)
+
+	let result = THREAD_PANICKED
+
+	try {
+		const importObject = new JsBindgen(thread.module, thread.memory).importObject
+
+		// Imports added with `extendImportObject()` on the main thread can't be sent to workers.
+		for (const { module, name, kind } of WebAssembly.Module.imports(thread.module)) {
+			if (kind === "function") {
+				const imports = (importObject[module] ??= {})
+				imports[name] ??= () => {
+					throw new Error(`\`${module}:${name}\` is not available in workers`)
+				}
+			}
+		}
+
+		const instance = await WebAssembly.instantiate(thread.module, importObject)
+		const stackPointer = instance.exports["__stack_pointer"] as WebAssembly.Global
+		const tlsSize = instance.exports["__tls_size"] as WebAssembly.Global
+		const tlsAlign = instance.exports["__tls_align"] as WebAssembly.Global
+		const run = instance.exports[thread.run] as (...args: (number | bigint)[]) => void
+		// Wasm64 uses `BigInt`s for pointers.
+		const ptr = typeof stackPointer.value === "bigint" ? BigInt : Number
+
+		stackPointer.value = ptr(thread.stack)
+		callStart(instance)
+		run(ptr(thread.context), ptr(tlsSize.value), ptr(tlsAlign.value))
+		result = THREAD_FINISHED
+	} finally {
+		// Only signal the result after Wasm stopped using the stack of this thread.
+		const state = new Int32Array(thread.memory.buffer)
+		Atomics.store(state, thread.state / 4, result)
+		Atomics.notify(state, thread.state / 4)
+		globalThis.close()
+	}
+}
+
+if (
+	JBG_PLACEHOLDER_THREADS &&
+	typeof WorkerGlobalScope !== "undefined" &&
+	globalThis instanceof WorkerGlobalScope
+) {
+	globalThis.addEventListener("message", startThread)
 }
@@ -1,25 +1,50 @@
+mod minify;
+mod parser;
+
+use std::borrow::Cow;
 use std::fmt::Display;
 use std::hash::Hash;
-use std::io::Write;
-use std::ops::Deref;
+use std::io::{self, Write};
+use std::ops::{Deref, RangeInclusive};
+use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use foldhash::fast::FixedState;
 use hashbrown::HashMap;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use wasmparser::MemoryType;
 
+pub use crate::parser::{JsOutputParser, raw_section};
+
 pub const JS_OUTPUT_SECTION: &str = "js_bindgen.js_output";
 
 type FixedHashMap<K, V> = HashMap<K, V, FixedState>;
+/// Shortened keys of embeds by module and name.
+type EmbedKeys<'a> = HashMap<(&'a str, &'a str), String>;
 
 #[derive(Deserialize, Serialize)]
 pub struct JsOutput<'a, T: Deref<Target = str> + Display + Eq + Hash + Serialize> {
 	#[serde(borrow)]
 	pub main_memory: MainMemory<'a>,
-	pub js_import: FixedHashMap<T, FixedHashMap<T, T>>,
-	pub js_embed: FixedHashMap<T, FixedHashMap<T, T>>,
+	/// Directory relative [`JsSource::file`]s are resolved against.
+	pub source_root: T,
+	pub js_import: FixedHashMap<T, FixedHashMap<T, JsSource<T>>>,
+	pub js_embed: FixedHashMap<T, FixedHashMap<T, JsSource<T>>>,
+	/// Whether to include the bootstrap for threads spawned as workers.
+	pub threads: bool,
+	/// Used to generate the TypeScript declarations.
+	pub exports: Vec<Export<T>>,
+}
+
+/// JS of a single `import_js!` or `embed_js!` call.
+#[derive(Deserialize, Serialize)]
+pub struct JsSource<T> {
+	pub js: T,
+	/// Rust file of the macro call, as returned by `file!()`.
+	pub file: T,
+	/// Line of the macro call, starting at 1.
+	pub line: u32,
 }
 
 #[derive(Clone, Copy, Deserialize, Serialize)]
@@ -28,13 +53,181 @@ pub struct MainMemory<'a> {
 	pub name: &'a str,
 }
 
+#[derive(Deserialize, Serialize)]
+pub struct Export<T> {
+	pub name: T,
+	pub kind: ExportKind,
+}
+
+#[derive(Deserialize, Serialize)]
+pub enum ExportKind {
+	Function {
+		params: Vec<ValType>,
+		results: Vec<ValType>,
+	},
+	Global,
+	Memory,
+	Table,
+}
+
+/// Wasm value types as far as they are representable in JS.
+#[derive(Clone, Copy, Deserialize, Serialize)]
+pub enum ValType {
+	I32,
+	I64,
+	F32,
+	F64,
+	ExternRef,
+	FuncRef,
+	/// Throws when crossing the JS boundary.
+	Other,
+}
+
+impl From<wasmparser::ValType> for ValType {
+	fn from(value: wasmparser::ValType) -> Self {
+		match value {
+			wasmparser::ValType::I32 => Self::I32,
+			wasmparser::ValType::I64 => Self::I64,
+			wasmparser::ValType::F32 => Self::F32,
+			wasmparser::ValType::F64 => Self::F64,
+			wasmparser::ValType::Ref(r#ref) if r#ref.is_extern_ref() => Self::ExternRef,
+			wasmparser::ValType::Ref(r#ref) if r#ref.is_func_ref() => Self::FuncRef,
+			wasmparser::ValType::V128 | wasmparser::ValType::Ref(_) => Self::Other,
+		}
+	}
+}
+
+impl ValType {
+	/// Returns the type JS receives for this value, e.g. `unknown` for an
+	/// `externref`.
+	fn ts(self) -> &'static str {
+		match self {
+			Self::I32 | Self::F32 | Self::F64 => "number",
+			Self::I64 => "bigint",
+			Self::ExternRef => "unknown",
+			Self::FuncRef => "Function | null",
+			Self::Other => "never",
+		}
+	}
+}
+
+impl<T: Deref<Target = str> + Display> JsSource<T> {
+	fn write<W: Write>(
+		&self,
+		output: &mut LineWriter<W>,
+		source_map: &mut SourceMap,
+		embed_keys: Option<&EmbedKeys<'_>>,
+	) -> Result<()> {
+		let start = output.line;
+		let js = match embed_keys {
+			Some(embed_keys) => shorten_embeds(&self.js, embed_keys),
+			None => Cow::Borrowed(&*self.js),
+		};
+
+		for (index, line) in js.lines().enumerate() {
+			if index != 0 {
+				if line.is_empty() {
+					output.write_all(b"\n")?;
+				} else {
+					output.write_all(b"\n\t\t\t\t")?;
+				}
+			}
+
+			output.write_all(line.as_bytes())?;
+		}
+
+		source_map.add(start..=output.line, &self.file, self.line);
+
+		Ok(())
+	}
+}
+
+/// Environment the JS glue is generated for.
+#[derive(Clone, Copy, Default)]
+pub enum Target<'a> {
+	/// ES module fetching the Wasm file with the same name next to it.
+	#[default]
+	Web,
+	/// ES module referencing the given Wasm file with `new URL(...,
+	/// import.meta.url)`, which bundlers pick up.
+	Bundler(&'a str),
+	/// CommonJS module reading the given Wasm file with `node:fs`.
+	Node(&'a str),
+	/// ES module reading the given Wasm file with `Deno.readFile()`.
+	Deno(&'a str),
+}
+
 impl<T: Deref<Target = str> + Display + Eq + Hash + Serialize> JsOutput<'_, T> {
-	pub fn js(&self, mut output: impl Write, main_memory: MemoryType) -> Result<()> {
+	/// Writes the JS glue and returns a source map pointing its embedded and
+	/// imported JS back to the originating Rust macro calls.
+	///
+	/// When minifying, comments and indentation are stripped and embeds are
+	/// stored under shortened keys.
+	pub fn js(
+		&self,
+		mut output: impl Write,
+		main_memory: MemoryType,
+		target: Target<'_>,
+		minify: bool,
+	) -> Result<SourceMap> {
+		if !minify {
+			return self.write_js(output, main_memory, target, None);
+		}
+
+		let mut embed_keys = self
+			.js_embed
+			.iter()
+			.flat_map(|(module, names)| names.keys().map(|name| (&**module, &**name)))
+			.collect::<Vec<_>>();
+		embed_keys.sort_unstable();
+		let embed_keys = embed_keys
+			.into_iter()
+			.enumerate()
+			.map(|(index, embed)| (embed, short_key(index)))
+			.collect();
+
+		let mut js = Vec::new();
+		let mut source_map = self.write_js(&mut js, main_memory, target, Some(&embed_keys))?;
+		let minified = minify::minify(std::str::from_utf8(&js)?);
+		source_map.remap(&minified.lines);
+		output.write_all(minified.js.as_bytes())?;
+
+		Ok(source_map)
+	}
+
+	fn write_js(
+		&self,
+		output: impl Write,
+		main_memory: MemoryType,
+		target: Target<'_>,
+		embed_keys: Option<&EmbedKeys<'_>>,
+	) -> Result<SourceMap> {
 		const IMPORTS_JS: &str = include_str!("js/imports.mjs");
 
 		let (js_file_memory, rest) = IMPORTS_JS.split_once("JBG_PLACEHOLDER_MEMORY").unwrap();
 		let (js_file_embed, rest) = rest.split_once("JBG_PLACEHOLDER_JS_EMBED").unwrap();
-		let (js_file_import, js_file_4) = rest.split_once("JBG_PLACEHOLDER_IMPORT_OBJECT").unwrap();
+		let (js_file_import, rest) = rest.split_once("JBG_PLACEHOLDER_IMPORT_OBJECT").unwrap();
+		let (js_file_response, rest) = rest.split_once("JBG_PLACEHOLDER_WASM_RESPONSE").unwrap();
+		let (js_file_bytes, rest) = rest.split_once("JBG_PLACEHOLDER_WASM_BYTES").unwrap();
+		let (js_file_threads, js_file_7) = rest.split_once("JBG_PLACEHOLDER_THREADS").unwrap();
+
+		let mut output = LineWriter {
+			inner: output,
+			line: 0,
+		};
+		let mut source_map = SourceMap::default();
+
+		let js_file_memory = if let Target::Node(_) = target {
+			// Spawning threads relies on `import.meta.url` and Web Workers.
+			if self.threads {
+				bail!("threads are not supported when targeting Node.js")
+			}
+
+			// Exported at the end with `module.exports`.
+			js_file_memory.strip_prefix("export ").unwrap()
+		} else {
+			js_file_memory
+		};
 
 		// `WebAssembly.Memory`.
 		output.write_all(js_file_memory.as_bytes())?;
@@ -70,28 +263,27 @@ impl<T: Deref<Target = str> + Display + Eq + Hash + Serialize> JsOutput<'_, T> {
 
 		output.write_all(b"{\n")?;
 
-		for (package, embeds) in &self.js_embed {
-			writeln!(output, "\t\t\t{package}: {{")?;
-
-			for (name, js) in embeds {
-				write!(output, "\t\t\t\t'{name}': ")?;
-
-				for (position, line) in js.lines().with_position() {
-					if position.is_middle() || position.is_last() {
-						if line.is_empty() {
-							output.write_all(b"\n")?;
-						} else {
-							output.write_all(b"\n\t\t\t\t")?;
-						}
-					}
+		if let Some(embed_keys) = embed_keys {
+			// Embeds are referenced by their shortened key only.
+			for (module, embeds) in sorted(&self.js_embed) {
+				for (name, js) in sorted(embeds) {
+					write!(output, "\t\t\t{}: ", embed_keys[&(&**module, &**name)])?;
+					js.write(&mut output, &mut source_map, Some(embed_keys))?;
+					output.write_all(b",\n")?;
+				}
+			}
+		} else {
+			for (module, embeds) in sorted(&self.js_embed) {
+				writeln!(output, "\t\t\t{module}: {{")?;
 
-					output.write_all(line.as_bytes())?;
+				for (name, js) in sorted(embeds) {
+					write!(output, "\t\t\t\t'{name}': ")?;
+					js.write(&mut output, &mut source_map, None)?;
+					output.write_all(b",\n")?;
 				}
 
-				output.write_all(b",\n")?;
+				output.write_all(b"\t\t\t},\n")?;
 			}
-
-			output.write_all(b"\t\t\t},\n")?;
 		}
 
 		output.write_all(b"\t\t}")?;
@@ -106,35 +298,586 @@ impl<T: Deref<Target = str> + Display + Eq + Hash + Serialize> JsOutput<'_, T> {
 			self.main_memory.module, self.main_memory.name
 		)?;
 
-		for (module, names) in &self.js_import {
+		for (module, names) in sorted(&self.js_import) {
 			writeln!(output, "\t\t\t{module}: {{")?;
 
-			for (name, js) in names {
+			for (name, js) in sorted(names) {
 				write!(output, "\t\t\t\t'{name}': ")?;
+				js.write(&mut output, &mut source_map, embed_keys)?;
+				output.write_all(b",\n")?;
+			}
+
+			output.write_all(b"\t\t\t},\n")?;
+		}
+
+		output.write_all(b"\t\t}")?;
+
+		// Default Wasm file for `instantiateStreaming()` and `instantiateBytes()`.
+		let (wasm_response, wasm_bytes) = match target {
+			Target::Web => (
+				String::from("fetch(import.meta.url.replace(/\\.mjs$/, \".wasm\"))"),
+				None,
+			),
+			Target::Bundler(wasm) => {
+				let url = format!("new URL({:?}, import.meta.url)", format!("./{wasm}"));
+				(format!("fetch({url})"), None)
+			}
+			Target::Node(wasm) => {
+				let path = format!("require(\"node:path\").join(__dirname, {wasm:?})");
+				(
+					format!(
+						"require(\"node:fs/promises\").readFile({path}).then(bytes => new \
+						 Response(bytes, {{ headers: {{ \"Content-Type\": \"application/wasm\" }} \
+						 }}))"
+					),
+					Some(format!("require(\"node:fs\").readFileSync({path})")),
+				)
+			}
+			Target::Deno(wasm) => {
+				let url = format!("new URL({:?}, import.meta.url)", format!("./{wasm}"));
+				(
+					format!("fetch({url})"),
+					Some(format!("Deno.readFileSync({url})")),
+				)
+			}
+		};
+
+		output.write_all(js_file_response.as_bytes())?;
+		write!(output, "(() => {wasm_response})")?;
+
+		output.write_all(js_file_bytes.as_bytes())?;
+
+		if let Some(wasm_bytes) = wasm_bytes {
+			write!(output, "(() => {wasm_bytes})")?;
+		} else {
+			output.write_all(
+				b"(() => { throw new Error(\"reading the Wasm file synchronously is not supported, \
+				  pass its bytes instead\") })",
+			)?;
+		}
+
+		// Worker bootstrap.
+		output.write_all(js_file_threads.as_bytes())?;
+
+		if self.threads {
+			output.write_all(b"true")?;
+		} else {
+			output.write_all(b"false")?;
+		}
+
+		// Finish
+		output.write_all(js_file_7.as_bytes())?;
+
+		if let Target::Node(_) = target {
+			output.write_all(b"module.exports = { JsBindgen };\n")?;
+		}
+
+		Ok(source_map)
+	}
+
+	/// Writes the TypeScript declarations for the output of [`Self::js()`].
+	///
+	/// Exported functions are typed by their Wasm signature, e.g. `externref`
+	/// as `unknown`. Unlike imports, exports carry no information about their
+	/// Rust types in custom sections.
+	pub fn dts(&self, mut output: impl Write) -> Result<()> {
+		const IMPORTS_DTS: &str = include_str!("js/imports.d.mts");
+		// As emitted by `tsc` for `imports.mts`.
+		const EXPORTS: &str = "export type Exports = WebAssembly.Exports;";
+
+		let (dts_file_1, dts_file_2) = IMPORTS_DTS
+			.split_once(EXPORTS)
+			.expect("`imports.d.mts` should declare `Exports`");
+
+		output.write_all(dts_file_1.as_bytes())?;
+
+		output.write_all(b"export type Exports = {\n")?;
 
-				for (position, line) in js.lines().with_position() {
-					if position.is_middle() || position.is_last() {
-						if line.is_empty() {
-							output.write_all(b"\n")?;
-						} else {
-							output.write_all(b"\n\t\t\t\t")?;
+		for Export { name, kind } in &self.exports {
+			write!(output, "    readonly {:?}: ", &**name)?;
+
+			match kind {
+				ExportKind::Function { params, results } => {
+					output.write_all(b"(")?;
+
+					for (index, param) in params.iter().enumerate() {
+						if index != 0 {
+							output.write_all(b", ")?;
 						}
+
+						write!(output, "arg{index}: {}", param.ts())?;
 					}
 
-					output.write_all(line.as_bytes())?;
+					output.write_all(b") => ")?;
+
+					match results.as_slice() {
+						[] => output.write_all(b"void")?,
+						[result] => output.write_all(result.ts().as_bytes())?,
+						results => write!(
+							output,
+							"[{}]",
+							results.iter().map(|result| result.ts()).format(", ")
+						)?,
+					}
 				}
+				ExportKind::Global => output.write_all(b"WebAssembly.Global")?,
+				ExportKind::Memory => output.write_all(b"WebAssembly.Memory")?,
+				ExportKind::Table => output.write_all(b"WebAssembly.Table")?,
+			}
 
-				output.write_all(b",\n")?;
+			output.write_all(b";\n")?;
+		}
+
+		output.write_all(b"};")?;
+
+		output.write_all(dts_file_2.as_bytes())?;
+
+		Ok(())
+	}
+}
+
+/// Maps lines of the JS glue to the Rust macro calls their JS originates from.
+#[derive(Default)]
+pub struct SourceMap {
+	sources: Vec<String>,
+	/// Source index and zero-based source line of every generated line.
+	lines: Vec<Option<(usize, u32)>>,
+}
+
+impl SourceMap {
+	fn add(&mut self, lines: RangeInclusive<usize>, file: &str, line: u32) {
+		let source = if let Some(source) = self.sources.iter().position(|source| source == file) {
+			source
+		} else {
+			self.sources.push(file.to_owned());
+			self.sources.len() - 1
+		};
+
+		if self.lines.len() <= *lines.end() {
+			self.lines.resize(lines.end() + 1, None);
+		}
+
+		for generated in lines {
+			self.lines[generated] = Some((source, line.saturating_sub(1)));
+		}
+	}
+
+	/// Adjusts the generated lines after the JS was minified.
+	fn remap(&mut self, lines: &[usize]) {
+		self.lines = lines
+			.iter()
+			.map(|line| self.lines.get(*line).copied().flatten())
+			.collect();
+
+		while let Some(None) = self.lines.last() {
+			self.lines.pop();
+		}
+	}
+
+	/// Writes the source map in the [Source Map format] for the JS glue
+	/// `file`. Relative sources are resolved against `root`.
+	///
+	/// [Source Map format]: https://tc39.es/ecma426/
+	pub fn write(&self, mut output: impl Write, file: &str, root: &Path) -> Result<()> {
+		write!(
+			output,
+			"{{\"version\":3,\"file\":{},\"sources\":[",
+			serde_json::to_string(file)?
+		)?;
+
+		for (index, source) in self.sources.iter().enumerate() {
+			if index != 0 {
+				output.write_all(b",")?;
 			}
 
-			output.write_all(b"\t\t\t},\n")?;
+			let source = root.join(source).display().to_string();
+			output.write_all(serde_json::to_string(&source)?.as_bytes())?;
 		}
 
-		output.write_all(b"\t\t}")?;
+		output.write_all(b"],\"names\":[],\"mappings\":\"")?;
 
-		// Finish
-		output.write_all(js_file_4.as_bytes())?;
+		// Every mapped line has a single segment starting at the first column, which
+		// is encoded relative to the previous segment.
+		let mut previous = (0, 0);
+
+		for (index, line) in self.lines.iter().enumerate() {
+			if index != 0 {
+				output.write_all(b";")?;
+			}
+
+			if let Some((source, line)) = *line {
+				let mut segment = String::from("A");
+				vlq(
+					&mut segment,
+					i64::try_from(source)? - i64::try_from(previous.0)?,
+				);
+				vlq(&mut segment, i64::from(line) - i64::from(previous.1));
+				segment.push('A');
+				output.write_all(segment.as_bytes())?;
+
+				previous = (source, line);
+			}
+		}
+
+		output.write_all(b"\"}")?;
 
 		Ok(())
 	}
 }
+
+/// Orders map entries by their key, so the output is deterministic.
+fn sorted<K: Deref<Target = str>, V>(map: &FixedHashMap<K, V>) -> Vec<(&K, &V)> {
+	let mut entries: Vec<_> = map.iter().collect();
+	entries.sort_unstable_by(|a, b| a.0.deref().cmp(b.0.deref()));
+	entries
+}
+
+/// Generates short but valid JS identifiers: `a`, `b`, ..., `Z`, `aa`, `ba`,
+/// ...
+fn short_key(mut index: usize) -> String {
+	const FIRST: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+	const REST: &[u8; 64] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_$";
+
+	let mut key = String::from(char::from(FIRST[index % FIRST.len()]));
+	index /= FIRST.len();
+
+	while index != 0 {
+		index -= 1;
+		key.push(char::from(REST[index % REST.len()]));
+		index /= REST.len();
+	}
+
+	key
+}
+
+/// Replaces `this.#jsEmbed.<module>['<name>']` and
+/// `this.#jsEmbed.<module>.<name>` with the shortened key of the embed.
+fn shorten_embeds<'js>(js: &'js str, embed_keys: &EmbedKeys<'_>) -> Cow<'js, str> {
+	const PREFIX: &str = "this.#jsEmbed.";
+
+	fn identifier(js: &str) -> (&str, &str) {
+		js.split_at(
+			js.find(|char: char| !(char.is_alphanumeric() || matches!(char, '_' | '$')))
+				.unwrap_or(js.len()),
+		)
+	}
+
+	if !js.contains(PREFIX) {
+		return Cow::Borrowed(js);
+	}
+
+	let mut output = String::with_capacity(js.len());
+	let mut rest = js;
+
+	while let Some(position) = rest.find(PREFIX) {
+		let (before, after) = rest.split_at(position + PREFIX.len());
+		output.push_str(before);
+		rest = after;
+
+		let (module, after) = identifier(after);
+		let name = if let Some(after) = after.strip_prefix("['") {
+			after.split_once("']")
+		} else {
+			after.strip_prefix('.').map(identifier)
+		};
+
+		if let Some((name, after)) = name
+			&& let Some(key) = embed_keys.get(&(module, name))
+		{
+			output.push_str(key);
+			rest = after;
+		}
+	}
+
+	output.push_str(rest);
+
+	Cow::Owned(output)
+}
+
+/// Base64 VLQ encoding as used in the `mappings` of source maps.
+fn vlq(output: &mut String, value: i64) {
+	const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+	// The sign is stored in the least significant bit.
+	let mut value = (value.unsigned_abs() << 1) | u64::from(value < 0);
+
+	loop {
+		let mut digit = value & 0b1_1111;
+		value >>= 5;
+
+		if value != 0 {
+			digit |= 0b10_0000;
+		}
+
+		output.push(char::from(BASE64[usize::try_from(digit).unwrap()]));
+
+		if value == 0 {
+			break;
+		}
+	}
+}
+
+/// Counts the lines written, used to build the [`SourceMap`].
+struct LineWriter<W> {
+	inner: W,
+	line: usize,
+}
+
+impl<W: Write> Write for LineWriter<W> {
+	#[expect(clippy::naive_bytecount, reason = "not worth a dependency")]
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		let written = self.inner.write(buf)?;
+		self.line += buf[..written].iter().filter(|byte| **byte == b'\n').count();
+
+		Ok(written)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.inner.flush()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use wasmparser::MemoryType;
+
+	use crate::{
+		Export, ExportKind, FixedHashMap, JsOutput, JsSource, MainMemory, SourceMap, Target,
+		ValType,
+	};
+
+	const MEMORY: MemoryType = MemoryType {
+		memory64: false,
+		shared: false,
+		initial: 17,
+		maximum: None,
+		page_size_log2: None,
+	};
+
+	fn source(js: &'static str) -> JsSource<&'static str> {
+		JsSource {
+			js,
+			file: "src/lib.rs",
+			line: 1,
+		}
+	}
+
+	fn output(
+		js_import: &[(&'static str, &'static str, &'static str)],
+		js_embed: &[(&'static str, &'static str, &'static str)],
+	) -> JsOutput<'static, &'static str> {
+		fn map(
+			js: &[(&'static str, &'static str, &'static str)],
+		) -> FixedHashMap<&'static str, FixedHashMap<&'static str, JsSource<&'static str>>> {
+			let mut map: FixedHashMap<_, FixedHashMap<_, _>> = FixedHashMap::default();
+
+			for &(module, name, js) in js {
+				map.entry(module).or_default().insert(name, source(js));
+			}
+
+			map
+		}
+
+		JsOutput {
+			main_memory: MainMemory {
+				module: "js_bindgen",
+				name: "memory",
+			},
+			source_root: "/root",
+			js_import: map(js_import),
+			js_embed: map(js_embed),
+			threads: false,
+			exports: Vec::new(),
+		}
+	}
+
+	#[test]
+	fn minify_deterministic() {
+		let mut js_import = [
+			(
+				"foo",
+				"log",
+				"(value) => {\n\t// Log.\n\tconsole.log(value)\n}",
+			),
+			(
+				"foo",
+				"add",
+				"(a, b) => this.#jsEmbed.bar['math.add'](a, b)",
+			),
+			("baz", "sub", "(a, b) => this.#jsEmbed.bar.sub(a, b)"),
+		];
+		let mut js_embed = [
+			("bar", "math.add", "(a, b) => a + b"),
+			("bar", "sub", "(a, b) => /* Subtract. */ a - b"),
+		];
+
+		let mut minified = Vec::new();
+		output(&js_import, &js_embed)
+			.js(&mut minified, MEMORY, Target::Web, true)
+			.unwrap();
+
+		let mut reversed = Vec::new();
+		js_import.reverse();
+		js_embed.reverse();
+		output(&js_import, &js_embed)
+			.js(&mut reversed, MEMORY, Target::Web, true)
+			.unwrap();
+
+		assert_eq!(minified, reversed);
+
+		let minified = String::from_utf8(minified).unwrap();
+		assert!(
+			minified.contains("this.#jsEmbed = {\na: (a, b) => a + b,\nb: (a, b) => a - b,\n}")
+		);
+		assert!(minified.contains("'add': (a, b) => this.#jsEmbed.a(a, b),"));
+		assert!(minified.contains("'sub': (a, b) => this.#jsEmbed.b(a, b),"));
+		assert!(minified.contains("'log': (value) => {\nconsole.log(value)\n},"));
+		assert!(!minified.contains("//"));
+		assert!(!minified.contains('\t'));
+	}
+
+	#[test]
+	fn dts() {
+		let mut output = output(&[], &[]);
+		output.exports = vec![
+			Export {
+				name: "add",
+				kind: ExportKind::Function {
+					params: vec![ValType::I32, ValType::ExternRef],
+					results: vec![ValType::I64],
+				},
+			},
+			Export {
+				name: "pair",
+				kind: ExportKind::Function {
+					params: Vec::new(),
+					results: vec![ValType::F64, ValType::FuncRef],
+				},
+			},
+			Export {
+				name: "memory",
+				kind: ExportKind::Memory,
+			},
+		];
+
+		let mut dts = Vec::new();
+		output.dts(&mut dts).unwrap();
+		let dts = String::from_utf8(dts).unwrap();
+
+		assert!(dts.contains(
+			"export type Exports = {\n    readonly \"add\": (arg0: number, arg1: unknown) => \
+			 bigint;\n    readonly \"pair\": () => [number, Function | null];\n    readonly \
+			 \"memory\": WebAssembly.Memory;\n};\n"
+		));
+		assert!(dts.contains("export declare class JsBindgen {"));
+		assert!(!dts.contains("WebAssembly.Exports"));
+	}
+
+	fn js(target: Target<'_>) -> String {
+		let mut js = Vec::new();
+		output(&[], &[]).js(&mut js, MEMORY, target, false).unwrap();
+		String::from_utf8(js).unwrap()
+	}
+
+	#[test]
+	fn target_web() {
+		let js = js(Target::Web);
+		assert!(js.starts_with("export class JsBindgen {"));
+		assert!(js.contains("fetch(import.meta.url.replace(/\\.mjs$/, \".wasm\"))"));
+		assert!(js.contains("reading the Wasm file synchronously is not supported"));
+	}
+
+	#[test]
+	fn target_bundler() {
+		let js = js(Target::Bundler("test.wasm"));
+		assert!(js.starts_with("export class JsBindgen {"));
+		assert!(js.contains("fetch(new URL(\"./test.wasm\", import.meta.url))"));
+		assert!(js.contains("reading the Wasm file synchronously is not supported"));
+	}
+
+	#[test]
+	fn target_node() {
+		let js = js(Target::Node("test.wasm"));
+		assert!(!js.lines().any(|line| line.starts_with("export")));
+		assert!(!js.contains("import.meta"));
+		assert!(js.ends_with("module.exports = { JsBindgen };\n"));
+		assert!(js.contains(
+			"require(\"node:fs\").readFileSync(require(\"node:path\").join(__dirname, \
+			 \"test.wasm\"))"
+		));
+	}
+
+	#[test]
+	fn target_node_threads() {
+		let mut output = output(&[], &[]);
+		output.threads = true;
+
+		assert!(
+			output
+				.js(Vec::new(), MEMORY, Target::Node("test.wasm"), false)
+				.is_err()
+		);
+	}
+
+	#[test]
+	fn target_deno() {
+		let js = js(Target::Deno("test.wasm"));
+		assert!(js.starts_with("export class JsBindgen {"));
+		assert!(js.contains("fetch(new URL(\"./test.wasm\", import.meta.url))"));
+		assert!(js.contains("Deno.readFileSync(new URL(\"./test.wasm\", import.meta.url))"));
+	}
+
+	#[test]
+	fn vlq() {
+		for (value, expected) in [
+			(0, "A"),
+			(1, "C"),
+			(-1, "D"),
+			(15, "e"),
+			(16, "gB"),
+			(-17, "jB"),
+			(1000, "w+B"),
+		] {
+			let mut output = String::new();
+			super::vlq(&mut output, value);
+			assert_eq!(output, expected, "{value}");
+		}
+	}
+
+	#[test]
+	fn source_map() {
+		let mut source_map = SourceMap::default();
+		source_map.add(0..=1, "src/lib.rs", 3);
+		source_map.add(3..=3, "/absolute/src/b.rs", 1);
+		source_map.add(4..=4, "src/lib.rs", 10);
+
+		let mut output = Vec::new();
+		source_map
+			.write(&mut output, "test.mjs", Path::new("/root"))
+			.unwrap();
+
+		assert_eq!(
+			String::from_utf8(output).unwrap(),
+			"{\"version\":3,\"file\":\"test.mjs\",\"sources\":[\"/root/src/lib.rs\",\"/absolute/\
+			 src/b.rs\"],\"names\":[],\"mappings\":\"AAEA;AAAA;;ACFA;ADSA\"}"
+		);
+	}
+
+	#[test]
+	fn source_map_escape() {
+		let mut source_map = SourceMap::default();
+		// Decomposed characters are common in paths on macOS.
+		source_map.add(0..=0, "src/cafe\u{301}.rs", 1);
+
+		let mut output = Vec::new();
+		source_map
+			.write(&mut output, "\"test\".mjs", Path::new("/root"))
+			.unwrap();
+
+		let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+		assert_eq!(json["file"], "\"test\".mjs");
+		assert_eq!(json["sources"][0], "/root/src/cafe\u{301}.rs");
+	}
+}
@@ -0,0 +1,290 @@
+//! Strips comments and redundant whitespace from JS. Line breaks are kept so
+//! automatic semicolon insertion is not affected.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+pub(crate) struct Minified {
+	pub js: String,
+	/// The zero-based input line every output line starts at.
+	pub lines: Vec<usize>,
+}
+
+enum Context {
+	Template,
+	/// Expression inside a template literal with the number of open braces.
+	Expression(usize),
+}
+
+struct Minifier<'js> {
+	input: Peekable<Chars<'js>>,
+	input_line: usize,
+	output: String,
+	lines: Vec<usize>,
+	line: String,
+	line_start: usize,
+	stack: Vec<Context>,
+}
+
+/// Keywords after which a `/` starts a regular expression instead of a
+/// division.
+const REGEX_KEYWORDS: [&str; 13] = [
+	"await",
+	"case",
+	"delete",
+	"do",
+	"else",
+	"in",
+	"instanceof",
+	"new",
+	"of",
+	"return",
+	"throw",
+	"typeof",
+	"void",
+];
+
+pub(crate) fn minify(js: &str) -> Minified {
+	let mut minifier = Minifier {
+		input: js.chars().peekable(),
+		input_line: 0,
+		output: String::with_capacity(js.len()),
+		lines: Vec::new(),
+		line: String::new(),
+		line_start: 0,
+		stack: Vec::new(),
+	};
+
+	while let Some(char) = minifier.input.next() {
+		if let Some(Context::Template) = minifier.stack.last() {
+			minifier.template(char);
+		} else {
+			minifier.code(char);
+		}
+	}
+
+	minifier.flush();
+
+	if !minifier.output.is_empty() {
+		minifier.output.push('\n');
+	}
+
+	Minified {
+		js: minifier.output,
+		lines: minifier.lines,
+	}
+}
+
+impl Minifier<'_> {
+	fn push(&mut self, char: char) {
+		if self.line.is_empty() {
+			self.line_start = self.input_line;
+		}
+
+		self.line.push(char);
+	}
+
+	/// Copies the next character verbatim.
+	fn copy_next(&mut self) -> Option<char> {
+		let char = self.input.next()?;
+
+		if char == '\n' {
+			self.input_line += 1;
+		}
+
+		self.push(char);
+		Some(char)
+	}
+
+	fn space(&mut self) {
+		if !self.line.is_empty() && !self.line.ends_with(' ') {
+			self.line.push(' ');
+		}
+	}
+
+	fn flush(&mut self) {
+		let line = self.line.trim_end();
+
+		if !line.is_empty() {
+			if !self.output.is_empty() {
+				self.output.push('\n');
+			}
+
+			self.output.push_str(line);
+			self.lines.push(self.line_start);
+		}
+
+		self.line.clear();
+	}
+
+	fn template(&mut self, char: char) {
+		if char == '\n' {
+			self.input_line += 1;
+		}
+
+		self.push(char);
+
+		match char {
+			'\\' => {
+				self.copy_next();
+			}
+			'`' => {
+				self.stack.pop();
+			}
+			'$' if self.input.peek() == Some(&'{') => {
+				self.copy_next();
+				self.stack.push(Context::Expression(0));
+			}
+			_ => (),
+		}
+	}
+
+	fn code(&mut self, char: char) {
+		match char {
+			'\n' => {
+				self.input_line += 1;
+				self.flush();
+			}
+			' ' | '\t' | '\r' => self.space(),
+			'\'' | '"' => {
+				self.push(char);
+
+				while let Some(next) = self.input.peek().copied() {
+					// Unterminated strings end at the line break.
+					if next == '\n' {
+						break;
+					}
+
+					self.copy_next();
+
+					if next == '\\' {
+						self.copy_next();
+					} else if next == char {
+						break;
+					}
+				}
+			}
+			'`' => {
+				self.push(char);
+				self.stack.push(Context::Template);
+			}
+			'{' => {
+				if let Some(Context::Expression(depth)) = self.stack.last_mut() {
+					*depth += 1;
+				}
+
+				self.push(char);
+			}
+			'}' => {
+				match self.stack.last_mut() {
+					Some(Context::Expression(0)) => {
+						self.stack.pop();
+					}
+					Some(Context::Expression(depth)) => *depth -= 1,
+					_ => (),
+				}
+
+				self.push(char);
+			}
+			'/' => match self.input.peek().copied() {
+				Some('/') => while self.input.next_if(|char| *char != '\n').is_some() {},
+				Some('*') => {
+					self.input.next();
+					let mut line_break = false;
+
+					while let Some(char) = self.input.next() {
+						if char == '\n' {
+							self.input_line += 1;
+							line_break = true;
+						} else if char == '*' && self.input.next_if_eq(&'/').is_some() {
+							break;
+						}
+					}
+
+					// A comment containing a line break counts as one for automatic
+					// semicolon insertion.
+					if line_break {
+						self.flush();
+					} else {
+						self.space();
+					}
+				}
+				_ if self.is_regex_start() => {
+					self.push(char);
+					let mut class = false;
+
+					while let Some(next) = self.input.peek().copied() {
+						// Unterminated regular expressions end at the line break.
+						if next == '\n' {
+							break;
+						}
+
+						self.copy_next();
+
+						match next {
+							'\\' => {
+								self.copy_next();
+							}
+							'[' => class = true,
+							']' => class = false,
+							'/' if !class => break,
+							_ => (),
+						}
+					}
+				}
+				_ => self.push(char),
+			},
+			char => self.push(char),
+		}
+	}
+
+	fn is_regex_start(&self) -> bool {
+		let previous = if self.line.trim_end().is_empty() {
+			self.output.as_str()
+		} else {
+			self.line.trim_end()
+		};
+
+		match previous.chars().next_back() {
+			None => true,
+			Some(char) if char.is_alphanumeric() || matches!(char, '_' | '$') => {
+				let word = previous
+					.rsplit(|char: char| !(char.is_alphanumeric() || matches!(char, '_' | '$')))
+					.next()
+					.unwrap_or_default();
+				REGEX_KEYWORDS.contains(&word)
+			}
+			Some(char) => !matches!(char, ')' | ']' | '\'' | '"' | '`'),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::minify;
+
+	#[test]
+	fn comments() {
+		let minified = minify(
+			"// Line comment.\nconst a = 1; /* Block */ const b = 2;\n/**\n * Doc.\n */\nreturn \
+			 a\n\t\t+ b;\n",
+		);
+
+		assert_eq!(minified.js, "const a = 1; const b = 2;\nreturn a\n+ b;\n");
+		assert_eq!(minified.lines, [1, 5, 6]);
+	}
+
+	#[test]
+	fn literals() {
+		let minified = minify(
+			"const a = '//  not a comment';\nconst b = /\\/*[/]  /g.test(a) / 2;\nconst c = \
+			 `\n\t${ { a }.a }  // kept\n`;\n",
+		);
+
+		assert_eq!(
+			minified.js,
+			"const a = '//  not a comment';\nconst b = /\\/*[/]  /g.test(a) / 2;\nconst c = \
+			 `\n\t${ { a }.a }  // kept\n`;\n"
+		);
+	}
+}
@@ -0,0 +1,72 @@
+use anyhow::{Context, Result};
+use wasm_encoder::RawSection;
+use wasmparser::{MemoryType, Payload, TypeRef};
+
+use crate::{JS_OUTPUT_SECTION, JsOutput};
+
+/// Finds the JS output and the main memory of a Wasm file linked by
+/// `js-bindgen-ld`.
+#[derive(Default)]
+pub struct JsOutputParser<'a> {
+	js_output: Option<&'a [u8]>,
+	memories: Vec<(&'a str, &'a str, MemoryType)>,
+}
+
+impl<'a> JsOutputParser<'a> {
+	pub fn parse(&mut self, payload: &Payload<'a>) -> Result<()> {
+		match payload {
+			Payload::ImportSection(i) => {
+				for i in i.clone().into_imports() {
+					let import = i.context("import should be parsable")?;
+
+					if let TypeRef::Memory(memory) = import.ty {
+						self.memories.push((import.module, import.name, memory));
+					}
+				}
+			}
+			Payload::CustomSection(c) if c.name() == JS_OUTPUT_SECTION => {
+				self.js_output = Some(c.data());
+			}
+			_ => (),
+		}
+
+		Ok(())
+	}
+
+	/// Returns `true` if the JS output was found. Imports precede custom
+	/// sections, so this is the last thing to look for.
+	#[must_use]
+	pub const fn found(&self) -> bool {
+		self.js_output.is_some()
+	}
+
+	/// Returns the JS output and the type of its main memory.
+	pub fn finish(self) -> Result<(JsOutput<'a, &'a str>, MemoryType)> {
+		let js_output: JsOutput<'a, &'a str> = postcard::from_bytes(
+			self.js_output
+				.context("unable to find JS output, was it linked with `js-bindgen-ld`?")?,
+		)?;
+
+		let (.., main_memory) = self
+			.memories
+			.into_iter()
+			.find(|(module, name, _)| {
+				*module == js_output.main_memory.module && *name == js_output.main_memory.name
+			})
+			.context("unable to find main memory as encoded")?;
+
+		Ok((js_output, main_memory))
+	}
+}
+
+/// Returns `payload` as a section to copy into a new Wasm file.
+pub fn raw_section<'a>(wasm: &'a [u8], payload: &Payload<'_>) -> Result<RawSection<'a>> {
+	let (id, range) = payload
+		.as_section()
+		.with_context(|| format!("expected parsable Wasm payload:\n{payload:?}"))?;
+
+	Ok(RawSection {
+		id,
+		data: &wasm[range],
+	})
+}
@@ -0,0 +1,355 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use clap::{Args, ValueEnum};
+use js_bindgen_cli_lib::{JS_OUTPUT_SECTION, JsOutputParser, Target};
+use js_bindgen_shared::{IS_COMPAT_SECTION, IS_TEST_SECTION, ReadFile};
+use wasm_encoder::{CustomSection, Encode, Module, Section};
+use wasmparser::{Encoding, Parser, Payload};
+
+#[derive(Args)]
+pub(crate) struct Build {
+	/// Wasm file linked by `js-bindgen-ld`.
+	file: PathBuf,
+	/// Directory to write `<name>.wasm`, the JS glue and its TypeScript
+	/// declarations to.
+	#[arg(long)]
+	out_dir: PathBuf,
+	/// Environment to generate the JS glue for.
+	#[arg(long, value_enum, default_value_t)]
+	target: TargetArg,
+	/// Write a source map for the JS glue, pointing back to the Rust macro
+	/// calls the JS originates from.
+	#[arg(long)]
+	source_map: bool,
+	/// Strip comments and indentation from the JS glue and shorten the keys of
+	/// embedded JS.
+	#[arg(long)]
+	minify: bool,
+	/// Move DWARF sections into `<name>.debug.wasm`, referenced by an
+	/// `external_debug_info` section.
+	#[arg(long)]
+	split_debug_info: bool,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum TargetArg {
+	/// ES module fetching the Wasm file next to it.
+	#[default]
+	Web,
+	/// ES module referencing the Wasm file in a way bundlers understand.
+	Bundler,
+	/// CommonJS module for Node.js.
+	Node,
+	/// ES module for Deno.
+	Deno,
+}
+
+impl Build {
+	pub(crate) fn run(self) -> Result<()> {
+		let wasm_input = ReadFile::new(&self.file)
+			.with_context(|| format!("failed to read Wasm file: {}", self.file.display()))?;
+		let name = self
+			.file
+			.file_stem()
+			.and_then(OsStr::to_str)
+			.context("expected the Wasm file to have a UTF-8 name")?;
+		let wasm_name = format!("{name}.wasm");
+		let target = match self.target {
+			TargetArg::Web => Target::Web,
+			TargetArg::Bundler => Target::Bundler(&wasm_name),
+			TargetArg::Node => Target::Node(&wasm_name),
+			TargetArg::Deno => Target::Deno(&wasm_name),
+		};
+		// Node.js treats `.js` files as CommonJS or ES modules depending on the
+		// `package.json`, so we are explicit.
+		let (js_extension, dts_extension) = match self.target {
+			TargetArg::Node => ("cjs", "d.cts"),
+			TargetArg::Web | TargetArg::Bundler | TargetArg::Deno => ("mjs", "d.mts"),
+		};
+
+		let mut wasm_output = Vec::new();
+		// A full copy of the module, because DWARF refers to offsets in the code
+		// section.
+		let mut debug_output = self.split_debug_info.then(Vec::new);
+		let mut js_output = JsOutputParser::default();
+
+		for payload in Parser::new(0).parse_all(&wasm_input) {
+			let payload = payload.context("expected a valid Wasm file")?;
+			js_output.parse(&payload)?;
+
+			match payload {
+				Payload::Version { encoding, .. } => {
+					let header = match encoding {
+						Encoding::Module => &Module::HEADER,
+						Encoding::Component => bail!("components are not supported"),
+					};
+					wasm_output.extend_from_slice(header);
+
+					if let Some(debug_output) = &mut debug_output {
+						debug_output.extend_from_slice(header);
+					}
+				}
+				// The JS output is only needed to generate the JS glue.
+				Payload::CustomSection(c) if c.name() == JS_OUTPUT_SECTION => {}
+				// Only used by `js-bindgen-runner`.
+				Payload::CustomSection(c)
+					if c.name() == IS_TEST_SECTION || c.name() == IS_COMPAT_SECTION => {}
+				Payload::CodeSectionEntry(_) | Payload::End(_) => (),
+				payload => {
+					let is_dwarf = matches!(
+						&payload,
+						Payload::CustomSection(c) if c.name().starts_with(".debug_")
+					);
+					let section = js_bindgen_cli_lib::raw_section(&wasm_input, &payload)?;
+
+					if let Some(debug_output) = &mut debug_output {
+						section.append_to(debug_output);
+
+						if is_dwarf {
+							continue;
+						}
+					}
+
+					section.append_to(&mut wasm_output);
+				}
+			}
+		}
+
+		let (js_output, main_memory) = js_output
+			.finish()
+			.with_context(|| format!("invalid Wasm file: {}", self.file.display()))?;
+
+		let js_name = format!("{name}.{js_extension}");
+		let mut js_file = Vec::new();
+		let source_map = js_output.js(&mut js_file, main_memory, target, self.minify)?;
+
+		let source_map = if self.source_map {
+			let map_name = format!("{js_name}.map");
+			writeln!(js_file, "//# sourceMappingURL={map_name}")?;
+
+			let mut map_file = Vec::new();
+			source_map.write(&mut map_file, &js_name, Path::new(js_output.source_root))?;
+			Some((map_name, map_file))
+		} else {
+			None
+		};
+
+		let debug_output = debug_output.map(|debug_output| {
+			let debug_name = format!("{name}.debug.wasm");
+
+			// The URL is resolved relative to the Wasm file.
+			let mut data = Vec::new();
+			debug_name.as_str().encode(&mut data);
+			CustomSection {
+				name: "external_debug_info".into(),
+				data: data.into(),
+			}
+			.append_to(&mut wasm_output);
+
+			(debug_name, debug_output)
+		});
+
+		let mut dts_file = Vec::new();
+		js_output.dts(&mut dts_file)?;
+
+		fs::create_dir_all(&self.out_dir).with_context(|| {
+			format!(
+				"failed to create output directory: {}",
+				self.out_dir.display()
+			)
+		})?;
+
+		let wasm_path = self.out_dir.join(wasm_name);
+		fs::write(&wasm_path, wasm_output)
+			.with_context(|| format!("failed to write Wasm file: {}", wasm_path.display()))?;
+
+		if let Some((debug_name, debug_output)) = debug_output {
+			let debug_path = self.out_dir.join(debug_name);
+			fs::write(&debug_path, debug_output).with_context(|| {
+				format!("failed to write debug info file: {}", debug_path.display())
+			})?;
+		}
+
+		let js_path = self.out_dir.join(js_name);
+		fs::write(&js_path, js_file)
+			.with_context(|| format!("failed to write JS file: {}", js_path.display()))?;
+
+		if let Some((map_name, map_file)) = source_map {
+			let map_path = self.out_dir.join(map_name);
+			fs::write(&map_path, map_file)
+				.with_context(|| format!("failed to write source map: {}", map_path.display()))?;
+		}
+
+		// TypeScript resolves declarations of `.mjs` and `.cjs` files from `.d.mts`
+		// and `.d.cts` files.
+		let dts_path = self.out_dir.join(name).with_extension(dts_extension);
+		fs::write(&dts_path, dts_file).with_context(|| {
+			format!(
+				"failed to write TypeScript declarations: {}",
+				dts_path.display()
+			)
+		})?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs;
+	use std::path::Path;
+
+	use hashbrown::HashMap;
+	use js_bindgen_cli_lib::{JS_OUTPUT_SECTION, JsOutput, JsSource, MainMemory};
+	use js_bindgen_shared::{IS_COMPAT_SECTION, IS_TEST_SECTION};
+	use tempfile::TempDir;
+	use wasm_encoder::{CustomSection, Encode, ImportSection, MemoryType, Module};
+	use wasmparser::{Parser, Payload};
+
+	use super::{Build, TargetArg};
+
+	/// Builds a module linked like by `js-bindgen-ld` with the given custom
+	/// sections into `out` of the returned directory.
+	fn build(sections: &[(&str, &[u8])], source_map: bool, split_debug_info: bool) -> TempDir {
+		let js_output = JsOutput::<&str> {
+			main_memory: MainMemory {
+				module: "js_bindgen",
+				name: "memory",
+			},
+			source_root: "/project",
+			js_import: [(
+				"test",
+				[(
+					"log",
+					JsSource {
+						js: "(value) => console.log(value)",
+						file: "src/lib.rs",
+						line: 3,
+					},
+				)]
+				.into_iter()
+				.collect(),
+			)]
+			.into_iter()
+			.collect(),
+			js_embed: HashMap::default(),
+			threads: false,
+			exports: Vec::new(),
+		};
+
+		let mut imports = ImportSection::new();
+		imports.import(
+			"js_bindgen",
+			"memory",
+			MemoryType {
+				minimum: 17,
+				maximum: None,
+				memory64: false,
+				shared: false,
+				page_size_log2: None,
+			},
+		);
+
+		let mut module = Module::new();
+		module.section(&imports);
+		module.section(&CustomSection {
+			name: JS_OUTPUT_SECTION.into(),
+			data: postcard::to_allocvec(&js_output).unwrap().into(),
+		});
+
+		for (name, data) in sections {
+			module.section(&CustomSection {
+				name: (*name).into(),
+				data: (*data).into(),
+			});
+		}
+
+		let dir = tempfile::tempdir().unwrap();
+		let file = dir.path().join("test.wasm");
+		fs::write(&file, module.finish()).unwrap();
+
+		Build {
+			file,
+			out_dir: dir.path().join("out"),
+			target: TargetArg::Web,
+			source_map,
+			minify: false,
+			split_debug_info,
+		}
+		.run()
+		.unwrap();
+
+		dir
+	}
+
+	fn custom_sections(path: &Path) -> Vec<(String, Vec<u8>)> {
+		Parser::new(0)
+			.parse_all(&fs::read(path).unwrap())
+			.filter_map(|payload| match payload.unwrap() {
+				Payload::CustomSection(c) => Some((c.name().to_owned(), c.data().to_owned())),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn strip_sections() {
+		let dir = build(
+			&[
+				(IS_COMPAT_SECTION, &[]),
+				(IS_TEST_SECTION, &[]),
+				("name", &[0, 5, 4, b't', b'e', b's', b't']),
+			],
+			false,
+			false,
+		);
+		let out_dir = dir.path().join("out");
+
+		assert_eq!(
+			custom_sections(&out_dir.join("test.wasm")),
+			[(String::from("name"), vec![0, 5, 4, b't', b'e', b's', b't'])]
+		);
+
+		let js = fs::read_to_string(out_dir.join("test.mjs")).unwrap();
+		assert!(js.contains("export class JsBindgen"));
+		assert!(!js.contains("sourceMappingURL"));
+		assert!(out_dir.join("test.d.mts").exists());
+		assert!(!out_dir.join("test.mjs.map").exists());
+		assert!(!out_dir.join("test.debug.wasm").exists());
+	}
+
+	#[test]
+	fn split_debug_info() {
+		let dir = build(&[(".debug_info", &[1, 2, 3])], false, true);
+		let out_dir = dir.path().join("out");
+
+		let mut data = Vec::new();
+		"test.debug.wasm".encode(&mut data);
+		assert_eq!(
+			custom_sections(&out_dir.join("test.wasm")),
+			[(String::from("external_debug_info"), data)]
+		);
+		assert_eq!(
+			custom_sections(&out_dir.join("test.debug.wasm")),
+			[(String::from(".debug_info"), vec![1, 2, 3])]
+		);
+	}
+
+	#[test]
+	fn source_map() {
+		let dir = build(&[], true, false);
+		let out_dir = dir.path().join("out");
+
+		let js = fs::read_to_string(out_dir.join("test.mjs")).unwrap();
+		assert!(js.ends_with("//# sourceMappingURL=test.mjs.map\n"));
+
+		// Resolved against the directory the module was linked in, not the current
+		// one.
+		let map = fs::read_to_string(out_dir.join("test.mjs.map")).unwrap();
+		assert!(map.contains("\"sources\":[\"/project/src/lib.rs\"]"));
+	}
+}
@@ -0,0 +1,26 @@
+mod build;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::build::Build;
+
+#[derive(Parser)]
+#[command(name = "js-bindgen", version, about, long_about = None)]
+struct Cli {
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Write the JS glue, its TypeScript declarations and a Wasm file ready for
+	/// deployment.
+	Build(Build),
+}
+
+fn main() -> Result<()> {
+	match Cli::parse().command {
+		Command::Build(build) => build.run(),
+	}
+}
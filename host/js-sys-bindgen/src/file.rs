@@ -6,14 +6,13 @@ use syn::{Error, File, Item, ItemMod, Meta, Path, Result, parse_quote};
 use crate::ImportManager;
 use crate::r#macro::{self, ErrorStack};
 
-pub fn file(input: &str, crate_: &str, js_sys: Option<Path>) -> Result<File> {
+pub fn file(input: &str, js_sys: Option<Path>) -> Result<File> {
 	let mut file: File = syn::parse_str(input)?;
 	let mut imports = ImportManager::new(js_sys);
 	let mut error = ErrorStack::new();
 	process_items(
 		mem::take(&mut file.items),
 		&mut file.items,
-		crate_,
 		&mut imports,
 		&mut error,
 	);
@@ -38,7 +37,6 @@ pub fn file(input: &str, crate_: &str, js_sys: Option<Path>) -> Result<File> {
 fn process_items(
 	items: Vec<Item>,
 	output: &mut Vec<Item>,
-	crate_: &str,
 	imports: &mut ImportManager,
 	error: &mut ErrorStack,
 ) {
@@ -64,7 +62,7 @@ fn process_items(
 						}
 					};
 
-					match r#macro::internal(attr, foreign_mod, Some(crate_), Some(imports)) {
+					match r#macro::internal(attr, foreign_mod, Some(imports)) {
 						Ok(mut items) => output.append(&mut items),
 						Err((_, e)) => {
 							error.push(e);
@@ -83,7 +81,7 @@ fn process_items(
 				},
 			) => {
 				let items = &mut r#mod.content.as_mut().unwrap().1;
-				process_items(mem::take(items), items, crate_, imports, error);
+				process_items(mem::take(items), items, imports, error);
 				output.push(r#mod.into());
 			}
 			item => error.push(Error::new_spanned(item, "item not supported")),
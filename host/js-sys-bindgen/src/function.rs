@@ -28,18 +28,18 @@ pub enum FunctionJsOutput {
 		property: bool,
 	},
 	Embed(String),
-	Import,
+	/// Imported from the given module instead of the namespace of the calling
+	/// crate, if any.
+	Import(Option<String>),
 }
 
 struct State<'a> {
-	crate_: &'a str,
 	namespace: Option<&'a str>,
 	js_bindgen: Path,
 	r#macro: Option<Path>,
 	input: Path,
 	output: Path,
 	import_name: String,
-	foreign_name: String,
 	input_tys: Vec<Type>,
 	output_ty: Vec<Type>,
 	extern_input_names: Vec<Ident>,
@@ -55,7 +55,7 @@ enum OutputType {
 		member: Option<Member>,
 	},
 	Embed(String),
-	Import,
+	Import(Option<String>),
 }
 
 struct Member {
@@ -74,7 +74,6 @@ impl Function {
 		hygiene: &mut Hygiene<'_>,
 		js_output: FunctionJsOutput,
 		namespace: Option<&str>,
-		crate_: &str,
 		item: ForeignItemFn,
 	) -> Result<Self> {
 		if let Some(constness) = item.sig.constness {
@@ -106,15 +105,14 @@ impl Function {
 			..
 		} = item;
 
-		let mut state = State::parse(
-			crate_, js_output, namespace, hygiene, &attrs, &mut sig, span,
-		)?;
+		let mut state = State::parse(js_output, namespace, hygiene, &attrs, &mut sig, span)?;
 		let wat = state.wat();
 		let js = state.js();
 		let State {
+			js_bindgen,
 			input,
 			output,
-			foreign_name,
+			import_name,
 			input_tys,
 			output_ty,
 			extern_input_names,
@@ -127,21 +125,25 @@ impl Function {
 
 		let mut foreign_call =
 			quote_spanned!(span=> unsafe { #ident(#(#input::into_raw(#intern_input_names)),*) });
-		if output_ty.is_empty() {
+		// Returned values record the caller of the binding as their creation site.
+		let track_caller = if output_ty.is_empty() {
 			foreign_call.extend(quote_spanned!(span=> ;));
+			None
 		} else {
 			foreign_call = quote_spanned! (span=> #output::from_raw(#foreign_call));
-		}
+			Some(quote_spanned!(span=> #[track_caller]))
+		};
 
 		let item_fn = parse_quote_spanned! {span=>
 			#(#attrs)*
+			#track_caller
 			#vis #sig {
 				#wat
 
 				#js
 
 				unsafe extern "C" {
-					#[link_name = #foreign_name]
+					#[link_name = #js_bindgen::namespace!(#import_name)]
 					fn #ident(#(#extern_input_names: <#input_tys as #input>::Type),*) #( -> <#output_ty as #output>::Type)*;
 				}
 
@@ -190,7 +192,6 @@ impl Default for FunctionJsOutput {
 
 impl<'a> State<'a> {
 	fn parse(
-		crate_: &'a str,
 		js_output: FunctionJsOutput,
 		namespace: Option<&'a str>,
 		hygiene: &'a mut Hygiene<'_>,
@@ -203,7 +204,6 @@ impl<'a> State<'a> {
 		} else {
 			sig.ident.to_string()
 		};
-		let foreign_name = format!("{crate_}.{import_name}");
 
 		let mut self_ty = None;
 
@@ -309,7 +309,7 @@ impl<'a> State<'a> {
 				OutputType::Generate { js_name, member }
 			}
 			FunctionJsOutput::Embed(embed) => OutputType::Embed(embed),
-			FunctionJsOutput::Import => OutputType::Import,
+			FunctionJsOutput::Import(module) => OutputType::Import(module),
 		};
 
 		let output_ty = match &sig.output {
@@ -345,14 +345,12 @@ impl<'a> State<'a> {
 		let output = hygiene.output(outer_attrs, span);
 
 		Ok(Self {
-			crate_,
 			namespace,
 			js_bindgen,
 			r#macro,
 			input,
 			output,
 			import_name,
-			foreign_name,
 			input_tys,
 			output_ty,
 			extern_input_names,
@@ -421,15 +419,14 @@ impl<'a> State<'a> {
 
 	fn wat(&self) -> Stmt {
 		let Self {
-			crate_,
 			js_bindgen,
 			r#macro,
 			input,
 			import_name,
-			foreign_name,
 			input_tys,
 			output_ty,
 			intern_input_names,
+			r#type,
 			span,
 			..
 		} = self;
@@ -479,12 +476,17 @@ impl<'a> State<'a> {
 			"{}"
 		};
 		let wat_ret_conv: String = iter::repeat_n("{}", output_ty.len()).collect();
+		let module = if let OutputType::Import(Some(module)) = r#type {
+			module
+		} else {
+			"{ns}"
+		};
 
 		let wat = TokenStream::from_str(&format!(
-			r#""(import \"{crate_}\" \"{import_name}\" (func ${crate_}.import.{import_name} (@sym (name \"{crate_}.import.{import_name}\")){import_params}{result})){import_funcs_placeholder}",
-            "(func ${foreign_name} (@sym){params}{result}",
+			r#""(import \"{module}\" \"{import_name}\" (func ${{ns}}.import.{import_name} (@sym (name \"{{ns}}.import.{import_name}\")){import_params}{result})){import_funcs_placeholder}",
+            "(func ${{ns}}.{import_name} (@sym){params}{result}",
             {wat_param_gets}
-            "  call ${crate_}.import.{import_name} (@reloc){wat_ret_conv}",
+            "  call ${{ns}}.import.{import_name} (@reloc){wat_ret_conv}",
             ")","#
 		))
 		.unwrap();
@@ -500,6 +502,7 @@ impl<'a> State<'a> {
 		parse_quote_spanned! {*span=>
 			#js_bindgen::unsafe_global_wat! {
 				#wat
+				ns = interpolate #js_bindgen::namespace!(),
 				#(interpolate #r#macro::wat_input_import_type::<#input_tys>(),)*
 				#(interpolate #r#macro::wat_output_import_type::<#output_ty>(),)*
 				#wat_imports
@@ -514,7 +517,6 @@ impl<'a> State<'a> {
 
 	fn js(&mut self) -> Option<Stmt> {
 		let Self {
-			crate_,
 			js_bindgen,
 			r#macro,
 			import_name,
@@ -544,10 +546,20 @@ impl<'a> State<'a> {
 					format!("{base}.{import_name}")
 				}
 			}
-			OutputType::Embed(name) => {
-				format!("this.#jsEmbed.{crate_}['{name}']")
+			// Prefixed with the namespace of the embed below.
+			OutputType::Embed(name) => format!("['{name}']"),
+			OutputType::Import(_) => return None,
+		};
+		// Embeds are placed into the namespace of the calling crate, which only
+		// `js_bindgen::namespace!()` knows.
+		let js_literal = |js: &str| {
+			if let OutputType::Embed(_) = r#type {
+				quote_spanned! {*span=>
+					::core::concat!("this.#jsEmbed.", #js_bindgen::namespace!(), #js)
+				}
+			} else {
+				quote_spanned!(*span=> #js)
 			}
-			OutputType::Import => return None,
 		};
 
 		let mut unique_inputs = Vec::new();
@@ -561,7 +573,7 @@ impl<'a> State<'a> {
 		let mut required_embeds = Vec::new();
 
 		if let OutputType::Embed(name) = &r#type {
-			required_embeds.push(quote_spanned!(*span=> (#crate_, #name)));
+			required_embeds.push(quote_spanned!(*span=> (#js_bindgen::namespace!(), #name)));
 		}
 
 		for ty in &unique_inputs {
@@ -579,12 +591,19 @@ impl<'a> State<'a> {
 		};
 
 		if input_tys.is_empty() && output_ty.is_empty() {
+			let js = if let OutputType::Embed(_) = r#type {
+				let js = format!("this.#jsEmbed.{{ns}}{js_path}");
+				quote_spanned!(*span=> #js, ns = interpolate #js_bindgen::namespace!())
+			} else {
+				quote_spanned!(*span=> #js_path)
+			};
+
 			return Some(parse_quote_spanned! {*span=>
 				#js_bindgen::import_js!(
-					module = #crate_,
+					module = #js_bindgen::namespace!(),
 					name = #import_name,
 					#(#required_embeds,)*
-					#js_path
+					#js
 				);
 			});
 		}
@@ -643,8 +662,11 @@ impl<'a> State<'a> {
 			(#(#unique_inputs),*) #(, #output_ty)*
 		};
 
+		let direct_js_call = js_literal(&direct_js_call);
+
 		let output = if output_ty.is_empty() {
 			first_output.to_mut().push_str("\n}");
+			let first_output = js_literal(&first_output);
 
 			quote_spanned! {*span=>
 				interpolate #r#macro::js_select!(#direct_js_call, #first_output, #direct_condition),
@@ -658,6 +680,8 @@ impl<'a> State<'a> {
 				start = first_output;
 			}
 
+			let indirect_js_call = js_literal(&indirect_js_call);
+
 			quote_spanned! {*span=>
 				interpolate #r#macro::js_output!(#start, #direct_js_call, #indirect_js_call, #(#output_ty,)*#(#unique_inputs),*),
 			}
@@ -665,7 +689,7 @@ impl<'a> State<'a> {
 
 		Some(parse_quote_spanned! {*span=>
 			#js_bindgen::import_js! {
-				module = #crate_,
+				module = #js_bindgen::namespace!(),
 				name = #import_name,
 				#(#required_embeds,)*
 				#placeholder,
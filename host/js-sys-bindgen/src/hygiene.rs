@@ -135,6 +135,30 @@ impl Hygiene<'_> {
 		}
 	}
 
+	pub(crate) fn deref(&mut self, attrs: &[Attribute], span: Span) -> Path {
+		match self {
+			Hygiene::Imports(imports) => {
+				imports.deref.get_or_insert_with(attrs, <[_]>::to_vec);
+				parse_quote_spanned!(span=> Deref)
+			}
+			Hygiene::Hygiene { .. } => {
+				parse_quote_spanned!(span=> ::core::ops::Deref)
+			}
+		}
+	}
+
+	pub(crate) fn fmt(&mut self, attrs: &[Attribute], span: Span) -> Path {
+		match self {
+			Hygiene::Imports(imports) => {
+				imports.fmt.get_or_insert_with(attrs, <[_]>::to_vec);
+				parse_quote_spanned!(span=> fmt)
+			}
+			Hygiene::Hygiene { .. } => {
+				parse_quote_spanned!(span=> ::core::fmt)
+			}
+		}
+	}
+
 	pub(crate) fn phantom_data(&mut self, attrs: &[Attribute], span: Span) -> Path {
 		match self {
 			Hygiene::Imports(imports) => {
@@ -198,6 +222,7 @@ type FixedHashSet<T> = HashSet<T, FixedState>;
 pub struct ImportManager {
 	js_sys: Path,
 	deref: FixedHashSet<Vec<Attribute>>,
+	fmt: FixedHashSet<Vec<Attribute>>,
 	phantom_data: FixedHashSet<Vec<Attribute>>,
 	js_sys_imports: FixedHashMap<Vec<Attribute>, FixedHashSet<Ident>>,
 	hazard_imports: FixedHashMap<Vec<Attribute>, FixedHashSet<Ident>>,
@@ -209,6 +234,7 @@ impl ImportManager {
 		Self {
 			js_sys: js_sys.unwrap_or_else(|| parse_quote! { js_sys }),
 			deref: FixedHashSet::default(),
+			fmt: FixedHashSet::default(),
 			phantom_data: FixedHashSet::default(),
 			js_sys_imports: FixedHashMap::default(),
 			hazard_imports: FixedHashMap::default(),
@@ -230,6 +256,12 @@ impl ImportManager {
 					use core::ops::Deref;
 				}
 			}))
+			.chain(self.fmt.iter().map(|attr| {
+				parse_quote! {
+					#(#attr)*
+					use core::fmt;
+				}
+			}))
 			.chain(self.js_sys_imports.iter().filter_map(|(attrs, types)| {
 				let js_sys = &self.js_sys;
 				let types = types.iter();
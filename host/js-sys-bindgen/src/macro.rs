@@ -1,9 +1,7 @@
-use std::env;
-
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::parse::Parser;
-use syn::{Error, ForeignItem, Item, ItemForeignMod, LitStr, Path, meta};
+use syn::{Error, ForeignItem, Item, ItemForeignMod, LitStr, Path, Token, meta};
 
 use crate::{Function, FunctionJsOutput, Hygiene, ImportManager, Type};
 
@@ -14,7 +12,7 @@ pub fn r#macro(
 ) -> Result<TokenStream, TokenStream> {
 	let foreign_mod: ItemForeignMod = syn::parse2(item).map_err(Error::into_compile_error)?;
 
-	internal(attr, foreign_mod, None, imports)
+	internal(attr, foreign_mod, imports)
 		.map(|items| items.into_iter().map(Item::into_token_stream).collect())
 		.map_err(|(output, error)| {
 			let error = error.into_compile_error();
@@ -33,7 +31,6 @@ pub fn r#macro(
 pub(crate) fn internal(
 	attr: TokenStream,
 	mut foreign_mod: ItemForeignMod,
-	crate_: Option<&str>,
 	imports: Option<&mut ImportManager>,
 ) -> Result<Vec<Item>, (Option<Vec<Item>>, Error)> {
 	let mut error = ErrorStack::new();
@@ -118,12 +115,29 @@ pub(crate) fn internal(
 							*js_name = Some(meta.value()?.parse::<LitStr>()?.value());
 							Ok(())
 						} else if meta.path.is_ident("js_import") {
-							if meta.input.is_empty() {
-								js_output = FunctionJsOutput::Import;
-								Ok(())
+							let module = if meta.input.peek(Token![=]) {
+								let module = meta.value()?.parse::<LitStr>()?;
+
+								if module.value().is_empty()
+									|| !module
+										.value()
+										.chars()
+										.all(|c| c.is_ascii_alphanumeric() || c == '_')
+								{
+									return Err(Error::new_spanned(
+										module,
+										"`js_import` module must only consist of ASCII \
+										 alphanumerics and `_`",
+									));
+								}
+
+								Some(module.value())
 							} else {
-								Err(meta.error("`js_import` supports no values"))
-							}
+								None
+							};
+
+							js_output = FunctionJsOutput::Import(module);
+							Ok(())
 						} else if meta.path.is_ident("js_embed") {
 							js_output =
 								FunctionJsOutput::Embed(meta.value()?.parse::<LitStr>()?.value());
@@ -143,27 +157,31 @@ pub(crate) fn internal(
 					}
 				}
 
-				let crate_ = if let Some(crate_) = crate_ {
-					crate_
-				} else {
-					&env::var("CARGO_CRATE_NAME").expect("`CARGO_CRATE_NAME` not found")
-				};
-
-				match Function::new(&mut hygiene, js_output, namespace.as_deref(), crate_, item) {
+				match Function::new(&mut hygiene, js_output, namespace.as_deref(), item) {
 					Ok(function) => output.push(function.into()),
 					Err(e) => error.push(e),
 				}
 			}
 			ForeignItem::Type(mut item) => {
-				if let Some(attr) = item
+				let mut extends = Vec::new();
+
+				for attr in item
 					.attrs
 					.extract_if(.., |attr| attr.path().is_ident("js_sys"))
-					.next()
 				{
-					error.push(Error::new_spanned(attr, "unsupported attribute"));
+					if let Err(e) = attr.parse_nested_meta(|meta| {
+						if meta.path.is_ident("extends") {
+							extends.push(meta.value()?.parse::<Path>()?);
+							Ok(())
+						} else {
+							Err(meta.error("unsupported attribute"))
+						}
+					}) {
+						error.push(e);
+					}
 				}
 
-				output.extend(Type::new(&mut hygiene, item));
+				output.extend(Type::new(&mut hygiene, item, &extends));
 			}
 			item => {
 				error.push(Error::new_spanned(
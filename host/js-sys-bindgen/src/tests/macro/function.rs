@@ -10,16 +10,17 @@ fn basic() {
 		{
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \"test_crate.import.log\")) (param {}))){}",
-					"(func $test_crate.log (@sym) (param $data {})", "  local.get $data{}",
-					"  call $test_crate.import.log (@reloc)", ")",
+					"(import \"{ns}\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\")) (param {}))){}",
+					"(func ${ns}.log (@sym) (param $data {})", "  local.get $data{}",
+					"  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 					interpolate::js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
 					::js_sys::hazard::Input > ::WAT_TYPE, interpolate::js_sys::r#macro::wat_input!(& JsValue),
 				}
 
 				::js_sys::js_bindgen::import_js! {
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "log",
 					required_embeds = [::js_sys::r#macro::js_input_embed::<&JsValue>()],
 					"{}{}{}",
@@ -28,26 +29,26 @@ fn basic() {
 					interpolate ::js_sys::r#macro::js_select!(
 						"globalThis.log",
 						"globalThis.log(data)\n}",
-						(&JsValue),
+						(&JsValue)
 					),
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.log"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("log")]
 					fn log(data: <&JsValue as ::js_sys::hazard::Input>::Type);
 				}
 
 				unsafe { log(::js_sys::hazard::Input::into_raw(data)) };
 			}
 		},
-		"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \
-		 \"test_crate.import.log\")) (param externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.log (@sym) (param $data i32)
+		"(import \"test_crate_0_0_0\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.log (@sym) (param $data i32)
 		  local.get $data
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.log (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.log (@reloc)
 		)",
 		"globalThis.log",
 	);
@@ -65,16 +66,17 @@ fn namespace() {
 		{
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"console.log\" (func $test_crate.import.console.log (@sym (name \"test_crate.import.console.log\")) (param {}))){}",
-					"(func $test_crate.console.log (@sym) (param $data {})", "  local.get $data{}",
-					"  call $test_crate.import.console.log (@reloc)", ")",
+					"(import \"{ns}\" \"console.log\" (func ${ns}.import.console.log (@sym (name \"{ns}.import.console.log\")) (param {}))){}",
+					"(func ${ns}.console.log (@sym) (param $data {})", "  local.get $data{}",
+					"  call ${ns}.import.console.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 					interpolate::js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
 					::js_sys::hazard::Input > ::WAT_TYPE, interpolate::js_sys::r#macro::wat_input!(& JsValue),
 				}
 
 				::js_sys::js_bindgen::import_js! {
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "console.log",
 					required_embeds = [::js_sys::r#macro::js_input_embed::<&JsValue>()],
 					"{}{}{}",
@@ -83,26 +85,26 @@ fn namespace() {
 					interpolate ::js_sys::r#macro::js_select!(
 						"globalThis.console.log",
 						"globalThis.console.log(data)\n}",
-						(&JsValue),
+						(&JsValue)
 					),
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.console.log"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("console.log")]
 					fn log(data: <&JsValue as ::js_sys::hazard::Input>::Type);
 				}
 
 				unsafe { log(::js_sys::hazard::Input::into_raw(data)) };
 			}
 		},
-		"(import \"test_crate\" \"console.log\" (func $test_crate.import.console.log (@sym (name \
-		 \"test_crate.import.console.log\")) (param externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.console.log (@sym) (param $data i32)
+		"(import \"test_crate_0_0_0\" \"console.log\" (func $test_crate_0_0_0.import.console.log \
+		 (@sym (name \"test_crate_0_0_0.import.console.log\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.console.log (@sym) (param $data i32)
 		  local.get $data
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.console.log (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.console.log (@reloc)
 		)",
 		"globalThis.console.log",
 	);
@@ -120,16 +122,17 @@ fn js_sys() {
 		{
 			pub fn log(data: &JsValue) {
 				js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \"test_crate.import.log\")) (param {}))){}",
-					"(func $test_crate.log (@sym) (param $data {})", "  local.get $data{}",
-					"  call $test_crate.import.log (@reloc)", ")", interpolate
+					"(import \"{ns}\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\")) (param {}))){}",
+					"(func ${ns}.log (@sym) (param $data {})", "  local.get $data{}",
+					"  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate js_sys::js_bindgen::namespace!(), interpolate
 					js_sys::r#macro::wat_input_import_type:: < & JsValue > (), interpolate
 					js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
 					js_sys::hazard::Input > ::WAT_TYPE, interpolate js_sys::r#macro::wat_input!(& JsValue),
 				}
 
 				js_sys::js_bindgen::import_js! {
-					module = "test_crate",
+					module = js_sys::js_bindgen::namespace!(),
 					name = "log",
 					required_embeds = [js_sys::r#macro::js_input_embed::<&JsValue>()],
 					"{}{}{}",
@@ -138,26 +141,26 @@ fn js_sys() {
 					interpolate js_sys::r#macro::js_select!(
 						"globalThis.log",
 						"globalThis.log(data)\n}",
-						(&JsValue),
+						(&JsValue)
 					),
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.log"]
+					#[link_name = js_sys::js_bindgen::namespace!("log")]
 					fn log(data: <&JsValue as js_sys::hazard::Input>::Type);
 				}
 
 				unsafe { log(js_sys::hazard::Input::into_raw(data)) };
 			}
 		},
-		"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \
-		 \"test_crate.import.log\")) (param externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.log (@sym) (param $data i32)
+		"(import \"test_crate_0_0_0\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.log (@sym) (param $data i32)
 		  local.get $data
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.log (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.log (@reloc)
 		)",
 		"globalThis.log",
 	);
@@ -175,9 +178,10 @@ fn two_parameters() {
 		{
 			pub fn log(data1: &JsValue, data2: &JsValue) {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \"test_crate.import.log\")) (param {} {}))){}",
-					"(func $test_crate.log (@sym) (param $data1 {}) (param $data2 {})", "  local.get $data1{}",
-					"  local.get $data2{}", "  call $test_crate.import.log (@reloc)", ")",
+					"(import \"{ns}\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\")) (param {} {}))){}",
+					"(func ${ns}.log (@sym) (param $data1 {}) (param $data2 {})", "  local.get $data1{}",
+					"  local.get $data2{}", "  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 					interpolate::js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
@@ -187,7 +191,7 @@ fn two_parameters() {
 				}
 
 				::js_sys::js_bindgen::import_js! {
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "log",
 					required_embeds = [::js_sys::r#macro::js_input_embed::<&JsValue>()],
 					"{}{}{}{}",
@@ -197,12 +201,12 @@ fn two_parameters() {
 					interpolate ::js_sys::r#macro::js_select!(
 						"globalThis.log",
 						"globalThis.log(data1, data2)\n}",
-						(&JsValue),
+						(&JsValue)
 					),
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.log"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("log")]
 					fn log(
 						data1: <&JsValue as ::js_sys::hazard::Input>::Type,
 						data2: <&JsValue as ::js_sys::hazard::Input>::Type,
@@ -217,16 +221,16 @@ fn two_parameters() {
 				};
 			}
 		},
-		"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \
-		 \"test_crate.import.log\")) (param externref externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.log (@sym) (param $data1 i32) (param $data2 i32)
+		"(import \"test_crate_0_0_0\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\")) (param externref externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.log (@sym) (param $data1 i32) (param $data2 i32)
 		  local.get $data1
-		  call $js_sys.externref.get (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
 		  local.get $data2
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.log (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.log (@reloc)
 		)",
 		"globalThis.log",
 	);
@@ -244,28 +248,29 @@ fn empty() {
 		{
 			pub fn log() {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \"test_crate.import.log\"))))",
-					"(func $test_crate.log (@sym)", "  call $test_crate.import.log (@reloc)", ")",
+					"(import \"{ns}\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\"))))",
+					"(func ${ns}.log (@sym)", "  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 				}
 
 				::js_sys::js_bindgen::import_js!(
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "log",
 					"globalThis.log"
 				);
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.log"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("log")]
 					fn log();
 				}
 
 				unsafe { log() };
 			}
 		},
-		"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \
-		 \"test_crate.import.log\"))))
-		(func $test_crate.log (@sym)
-		  call $test_crate.import.log (@reloc)
+		"(import \"test_crate_0_0_0\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\"))))
+		(func $test_crate_0_0_0.log (@sym)
+		  call $test_crate_0_0_0.import.log (@reloc)
 		)",
 		"globalThis.log",
 	);
@@ -284,16 +289,17 @@ fn js_name() {
 		{
 			pub fn logx(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"logx\" (func $test_crate.import.logx (@sym (name \"test_crate.import.logx\")) (param {}))){}",
-					"(func $test_crate.logx (@sym) (param $data {})", "  local.get $data{}",
-					"  call $test_crate.import.logx (@reloc)", ")",
+					"(import \"{ns}\" \"logx\" (func ${ns}.import.logx (@sym (name \"{ns}.import.logx\")) (param {}))){}",
+					"(func ${ns}.logx (@sym) (param $data {})", "  local.get $data{}",
+					"  call ${ns}.import.logx (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 					interpolate::js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
 					::js_sys::hazard::Input > ::WAT_TYPE, interpolate::js_sys::r#macro::wat_input!(& JsValue),
 				}
 
 				::js_sys::js_bindgen::import_js! {
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "logx",
 					required_embeds = [::js_sys::r#macro::js_input_embed::<&JsValue>()],
 					"{}{}{}",
@@ -302,26 +308,26 @@ fn js_name() {
 					interpolate ::js_sys::r#macro::js_select!(
 						"globalThis.log",
 						"globalThis.log(data)\n}",
-						(&JsValue),
+						(&JsValue)
 					),
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.logx"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("logx")]
 					fn logx(data: <&JsValue as ::js_sys::hazard::Input>::Type);
 				}
 
 				unsafe { logx(::js_sys::hazard::Input::into_raw(data)) };
 			}
 		},
-		"(import \"test_crate\" \"logx\" (func $test_crate.import.logx (@sym (name \
-		 \"test_crate.import.logx\")) (param externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.logx (@sym) (param $data i32)
+		"(import \"test_crate_0_0_0\" \"logx\" (func $test_crate_0_0_0.import.logx (@sym (name \
+		 \"test_crate_0_0_0.import.logx\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.logx (@sym) (param $data i32)
 		  local.get $data
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.logx (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.logx (@reloc)
 		)",
 		"globalThis.log",
 	);
@@ -340,30 +346,74 @@ fn js_import() {
 		{
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \"test_crate.import.log\")) (param {}))){}",
-					"(func $test_crate.log (@sym) (param $data {})", "  local.get $data{}",
-					"  call $test_crate.import.log (@reloc)", ")",
+					"(import \"{ns}\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\")) (param {}))){}",
+					"(func ${ns}.log (@sym) (param $data {})", "  local.get $data{}",
+					"  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 					interpolate::js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
 					::js_sys::hazard::Input > ::WAT_TYPE, interpolate::js_sys::r#macro::wat_input!(& JsValue),
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.log"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("log")]
 					fn log(data: <&JsValue as ::js_sys::hazard::Input>::Type);
 				}
 
 				unsafe { log(::js_sys::hazard::Input::into_raw(data)) };
 			}
 		},
-		"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \
-		 \"test_crate.import.log\")) (param externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.log (@sym) (param $data i32)
+		"(import \"test_crate_0_0_0\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.log (@sym) (param $data i32)
 		  local.get $data
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.log (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.log (@reloc)
+		)",
+		None,
+	);
+}
+
+#[test]
+fn js_import_module() {
+	test!(
+		{},
+		{
+			extern "js-sys" {
+				#[js_sys(js_import = "test_module")]
+				pub fn log(data: &JsValue);
+			}
+		},
+		{
+			pub fn log(data: &JsValue) {
+				::js_sys::js_bindgen::unsafe_global_wat! {
+					"(import \"test_module\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\")) (param {}))){}",
+					"(func ${ns}.log (@sym) (param $data {})", "  local.get $data{}",
+					"  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
+					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
+					interpolate::js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
+					::js_sys::hazard::Input > ::WAT_TYPE, interpolate::js_sys::r#macro::wat_input!(& JsValue),
+				}
+
+				unsafe extern "C" {
+					#[link_name = ::js_sys::js_bindgen::namespace!("log")]
+					fn log(data: <&JsValue as ::js_sys::hazard::Input>::Type);
+				}
+
+				unsafe { log(::js_sys::hazard::Input::into_raw(data)) };
+			}
+		},
+		"(import \"test_module\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.log (@sym) (param $data i32)
+		  local.get $data
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.log (@reloc)
 		)",
 		None,
 	);
@@ -382,49 +432,50 @@ fn js_embed() {
 		{
 			pub fn log(data: &JsValue) {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \"test_crate.import.log\")) (param {}))){}",
-					"(func $test_crate.log (@sym) (param $data {})", "  local.get $data{}",
-					"  call $test_crate.import.log (@reloc)", ")",
+					"(import \"{ns}\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\")) (param {}))){}",
+					"(func ${ns}.log (@sym) (param $data {})", "  local.get $data{}",
+					"  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 					interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 					interpolate::js_sys::r#macro::wat_imports!((& JsValue),), interpolate < & JsValue as
 					::js_sys::hazard::Input > ::WAT_TYPE, interpolate::js_sys::r#macro::wat_input!(& JsValue),
 				}
 
 				::js_sys::js_bindgen::import_js! {
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "log",
 					required_embeds = [
-						("test_crate", "embed"),
-						::js_sys::r#macro::js_input_embed::<&JsValue>(),
+						(::js_sys::js_bindgen::namespace!(), "embed"),
+						::js_sys::r#macro::js_input_embed::<&JsValue>()
 					],
 					"{}{}{}",
 					interpolate ::js_sys::r#macro::js_select!("", "(data) => {\n", (&JsValue)),
 					interpolate ::js_sys::r#macro::js_parameter!("data", &JsValue),
 					interpolate ::js_sys::r#macro::js_select!(
-						"this.#jsEmbed.test_crate['embed']",
-						"this.#jsEmbed.test_crate['embed'](data)\n}",
-						(&JsValue),
+						::core::concat!("this.#jsEmbed.", ::js_sys::js_bindgen::namespace!(), "['embed']"),
+						::core::concat!("this.#jsEmbed.", ::js_sys::js_bindgen::namespace!(), "['embed'](data)\n}"),
+						(&JsValue)
 					),
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.log"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("log")]
 					fn log(data: <&JsValue as ::js_sys::hazard::Input>::Type);
 				}
 
 				unsafe { log(::js_sys::hazard::Input::into_raw(data)) };
 			}
 		},
-		"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \
-		 \"test_crate.import.log\")) (param externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.log (@sym) (param $data i32)
+		"(import \"test_crate_0_0_0\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.log (@sym) (param $data i32)
 		  local.get $data
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.log (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.log (@reloc)
 		)",
-		"this.#jsEmbed.test_crate['embed']",
+		"this.#jsEmbed.test_crate_0_0_0['embed']",
 	);
 }
 
@@ -438,11 +489,13 @@ fn r#return() {
 			}
 		},
 		{
+			#[track_caller]
 			pub fn is_nan() -> JsValue {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"is_nan\" (func $test_crate.import.is_nan (@sym (name \"test_crate.import.is_nan\")) (result {}))){}",
-					"(func $test_crate.is_nan (@sym) (param {}) (result {})",
-					"  call $test_crate.import.is_nan (@reloc){}", ")",
+					"(import \"{ns}\" \"is_nan\" (func ${ns}.import.is_nan (@sym (name \"{ns}.import.is_nan\")) (result {}))){}",
+					"(func ${ns}.is_nan (@sym) (param {}) (result {})",
+					"  call ${ns}.import.is_nan (@reloc){}", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 					interpolate::js_sys::r#macro::wat_output_import_type:: < JsValue > (),
 					interpolate::js_sys::r#macro::wat_imports!((), JsValue),
 					interpolate::js_sys::r#macro::wat_indirect!(JsValue),
@@ -451,7 +504,7 @@ fn r#return() {
 				}
 
 				::js_sys::js_bindgen::import_js! {
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "is_nan",
 					required_embeds = [::js_sys::r#macro::js_output_embed::<JsValue>()],
 					"{}{}",
@@ -465,20 +518,20 @@ fn r#return() {
 				}
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.is_nan"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("is_nan")]
 					fn is_nan() -> <JsValue as ::js_sys::hazard::Output>::Type;
 				}
 
 				::js_sys::hazard::Output::from_raw(unsafe { is_nan() })
 			}
 		},
-		"(import \"test_crate\" \"is_nan\" (func $test_crate.import.is_nan (@sym (name \
-		 \"test_crate.import.is_nan\")) (result externref)))
-		(import \"env\" \"js_sys.externref.insert\" (func $js_sys.externref.insert (@sym) (param \
-		 externref) (result i32)))
-		(func $test_crate.is_nan (@sym) (param ) (result i32)
-		  call $test_crate.import.is_nan (@reloc)
-		  call $js_sys.externref.insert (@reloc)
+		"(import \"test_crate_0_0_0\" \"is_nan\" (func $test_crate_0_0_0.import.is_nan (@sym \
+		 (name \"test_crate_0_0_0.import.is_nan\")) (result externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.insert\" (func $js_sys_0_0_0.externref.insert (@sym) \
+		 (param externref) (result i32)))
+		(func $test_crate_0_0_0.is_nan (@sym) (param ) (result i32)
+		  call $test_crate_0_0_0.import.is_nan (@reloc)
+		  call $js_sys_0_0_0.externref.insert (@reloc)
 		)",
 		"globalThis.is_nan",
 	);
@@ -498,28 +551,29 @@ fn cfg() {
 			#[cfg(all())]
 			pub fn log() {
 				::js_sys::js_bindgen::unsafe_global_wat! {
-					"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \"test_crate.import.log\"))))",
-					"(func $test_crate.log (@sym)", "  call $test_crate.import.log (@reloc)", ")",
+					"(import \"{ns}\" \"log\" (func ${ns}.import.log (@sym (name \"{ns}.import.log\"))))",
+					"(func ${ns}.log (@sym)", "  call ${ns}.import.log (@reloc)", ")",
+					ns = interpolate ::js_sys::js_bindgen::namespace!(),
 				}
 
 				::js_sys::js_bindgen::import_js!(
-					module = "test_crate",
+					module = ::js_sys::js_bindgen::namespace!(),
 					name = "log",
 					"globalThis.log"
 				);
 
 				unsafe extern "C" {
-					#[link_name = "test_crate.log"]
+					#[link_name = ::js_sys::js_bindgen::namespace!("log")]
 					fn log();
 				}
 
 				unsafe { log() };
 			}
 		},
-		"(import \"test_crate\" \"log\" (func $test_crate.import.log (@sym (name \
-		 \"test_crate.import.log\"))))
-		(func $test_crate.log (@sym)
-		  call $test_crate.import.log (@reloc)
+		"(import \"test_crate_0_0_0\" \"log\" (func $test_crate_0_0_0.import.log (@sym (name \
+		 \"test_crate_0_0_0.import.log\"))))
+		(func $test_crate_0_0_0.log (@sym)
+		  call $test_crate_0_0_0.import.log (@reloc)
 		)",
 		"globalThis.log",
 	);
@@ -11,9 +11,10 @@ fn method() {
 			impl JsTest {
 				pub fn test(self: &JsTest) {
 					::js_sys::js_bindgen::unsafe_global_wat! {
-						"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \"test_crate.import.test\")) (param {}))){}",
-						"(func $test_crate.test (@sym) (param $self {})", "  local.get $self{}",
-						"  call $test_crate.import.test (@reloc)", ")",
+						"(import \"{ns}\" \"test\" (func ${ns}.import.test (@sym (name \"{ns}.import.test\")) (param {}))){}",
+						"(func ${ns}.test (@sym) (param $self {})", "  local.get $self{}",
+						"  call ${ns}.import.test (@reloc)", ")",
+						ns = interpolate ::js_sys::js_bindgen::namespace!(),
 						interpolate::js_sys::r#macro::wat_input_import_type:: < & ::js_sys::JsValue > (),
 						interpolate::js_sys::r#macro::wat_imports!((& ::js_sys::JsValue),), interpolate < &
 						::js_sys::JsValue as ::js_sys::hazard::Input > ::WAT_TYPE,
@@ -21,25 +22,25 @@ fn method() {
 					}
 
 					::js_sys::js_bindgen::import_js! {
-						module = "test_crate",
+						module = ::js_sys::js_bindgen::namespace!(),
 						name = "test",
 						required_embeds = [::js_sys::r#macro::js_input_embed::<&::js_sys::JsValue>()],
 						"{}{}{}",
 						interpolate ::js_sys::r#macro::js_select!(
 							"(self) => ",
 							"(self) => {\n",
-							(&::js_sys::JsValue),
+							(&::js_sys::JsValue)
 						),
 						interpolate ::js_sys::r#macro::js_parameter!("self", &::js_sys::JsValue),
 						interpolate ::js_sys::r#macro::js_select!(
 							"self.test()",
 							"self.test()\n}",
-							(&::js_sys::JsValue),
+							(&::js_sys::JsValue)
 						),
 					}
 
 					unsafe extern "C" {
-						#[link_name = "test_crate.test"]
+						#[link_name = ::js_sys::js_bindgen::namespace!("test")]
 						fn test(this: <&::js_sys::JsValue as ::js_sys::hazard::Input>::Type);
 					}
 
@@ -47,14 +48,14 @@ fn method() {
 				}
 			}
 		},
-		"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \
-		 \"test_crate.import.test\")) (param externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.test (@sym) (param $self i32)
+		"(import \"test_crate_0_0_0\" \"test\" (func $test_crate_0_0_0.import.test (@sym (name \
+		 \"test_crate_0_0_0.import.test\")) (param externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.test (@sym) (param $self i32)
 		  local.get $self
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.test (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.test (@reloc)
 		)",
 		"(self) => self.test()",
 	);
@@ -73,10 +74,11 @@ fn method_par() {
 			impl JsTest {
 				pub fn test(self: &JsTest, par1: &JsValue, par2: &JsValue) {
 					::js_sys::js_bindgen::unsafe_global_wat! {
-						"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \"test_crate.import.test\")) (param {} {} {}))){}",
-						"(func $test_crate.test (@sym) (param $self {}) (param $par1 {}) (param $par2 {})",
+						"(import \"{ns}\" \"test\" (func ${ns}.import.test (@sym (name \"{ns}.import.test\")) (param {} {} {}))){}",
+						"(func ${ns}.test (@sym) (param $self {}) (param $par1 {}) (param $par2 {})",
 						"  local.get $self{}", "  local.get $par1{}", "  local.get $par2{}",
-						"  call $test_crate.import.test (@reloc)", ")",
+						"  call ${ns}.import.test (@reloc)", ")",
+						ns = interpolate ::js_sys::js_bindgen::namespace!(),
 						interpolate::js_sys::r#macro::wat_input_import_type:: < & ::js_sys::JsValue > (),
 						interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 						interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
@@ -89,17 +91,17 @@ fn method_par() {
 					}
 
 					::js_sys::js_bindgen::import_js! {
-						module = "test_crate",
+						module = ::js_sys::js_bindgen::namespace!(),
 						name = "test",
 						required_embeds = [
 							::js_sys::r#macro::js_input_embed::<&::js_sys::JsValue>(),
-							::js_sys::r#macro::js_input_embed::<&JsValue>(),
+							::js_sys::r#macro::js_input_embed::<&JsValue>()
 						],
 						"{}{}{}{}{}",
 						interpolate ::js_sys::r#macro::js_select!(
 							"(self, par1, par2) => ",
 							"(self, par1, par2) => {\n",
-							(&::js_sys::JsValue, &JsValue),
+							(&::js_sys::JsValue, &JsValue)
 						),
 						interpolate ::js_sys::r#macro::js_parameter!("self", &::js_sys::JsValue),
 						interpolate ::js_sys::r#macro::js_parameter!("par1", &JsValue),
@@ -107,12 +109,12 @@ fn method_par() {
 						interpolate ::js_sys::r#macro::js_select!(
 							"self.test(par1, par2)",
 							"self.test(par1, par2)\n}",
-							(&::js_sys::JsValue, &JsValue),
+							(&::js_sys::JsValue, &JsValue)
 						),
 					}
 
 					unsafe extern "C" {
-						#[link_name = "test_crate.test"]
+						#[link_name = ::js_sys::js_bindgen::namespace!("test")]
 						fn test(
 							this: <&::js_sys::JsValue as ::js_sys::hazard::Input>::Type,
 							par1: <&JsValue as ::js_sys::hazard::Input>::Type,
@@ -130,18 +132,18 @@ fn method_par() {
 				}
 			}
 		},
-		"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \
-		 \"test_crate.import.test\")) (param externref externref externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.test (@sym) (param $self i32) (param $par1 i32) (param $par2 i32)
+		"(import \"test_crate_0_0_0\" \"test\" (func $test_crate_0_0_0.import.test (@sym (name \
+		 \"test_crate_0_0_0.import.test\")) (param externref externref externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.test (@sym) (param $self i32) (param $par1 i32) (param $par2 i32)
 		  local.get $self
-		  call $js_sys.externref.get (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
 		  local.get $par1
-		  call $js_sys.externref.get (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
 		  local.get $par2
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.test (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.test (@reloc)
 		)",
 		"(self, par1, par2) => self.test(par1, par2)",
 	);
@@ -159,11 +161,13 @@ fn getter() {
 		},
 		{
 			impl JsTest {
+				#[track_caller]
 				pub fn test(self: &JsTest) -> JsValue {
 					::js_sys::js_bindgen::unsafe_global_wat! {
-						"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \"test_crate.import.test\")) (param {}) (result {}))){}",
-						"(func $test_crate.test (@sym) (param {}) (param $self {}) (result {})",
-						"  local.get $self{}", "  call $test_crate.import.test (@reloc){}", ")",
+						"(import \"{ns}\" \"test\" (func ${ns}.import.test (@sym (name \"{ns}.import.test\")) (param {}) (result {}))){}",
+						"(func ${ns}.test (@sym) (param {}) (param $self {}) (result {})",
+						"  local.get $self{}", "  call ${ns}.import.test (@reloc){}", ")",
+						ns = interpolate ::js_sys::js_bindgen::namespace!(),
 						interpolate::js_sys::r#macro::wat_input_import_type:: < & ::js_sys::JsValue > (),
 						interpolate::js_sys::r#macro::wat_output_import_type:: < JsValue > (),
 						interpolate::js_sys::r#macro::wat_imports!((& ::js_sys::JsValue), JsValue),
@@ -174,18 +178,18 @@ fn getter() {
 					}
 
 					::js_sys::js_bindgen::import_js! {
-						module = "test_crate",
+						module = ::js_sys::js_bindgen::namespace!(),
 						name = "test",
 						required_embeds = [
 							::js_sys::r#macro::js_input_embed::<&::js_sys::JsValue>(),
-							::js_sys::r#macro::js_output_embed::<JsValue>(),
+							::js_sys::r#macro::js_output_embed::<JsValue>()
 						],
 						"{}{}{}",
 						interpolate ::js_sys::r#macro::js_select!(
 							"(self) => ",
 							"(self) => {\n",
 							(&::js_sys::JsValue),
-							JsValue,
+							JsValue
 						),
 						interpolate ::js_sys::r#macro::js_parameter!("self", &::js_sys::JsValue),
 						interpolate ::js_sys::r#macro::js_output!(
@@ -193,12 +197,12 @@ fn getter() {
 							"self.test",
 							"self.test",
 							JsValue,
-							&::js_sys::JsValue,
+							&::js_sys::JsValue
 						),
 					}
 
 					unsafe extern "C" {
-						#[link_name = "test_crate.test"]
+						#[link_name = ::js_sys::js_bindgen::namespace!("test")]
 						fn test(
 							this: <&::js_sys::JsValue as ::js_sys::hazard::Input>::Type,
 						) -> <JsValue as ::js_sys::hazard::Output>::Type;
@@ -210,17 +214,17 @@ fn getter() {
 				}
 			}
 		},
-		"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \
-		 \"test_crate.import.test\")) (param externref) (result externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(import \"env\" \"js_sys.externref.insert\" (func $js_sys.externref.insert (@sym) (param \
-		 externref) (result i32)))
-		(func $test_crate.test (@sym) (param ) (param $self i32) (result i32)
+		"(import \"test_crate_0_0_0\" \"test\" (func $test_crate_0_0_0.import.test (@sym (name \
+		 \"test_crate_0_0_0.import.test\")) (param externref) (result externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.insert\" (func $js_sys_0_0_0.externref.insert (@sym) \
+		 (param externref) (result i32)))
+		(func $test_crate_0_0_0.test (@sym) (param ) (param $self i32) (result i32)
 		  local.get $self
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.test (@reloc)
-		  call $js_sys.externref.insert (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.test (@reloc)
+		  call $js_sys_0_0_0.externref.insert (@reloc)
 		)",
 		"(self) => self.test",
 	);
@@ -240,10 +244,11 @@ fn setter() {
 			impl JsTest {
 				pub fn test(self: &JsTest, value: &JsValue) {
 					::js_sys::js_bindgen::unsafe_global_wat! {
-						"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \"test_crate.import.test\")) (param {} {}))){}",
-						"(func $test_crate.test (@sym) (param $self {}) (param $value {})",
+						"(import \"{ns}\" \"test\" (func ${ns}.import.test (@sym (name \"{ns}.import.test\")) (param {} {}))){}",
+						"(func ${ns}.test (@sym) (param $self {}) (param $value {})",
 						"  local.get $self{}", "  local.get $value{}",
-						"  call $test_crate.import.test (@reloc)", ")",
+						"  call ${ns}.import.test (@reloc)", ")",
+						ns = interpolate ::js_sys::js_bindgen::namespace!(),
 						interpolate::js_sys::r#macro::wat_input_import_type:: < & ::js_sys::JsValue > (),
 						interpolate::js_sys::r#macro::wat_input_import_type:: < & JsValue > (),
 						interpolate::js_sys::r#macro::wat_imports!((& ::js_sys::JsValue, & JsValue),),
@@ -254,29 +259,29 @@ fn setter() {
 					}
 
 					::js_sys::js_bindgen::import_js! {
-						module = "test_crate",
+						module = ::js_sys::js_bindgen::namespace!(),
 						name = "test",
 						required_embeds = [
 							::js_sys::r#macro::js_input_embed::<&::js_sys::JsValue>(),
-							::js_sys::r#macro::js_input_embed::<&JsValue>(),
+							::js_sys::r#macro::js_input_embed::<&JsValue>()
 						],
 						"{}{}{}self.test = {}",
 						interpolate ::js_sys::r#macro::js_select!(
 							"(self, value) => ",
 							"(self, value) => {\n",
-							(&::js_sys::JsValue, &JsValue),
+							(&::js_sys::JsValue, &JsValue)
 						),
 						interpolate ::js_sys::r#macro::js_parameter!("self", &::js_sys::JsValue),
 						interpolate ::js_sys::r#macro::js_parameter!("value", &JsValue),
 						interpolate ::js_sys::r#macro::js_select!(
 							"value",
 							"value\n}",
-							(&::js_sys::JsValue, &JsValue),
+							(&::js_sys::JsValue, &JsValue)
 						),
 					}
 
 					unsafe extern "C" {
-						#[link_name = "test_crate.test"]
+						#[link_name = ::js_sys::js_bindgen::namespace!("test")]
 						fn test(
 							this: <&::js_sys::JsValue as ::js_sys::hazard::Input>::Type,
 							value: <&JsValue as ::js_sys::hazard::Input>::Type,
@@ -292,16 +297,16 @@ fn setter() {
 				}
 			}
 		},
-		"(import \"test_crate\" \"test\" (func $test_crate.import.test (@sym (name \
-		 \"test_crate.import.test\")) (param externref externref)))
-		(import \"env\" \"js_sys.externref.get\" (func $js_sys.externref.get (@sym) (param i32) (result \
-		 externref)))
-		(func $test_crate.test (@sym) (param $self i32) (param $value i32)
+		"(import \"test_crate_0_0_0\" \"test\" (func $test_crate_0_0_0.import.test (@sym (name \
+		 \"test_crate_0_0_0.import.test\")) (param externref externref)))
+		(import \"env\" \"js_sys_0_0_0.externref.get\" (func $js_sys_0_0_0.externref.get (@sym) (param \
+		 i32) (result externref)))
+		(func $test_crate_0_0_0.test (@sym) (param $self i32) (param $value i32)
 		  local.get $self
-		  call $js_sys.externref.get (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
 		  local.get $value
-		  call $js_sys.externref.get (@reloc)
-		  call $test_crate.import.test (@reloc)
+		  call $js_sys_0_0_0.externref.get (@reloc)
+		  call $test_crate_0_0_0.import.test (@reloc)
 		)",
 		"(self, value) => self.test = value",
 	);
@@ -33,7 +33,7 @@ macro_rules! test {
 
 		let foreign_mod = syn::parse2(input.clone()).unwrap();
 		let output =
-			r#macro::internal(attr.clone(), foreign_mod, Some("test_crate"), None).unwrap();
+			r#macro::internal(attr.clone(), foreign_mod, None).unwrap();
 		let output = prettyplease::unparse(&File {
 			shebang: None,
 			attrs: Vec::new(),
@@ -99,7 +99,6 @@ fn inner(tmp: &Path, source: &str) -> Result<(Option<String>, Option<String>)> {
 	let js_test = r#macro::internal(
 		TokenStream::new(),
 		parse_quote! { extern "js-sys" { pub type JsTest; } },
-		Some("test_crate"),
 		None,
 	)
 	.unwrap();
@@ -204,7 +203,7 @@ fn inner(tmp: &Path, source: &str) -> Result<(Option<String>, Option<String>)> {
 
 						match payload {
 							Payload::CustomSection(c) if c.name() == "js_bindgen.wat" => {
-								let wat = JsBindgenWatSectionParser::new(&c)
+								let wat = JsBindgenWatSectionParser::new(&c)?
 									.exactly_one()
 									.map_err(|wats| {
 										anyhow!(
@@ -217,11 +216,11 @@ fn inner(tmp: &Path, source: &str) -> Result<(Option<String>, Option<String>)> {
 								js_bindgen_ld_shared::wat_to_object(false, wat).unwrap();
 							}
 							Payload::CustomSection(c) if c.name() == "js_bindgen.import" => {
-								let mut parser = JsBindgenJsSectionParser::new(&c);
+								let mut parser = JsBindgenJsSectionParser::new(&c)?;
 
 								let import = parser.next().unwrap();
 
-								if import.module != "test_crate" {
+								if import.module != "test_crate_0_0_0" {
 									continue;
 								}
 
@@ -54,6 +54,12 @@ fn basic() {
 					Self(::js_sys::hazard::Output::from_raw(raw))
 				}
 			}
+
+			impl ::core::fmt::Debug for JsString {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					::core::fmt::Debug::fmt(&self.0, f)
+				}
+			}
 		},
 		None,
 		None,
@@ -122,6 +128,12 @@ fn generic() {
 					}
 				}
 			}
+
+			impl<T> ::core::fmt::Debug for JsString<T> {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					::core::fmt::Debug::fmt(&self.value, f)
+				}
+			}
 		},
 		None,
 		None,
@@ -190,6 +202,12 @@ fn default() {
 					}
 				}
 			}
+
+			impl<T> ::core::fmt::Debug for JsString<T> {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					::core::fmt::Debug::fmt(&self.value, f)
+				}
+			}
 		},
 		None,
 		None,
@@ -258,6 +276,12 @@ fn r#trait() {
 					}
 				}
 			}
+
+			impl<T: Sized> ::core::fmt::Debug for JsString<T> {
+				fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+					::core::fmt::Debug::fmt(&self.value, f)
+				}
+			}
 		},
 		None,
 		None,
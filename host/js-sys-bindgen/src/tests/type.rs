@@ -10,6 +10,7 @@ fn basic() {
 		parse_quote!(
 			type Test;
 		),
+		&[],
 	);
 
 	test!(
@@ -19,6 +20,7 @@ fn basic() {
 			#items
 		},
 		{
+			use core::fmt;
 			use js_sys::JsValue;
 			use js_sys::hazard::{Input, InputWatConv, InputJsConv, OutputJsConv, Output, JsCast, OutputWatConv};
 
@@ -62,6 +64,12 @@ fn basic() {
 					Self(Output::from_raw(raw))
 				}
 			}
+
+			impl fmt::Debug for Test {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					fmt::Debug::fmt(&self.0, f)
+				}
+			}
 		},
 	);
 }
@@ -74,6 +82,7 @@ fn generic() {
 		parse_quote!(
 			type Test<T = JsValue>;
 		),
+		&[],
 	);
 
 	test!(
@@ -84,6 +93,7 @@ fn generic() {
 		},
 		{
 			use core::marker::PhantomData;
+			use core::fmt;
 			use js_sys::JsValue;
 			use js_sys::hazard::{Input, InputWatConv, InputJsConv, OutputJsConv, Output, JsCast, OutputWatConv};
 
@@ -133,6 +143,105 @@ fn generic() {
 					}
 				}
 			}
+
+			impl<T> fmt::Debug for Test<T> {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					fmt::Debug::fmt(&self.value, f)
+				}
+			}
+		},
+	);
+}
+
+#[test]
+fn extends() {
+	let mut imports = ImportManager::new(None);
+	let items = Type::new(
+		&mut Hygiene::Imports(&mut imports),
+		parse_quote!(
+			type Test;
+		),
+		&[parse_quote!(Parent)],
+	);
+
+	test!(
+		{
+			#imports
+
+			#items
+		},
+		{
+			use core::ops::Deref;
+			use core::fmt;
+			use js_sys::JsValue;
+			use js_sys::hazard::{Input, InputWatConv, InputJsConv, OutputJsConv, Output, JsCast, OutputWatConv};
+
+			#[repr(transparent)]
+			struct Test(JsValue);
+
+			impl AsRef<JsValue> for Test {
+				fn as_ref(&self) -> &JsValue {
+					&self.0
+				}
+			}
+
+			impl From<Test> for JsValue {
+				fn from(value: Test) -> Self {
+					value.0
+				}
+			}
+
+			unsafe impl Input for &Test {
+				const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+				const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+				const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+
+				type Type = <&'static JsValue as Input>::Type;
+
+				fn into_raw(self) -> Self::Type {
+					Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl JsCast for Test {}
+
+			unsafe impl Output for Test {
+				const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+				const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+				const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+
+				type Type = <JsValue as Output>::Type;
+
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(Output::from_raw(raw))
+				}
+			}
+
+			impl fmt::Debug for Test {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					fmt::Debug::fmt(&self.0, f)
+				}
+			}
+
+			impl Deref for Test {
+				type Target = Parent;
+
+				fn deref(&self) -> &Self::Target {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl AsRef<Parent> for Test {
+				fn as_ref(&self) -> &Parent {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl From<Test> for Parent {
+				fn from(value: Test) -> Self {
+					JsCast::unchecked_from(value.0)
+				}
+			}
 		},
 	);
 }
@@ -7,6 +7,7 @@ fn basic() {
 	test!(
 		{ #file },
 		{
+			use core::fmt;
 			use js_sys::JsValue;
 			use js_sys::hazard::{Input, InputWatConv, InputJsConv, OutputJsConv, Output, JsCast, OutputWatConv};
 
@@ -50,6 +51,251 @@ fn basic() {
 					Self(Output::from_raw(raw))
 				}
 			}
+
+			impl fmt::Debug for Test {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					fmt::Debug::fmt(&self.0, f)
+				}
+			}
+		},
+	);
+}
+
+#[test]
+fn inheritance() {
+	let file =
+		crate::web_idl("interface Test : Parent { };", None, &Visibility::Inherited).unwrap();
+
+	test!(
+		{ #file },
+		{
+			use core::ops::Deref;
+			use core::fmt;
+			use js_sys::JsValue;
+			use js_sys::hazard::{Input, InputWatConv, InputJsConv, OutputJsConv, Output, JsCast, OutputWatConv};
+
+			#[repr(transparent)]
+			struct Test(JsValue);
+
+			impl AsRef<JsValue> for Test {
+				fn as_ref(&self) -> &JsValue {
+					&self.0
+				}
+			}
+
+			impl From<Test> for JsValue {
+				fn from(value: Test) -> Self {
+					value.0
+				}
+			}
+
+			unsafe impl Input for &Test {
+				const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+				const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+				const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+
+				type Type = <&'static JsValue as Input>::Type;
+
+				fn into_raw(self) -> Self::Type {
+					Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl JsCast for Test {}
+
+			unsafe impl Output for Test {
+				const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+				const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+				const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+
+				type Type = <JsValue as Output>::Type;
+
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(Output::from_raw(raw))
+				}
+			}
+
+			impl fmt::Debug for Test {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					fmt::Debug::fmt(&self.0, f)
+				}
+			}
+
+			impl Deref for Test {
+				type Target = Parent;
+
+				fn deref(&self) -> &Self::Target {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl AsRef<Parent> for Test {
+				fn as_ref(&self) -> &Parent {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl From<Test> for Parent {
+				fn from(value: Test) -> Self {
+					JsCast::unchecked_from(value.0)
+				}
+			}
+		},
+	);
+}
+
+#[test]
+fn ancestors() {
+	let file = crate::web_idl(
+		"interface Parent : Grandparent { }; interface Test : Parent { };",
+		None,
+		&Visibility::Inherited,
+	)
+	.unwrap();
+
+	test!(
+		{ #file },
+		{
+			use core::ops::Deref;
+			use core::fmt;
+			use js_sys::JsValue;
+			use js_sys::hazard::{InputJsConv, OutputJsConv, OutputWatConv, Input, InputWatConv, Output, JsCast};
+
+			#[repr(transparent)]
+			struct Parent(JsValue);
+
+			impl AsRef<JsValue> for Parent {
+				fn as_ref(&self) -> &JsValue {
+					&self.0
+				}
+			}
+
+			impl From<Parent> for JsValue {
+				fn from(value: Parent) -> Self {
+					value.0
+				}
+			}
+
+			unsafe impl Input for &Parent {
+				const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+				const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+				const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+				type Type = <&'static JsValue as Input>::Type;
+				fn into_raw(self) -> Self::Type {
+					Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl JsCast for Parent {}
+
+			unsafe impl Output for Parent {
+				const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+				const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+				const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+				type Type = <JsValue as Output>::Type;
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(Output::from_raw(raw))
+				}
+			}
+
+			impl fmt::Debug for Parent {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					fmt::Debug::fmt(&self.0, f)
+				}
+			}
+
+			impl Deref for Parent {
+				type Target = Grandparent;
+				fn deref(&self) -> &Self::Target {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl AsRef<Grandparent> for Parent {
+				fn as_ref(&self) -> &Grandparent {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl From<Parent> for Grandparent {
+				fn from(value: Parent) -> Self {
+					JsCast::unchecked_from(value.0)
+				}
+			}
+
+			#[repr(transparent)]
+			struct Test(JsValue);
+
+			impl AsRef<JsValue> for Test {
+				fn as_ref(&self) -> &JsValue {
+					&self.0
+				}
+			}
+
+			impl From<Test> for JsValue {
+				fn from(value: Test) -> Self {
+					value.0
+				}
+			}
+
+			unsafe impl Input for &Test {
+				const WAT_TYPE: &'static str = <&JsValue as Input>::WAT_TYPE;
+				const WAT_CONV: Option<InputWatConv> = <&JsValue as Input>::WAT_CONV;
+				const JS_CONV: Option<InputJsConv> = <&JsValue as Input>::JS_CONV;
+				type Type = <&'static JsValue as Input>::Type;
+				fn into_raw(self) -> Self::Type {
+					Input::into_raw(&self.0)
+				}
+			}
+
+			unsafe impl JsCast for Test {}
+
+			unsafe impl Output for Test {
+				const WAT_TYPE: &str = <JsValue as Output>::WAT_TYPE;
+				const WAT_CONV: Option<OutputWatConv> = <JsValue as Output>::WAT_CONV;
+				const JS_CONV: Option<OutputJsConv> = <JsValue as Output>::JS_CONV;
+				type Type = <JsValue as Output>::Type;
+				fn from_raw(raw: Self::Type) -> Self {
+					Self(Output::from_raw(raw))
+				}
+			}
+
+			impl fmt::Debug for Test {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					fmt::Debug::fmt(&self.0, f)
+				}
+			}
+
+			impl Deref for Test {
+				type Target = Parent;
+				fn deref(&self) -> &Self::Target {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl AsRef<Parent> for Test {
+				fn as_ref(&self) -> &Parent {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl From<Test> for Parent {
+				fn from(value: Test) -> Self {
+					JsCast::unchecked_from(value.0)
+				}
+			}
+
+			impl AsRef<Grandparent> for Test {
+				fn as_ref(&self) -> &Grandparent {
+					JsCast::unchecked_from_ref(&self.0)
+				}
+			}
+
+			impl From<Test> for Grandparent {
+				fn from(value: Test) -> Self {
+					JsCast::unchecked_from(value.0)
+				}
+			}
 		},
 	);
 }
@@ -1,20 +1,26 @@
-use std::array;
+use std::{iter, vec};
 
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote_spanned};
 use syn::spanned::Spanned;
-use syn::{Fields, ForeignItemType, Item, ItemImpl, ItemStruct, Token, parse_quote_spanned};
+use syn::{Fields, ForeignItemType, Item, ItemImpl, ItemStruct, Path, Token, parse_quote_spanned};
 
 use crate::Hygiene;
 
 pub struct Type {
 	pub r#struct: ItemStruct,
-	pub impls: [ItemImpl; 5],
+	pub impls: [ItemImpl; 6],
+	/// `Deref` to the first parent and `AsRef` and `From` implementations for
+	/// every parent this type extends.
+	///
+	/// Ancestors are not resolved transitively: for conversions into all of
+	/// them, every ancestor has to be listed, starting with the direct parent.
+	pub extends: Vec<ItemImpl>,
 }
 
 impl Type {
 	#[must_use]
-	pub fn new(hygiene: &mut Hygiene<'_>, item: ForeignItemType) -> Self {
+	pub fn new(hygiene: &mut Hygiene<'_>, item: ForeignItemType, extends: &[Path]) -> Self {
 		let span = item.span();
 		let ForeignItemType {
 			attrs,
@@ -37,6 +43,7 @@ impl Type {
 		let output = hygiene.output(&cfgs, span);
 		let output_wat_conv = hygiene.output_wat_conv(&cfgs, span);
 		let output_js_conv = hygiene.output_js_conv(&cfgs, span);
+		let fmt = hygiene.fmt(&cfgs, span);
 		let as_ref = hygiene.as_ref(span);
 		let str = hygiene.str(span);
 		let from = hygiene.from(span);
@@ -121,8 +128,55 @@ impl Type {
 					}
 				}
 			},
+			parse_quote_spanned! {span=>
+				#(#cfgs)*
+				impl #gen_impl #fmt::Debug for #ident #gen_type #gen_where {
+					fn fmt(&self, f: &mut #fmt::Formatter<'_>) -> #fmt::Result {
+						#fmt::Debug::fmt(&self.#value, f)
+					}
+				}
+			},
 		];
 
+		let deref = extends.first().map(|parent| {
+			let deref = hygiene.deref(&cfgs, span);
+
+			parse_quote_spanned! {span=>
+				#(#cfgs)*
+				impl #gen_impl #deref for #ident #gen_type #gen_where {
+					type Target = #parent;
+
+					fn deref(&self) -> &Self::Target {
+						#js_cast::unchecked_from_ref(&self.#value)
+					}
+				}
+			}
+		});
+
+		let extends = deref
+			.into_iter()
+			.chain(extends.iter().flat_map(|parent| {
+				[
+					parse_quote_spanned! {span=>
+						#(#cfgs)*
+						impl #gen_impl #as_ref<#parent> for #ident #gen_type #gen_where {
+							fn as_ref(&self) -> &#parent {
+								#js_cast::unchecked_from_ref(&self.#value)
+							}
+						}
+					},
+					parse_quote_spanned! {span=>
+						#(#cfgs)*
+						impl #gen_impl #from<#ident #gen_type> for #parent #gen_where {
+							fn from(value: #ident #gen_type) -> Self {
+								#js_cast::unchecked_from(value.#value)
+							}
+						}
+					},
+				]
+			}))
+			.collect();
+
 		item_attrs.append(&mut cfgs);
 		item_attrs.push(parse_quote_spanned! {span=>#[repr(transparent)]});
 
@@ -136,25 +190,24 @@ impl Type {
 			semi_token,
 		};
 
-		Self { r#struct, impls }
+		Self {
+			r#struct,
+			impls,
+			extends,
+		}
 	}
 }
 
 impl IntoIterator for Type {
 	type Item = Item;
-	type IntoIter = array::IntoIter<Item, 6>;
+	type IntoIter = vec::IntoIter<Item>;
 
 	fn into_iter(self) -> Self::IntoIter {
-		let [impl_1, impl_2, impl_3, impl_4, impl_5] = self.impls;
-		[
-			Item::from(self.r#struct),
-			impl_1.into(),
-			impl_2.into(),
-			impl_3.into(),
-			impl_4.into(),
-			impl_5.into(),
-		]
-		.into_iter()
+		iter::once(Item::from(self.r#struct))
+			.chain(self.impls.into_iter().map(Item::from))
+			.chain(self.extends.into_iter().map(Item::from))
+			.collect::<Vec<_>>()
+			.into_iter()
 	}
 }
 
@@ -162,7 +215,7 @@ impl ToTokens for Type {
 	fn to_tokens(&self, tokens: &mut TokenStream) {
 		self.r#struct.to_tokens(tokens);
 
-		for r#impl in &self.impls {
+		for r#impl in self.impls.iter().chain(&self.extends) {
 			r#impl.to_tokens(tokens);
 		}
 	}
@@ -1,3 +1,7 @@
+use std::iter;
+
+use foldhash::fast::FixedState;
+use hashbrown::HashMap;
 use proc_macro2::Span;
 use syn::{Attribute, File, Ident, Item, Path, Visibility, parse_quote};
 use weedle::common::Docstring;
@@ -14,7 +18,21 @@ pub fn web_idl<'i>(
 	let mut hygiene = Hygiene::Imports(&mut imports);
 	let mut items: Vec<Item> = Vec::new();
 
-	for definition in weedle::parse(web_idl)? {
+	let definitions = weedle::parse(web_idl)?;
+	// Conversions are generated for all ancestors, not just the direct parent.
+	let parents: HashMap<_, _, FixedState> = definitions
+		.iter()
+		.filter_map(|definition| match definition {
+			Definition::Interface(InterfaceDefinition {
+				identifier,
+				inheritance: Some(inheritance),
+				..
+			}) => Some((identifier.0, inheritance.identifier.0)),
+			_ => None,
+		})
+		.collect();
+
+	for definition in definitions {
 		match definition {
 			Definition::Interface(InterfaceDefinition {
 				docstring,
@@ -35,15 +53,22 @@ pub fn web_idl<'i>(
 				}
 
 				let identifier = Ident::new(identifier.0, Span::mixed_site());
+				let extends: Vec<Path> = iter::successors(
+					inheritance.map(|inheritance| inheritance.identifier.0),
+					|parent| parents.get(parent).copied(),
+				)
+				.take(parents.len())
+				.map(|parent| {
+					let parent = Ident::new(parent, Span::mixed_site());
+					parse_quote!(#parent)
+				})
+				.collect();
 				items.extend(Type::new(
 					&mut hygiene,
 					parse_quote!(#(#attrs)* #vis type #identifier;),
+					&extends,
 				));
 
-				if inheritance.is_some() {
-					todo!()
-				}
-
 				if !members.body.is_empty() {
 					todo!()
 				}
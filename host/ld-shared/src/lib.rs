@@ -1,5 +1,6 @@
+use std::error;
 use std::ffi::OsStr;
-use std::fmt::{self, Debug, Formatter};
+use std::fmt::{self, Debug, Display, Formatter};
 use std::io::Error;
 use std::path::Path;
 use std::time::SystemTime;
@@ -9,6 +10,50 @@ use object::read::archive::ArchiveFile;
 use rwat::ParseOptions;
 use wasmparser::CustomSectionReader;
 
+/// Version of the encoding used in the custom sections emitted by the
+/// `js-bindgen` macros. Every entry starts with [`VERSION_MARKER`] followed by
+/// it, so objects built with an incompatible `js-bindgen` are detected before
+/// parsing them.
+///
+/// Has to be kept in sync with `js-bindgen-macro`.
+pub const FORMAT_VERSION: u16 = 1;
+
+/// Precedes the [`FORMAT_VERSION`] of every entry. Entries from before the
+/// format was versioned never start with it: WAT is text and JS entries start
+/// with the length of their module name.
+///
+/// Has to be kept in sync with `js-bindgen-macro`.
+pub const VERSION_MARKER: u8 = 0;
+
+/// Returned when a custom section entry was not encoded with
+/// [`FORMAT_VERSION`].
+#[derive(Debug)]
+pub struct UnsupportedFormatError {
+	pub section: String,
+	/// [`None`] if the entry is from before the format was versioned.
+	pub version: Option<u16>,
+}
+
+impl Display for UnsupportedFormatError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(f, "found custom section `{}` with ", self.section)?;
+
+		if let Some(version) = self.version {
+			write!(f, "format version {version}")?;
+		} else {
+			f.write_str("an unversioned format")?;
+		}
+
+		write!(
+			f,
+			", expected version {FORMAT_VERSION}: it was built with an incompatible version of \
+			 `js-bindgen`"
+		)
+	}
+}
+
+impl error::Error for UnsupportedFormatError {}
+
 /// Creates a relocatable Wasm object from the WAT input.
 pub fn wat_to_object(wasm64: bool, wat: &str) -> rwat::Result<Vec<u8>> {
 	// `wasm-ld` requires a `(memory i64)` in every object file if the requested
@@ -115,9 +160,11 @@ pub fn ld_input_parser<E>(
 pub struct JsBindgenWatSectionParser<'cs>(CustomSectionParser<'cs>);
 
 impl<'cs> JsBindgenWatSectionParser<'cs> {
-	#[must_use]
-	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
-		Self(CustomSectionParser::new(custom_section))
+	/// # Errors
+	///
+	/// If any entry was not encoded with [`FORMAT_VERSION`].
+	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Result<Self, UnsupportedFormatError> {
+		CustomSectionParser::new(custom_section).map(Self)
 	}
 }
 
@@ -150,10 +197,18 @@ pub struct JsBindgenJsSectionParser<'cs>(CustomSectionParser<'cs>);
 pub struct JsBindgenJsSection<'cs> {
 	pub module: &'cs str,
 	pub name: &'cs str,
+	pub location: JsLocation<'cs>,
 	pub js: &'cs str,
 	pub embeds: Vec<JsRequiredEmbed<'cs>>,
 }
 
+/// Rust source location of the macro call providing the JS.
+#[derive(Clone, Copy, Debug)]
+pub struct JsLocation<'cs> {
+	pub file: &'cs str,
+	pub line: u32,
+}
+
 #[derive(Debug)]
 pub struct JsRequiredEmbed<'cs> {
 	pub module: &'cs str,
@@ -161,9 +216,11 @@ pub struct JsRequiredEmbed<'cs> {
 }
 
 impl<'cs> JsBindgenJsSectionParser<'cs> {
-	#[must_use]
-	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
-		Self(CustomSectionParser::new(custom_section))
+	/// # Errors
+	///
+	/// If any entry was not encoded with [`FORMAT_VERSION`].
+	pub fn new(custom_section: &CustomSectionReader<'cs>) -> Result<Self, UnsupportedFormatError> {
+		CustomSectionParser::new(custom_section).map(Self)
 	}
 }
 
@@ -202,6 +259,21 @@ impl<'cs> Iterator for JsBindgenJsSectionParser<'cs> {
 				})
 				.unwrap_or_else(|| panic!("found invalid JS encoding `{}`", self.0.name));
 
+			let location = data
+				.split_off(..2)
+				.and_then(|length| {
+					let length = usize::from(u16::from_le_bytes(length.try_into().unwrap()));
+
+					let location = str::from_utf8(data.split_off(..length)?).ok()?;
+					let (file, line) = location.rsplit_once(':')?;
+
+					Some(JsLocation {
+						file,
+						line: line.parse().ok()?,
+					})
+				})
+				.unwrap_or_else(|| panic!("found invalid JS encoding `{}`", self.0.name));
+
 			let embeds = data
 				.split_off_first()
 				.and_then(|length| {
@@ -237,6 +309,7 @@ impl<'cs> Iterator for JsBindgenJsSectionParser<'cs> {
 			JsBindgenJsSection {
 				module,
 				name,
+				location,
 				js,
 				embeds,
 			}
@@ -251,11 +324,31 @@ struct CustomSectionParser<'cs> {
 }
 
 impl<'cs> CustomSectionParser<'cs> {
-	fn new(custom_section: &CustomSectionReader<'cs>) -> Self {
-		Self {
-			name: custom_section.name(),
-			data: custom_section.data(),
+	/// Checks the version of all entries before any of them is parsed.
+	fn new(custom_section: &CustomSectionReader<'cs>) -> Result<Self, UnsupportedFormatError> {
+		let name = custom_section.name();
+		let mut data = custom_section.data();
+
+		while let Some(entry) = split_entry(name, &mut data) {
+			let version = match entry {
+				[VERSION_MARKER, version_1, version_2, ..] => {
+					Some(u16::from_le_bytes([*version_1, *version_2]))
+				}
+				_ => None,
+			};
+
+			if version != Some(FORMAT_VERSION) {
+				return Err(UnsupportedFormatError {
+					section: name.to_owned(),
+					version,
+				});
+			}
 		}
+
+		Ok(Self {
+			name,
+			data: custom_section.data(),
+		})
 	}
 }
 
@@ -263,21 +356,23 @@ impl<'cs> Iterator for CustomSectionParser<'cs> {
 	type Item = &'cs [u8];
 
 	fn next(&mut self) -> Option<Self::Item> {
-		if let Some(length) = self.data.split_off(..4) {
-			let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
+		// The version was already checked in `new()`.
+		split_entry(self.name, &mut self.data).map(|entry| &entry[3..])
+	}
+}
 
-			let data = self.data.split_off(..length).unwrap_or_else(|| {
-				panic!("invalid length encoding in custom section `{}`", self.name)
-			});
+fn split_entry<'cs>(name: &str, data: &mut &'cs [u8]) -> Option<&'cs [u8]> {
+	if let Some(length) = data.split_off(..4) {
+		let length = u32::from_le_bytes(length.try_into().unwrap()) as usize;
 
-			Some(data)
-		} else if self.data.is_empty() {
-			None
-		} else {
-			panic!(
-				"found left over bytes in custom section `{}`: {:?}",
-				self.name, self.data
-			);
-		}
+		let entry = data
+			.split_off(..length)
+			.unwrap_or_else(|| panic!("invalid length encoding in custom section `{name}`"));
+
+		Some(entry)
+	} else if data.is_empty() {
+		None
+	} else {
+		panic!("found left over bytes in custom section `{name}`: {data:?}");
 	}
 }
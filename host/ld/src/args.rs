@@ -18,7 +18,11 @@ enum OptKind {
 	Separate,
 }
 
-const CUSTOM_ARGS: [(&str, OptKind); 1usize] = [("web", OptKind::Flag)];
+const CUSTOM_ARGS: [(&str, OptKind); 3usize] = [
+	("web", OptKind::Flag),
+	("size-report=", OptKind::Joined),
+	("no-direct-imports", OptKind::Flag),
+];
 const LINKER_GUARD_LIBRARY: &str = "js-bindgen-needs-js-bindgen-ld";
 
 pub(crate) struct Arguments<'args> {
@@ -166,6 +170,14 @@ impl<'args> Arguments<'args> {
 		self.arg_flag("web")
 	}
 
+	pub(crate) fn size_report(&self) -> Option<&'args OsStr> {
+		self.arg_single("size-report=")
+	}
+
+	pub(crate) fn no_direct_imports(&self) -> bool {
+		self.arg_flag("no-direct-imports")
+	}
+
 	pub(crate) fn pass_args(&self) -> &[&OsString] {
 		&self.pass_args
 	}
@@ -173,7 +185,7 @@ impl<'args> Arguments<'args> {
 
 #[cfg(test)]
 mod tests {
-	use std::ffi::OsString;
+	use std::ffi::{OsStr, OsString};
 
 	use crate::args::Arguments;
 
@@ -182,6 +194,30 @@ mod tests {
 		let args = &["--web".into(), "--no-entry".into()];
 		let args = Arguments::new(args);
 		assert!(args.web());
+		assert!(args.size_report().is_none());
+		assert!(!args.no_direct_imports());
+
+		let mut iter = args.pass_args().iter();
+		assert_eq!(iter.next().copied(), Some(&OsString::from("--no-entry")));
+		assert!(iter.next().is_none());
+	}
+
+	#[test]
+	fn test_size_report() {
+		let args = &["--size-report=report.json".into(), "--no-entry".into()];
+		let args = Arguments::new(args);
+		assert_eq!(args.size_report(), Some(OsStr::new("report.json")));
+
+		let mut iter = args.pass_args().iter();
+		assert_eq!(iter.next().copied(), Some(&OsString::from("--no-entry")));
+		assert!(iter.next().is_none());
+	}
+
+	#[test]
+	fn test_no_direct_imports() {
+		let args = &["--no-direct-imports".into(), "--no-entry".into()];
+		let args = Arguments::new(args);
+		assert!(args.no_direct_imports());
 
 		let mut iter = args.pass_args().iter();
 		assert_eq!(iter.next().copied(), Some(&OsString::from("--no-entry")));
@@ -0,0 +1,218 @@
+use anyhow::Result;
+use hashbrown::HashMap;
+use wasmparser::{BinaryReader, CodeSectionReader, FunctionBody, Operator, Payload, TypeRef};
+
+/// Redirects calls to functions only forwarding their parameters to an
+/// imported function straight to the import.
+///
+/// Every import gets a WAT wrapper to apply conversions. If none are needed,
+/// the wrapper is a plain `call`. Calling the import directly lets engines
+/// fast-path well-known imports like `Math.sqrt()`.
+///
+/// Calls are patched in place with equally sized LEB128s, keeping code offsets
+/// used by DWARF valid. The unused wrappers are left in place.
+#[derive(Default)]
+pub struct DirectImports {
+	/// Type index of every function, including imported ones.
+	functions: Vec<u32>,
+	imports: u32,
+}
+
+impl DirectImports {
+	pub fn parse(&mut self, payload: &Payload<'_>) -> Result<()> {
+		match payload {
+			Payload::ImportSection(section) => {
+				for import in section.clone().into_imports() {
+					if let TypeRef::Func(index) = import?.ty {
+						self.functions.push(index);
+						self.imports += 1;
+					}
+				}
+			}
+			Payload::FunctionSection(section) => {
+				for index in section.clone() {
+					self.functions.push(index?);
+				}
+			}
+			_ => (),
+		}
+
+		Ok(())
+	}
+
+	/// Returns the patched code section or [`None`] if no call was redirected.
+	pub fn code_section(&self, data: &[u8], offset: usize) -> Result<Option<Vec<u8>>> {
+		let section = || CodeSectionReader::new(BinaryReader::new(data, offset));
+		let mut redirects = HashMap::new();
+
+		for (index, body) in (self.imports..).zip(section()?) {
+			if let Some(import) = self.forwarded_import(&body?)?
+				&& self.functions.get(index as usize) == self.functions.get(import as usize)
+			{
+				redirects.insert(index, import);
+			}
+		}
+
+		if redirects.is_empty() {
+			return Ok(None);
+		}
+
+		let mut output = data.to_vec();
+
+		for body in section()? {
+			let mut operators = body?.get_operators_reader()?;
+
+			while !operators.eof() {
+				let (operator, position) = operators.read_with_offset()?;
+
+				if let Operator::Call { function_index } | Operator::ReturnCall { function_index } =
+					operator && let Some(import) = redirects.get(&function_index)
+				{
+					// Skip the single byte opcode.
+					let start = position - offset + 1;
+					let end = operators.original_position() - offset;
+					write_padded_leb128(&mut output[start..end], *import);
+				}
+			}
+		}
+
+		Ok(Some(output))
+	}
+
+	/// Returns the import called if `body` consists of nothing but getting all
+	/// parameters in order and calling an imported function.
+	///
+	/// Equal function types are checked by the caller. Together with the body
+	/// passing validation, this guarantees that all parameters are forwarded.
+	fn forwarded_import(&self, body: &FunctionBody<'_>) -> Result<Option<u32>> {
+		if body.get_locals_reader()?.get_count() != 0 {
+			return Ok(None);
+		}
+
+		let mut operators = body.get_operators_reader()?;
+		let mut param = 0;
+
+		loop {
+			match operators.read()? {
+				Operator::LocalGet { local_index } if local_index == param => param += 1,
+				Operator::Call { function_index } if function_index < self.imports => {
+					return Ok(
+						(matches!(operators.read()?, Operator::End) && operators.eof())
+							.then_some(function_index),
+					);
+				}
+				_ => return Ok(None),
+			}
+		}
+	}
+}
+
+/// Writes `value` as LEB128 with the length of `output`. The value must fit.
+fn write_padded_leb128(output: &mut [u8], mut value: u32) {
+	let (last, rest) = output.split_last_mut().expect("LEB128 should not be empty");
+
+	for byte in rest {
+		*byte = u8::try_from(value & 0x7f).unwrap() | 0x80;
+		value >>= 7;
+	}
+
+	assert!(value < 0x80, "value should fit into the LEB128");
+	*last = u8::try_from(value).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+	use wasm_encoder::{
+		CodeSection, EntityType, Function, FunctionSection, ImportSection, Module, TypeSection,
+		ValType,
+	};
+	use wasmparser::{BinaryReader, CodeSectionReader, Operator, Parser, Payload};
+
+	use super::DirectImports;
+
+	fn calls(data: &[u8]) -> Vec<Vec<u32>> {
+		CodeSectionReader::new(BinaryReader::new(data, 0))
+			.unwrap()
+			.into_iter()
+			.map(|body| {
+				body.unwrap()
+					.get_operators_reader()
+					.unwrap()
+					.into_iter()
+					.filter_map(|operator| match operator.unwrap() {
+						Operator::Call { function_index } => Some(function_index),
+						_ => None,
+					})
+					.collect()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn redirect() {
+		let mut types = TypeSection::new();
+		types.ty().function([ValType::F64], [ValType::F64]);
+		types.ty().function([ValType::I32], [ValType::F64]);
+
+		let mut imports = ImportSection::new();
+		imports.import("js_sys", "sqrt", EntityType::Function(0));
+		imports.import("js_sys", "value", EntityType::Function(1));
+
+		let mut functions = FunctionSection::new();
+		let mut code = CodeSection::new();
+
+		// Forwarding wrapper, padded like `wasm-ld` does with relocations.
+		functions.function(0);
+		let mut function = Function::new([]);
+		function.raw([0x20, 0x00, 0x10, 0x80, 0x80, 0x80, 0x80, 0x00, 0x0b]);
+		code.function(&function);
+		// Wrapper with a conversion.
+		functions.function(1);
+		let mut function = Function::new([]);
+		function.raw([0x20, 0x00, 0x41, 0x01, 0x6a, 0x10, 0x01, 0x0b]);
+		code.function(&function);
+		// Calling the forwarding wrapper.
+		functions.function(0);
+		let mut function = Function::new([]);
+		function.raw([0x20, 0x00, 0x10, 0x82, 0x80, 0x80, 0x80, 0x00, 0x0b]);
+		code.function(&function);
+		// Calling the converting wrapper.
+		functions.function(1);
+		let mut function = Function::new([]);
+		function.raw([0x20, 0x00, 0x10, 0x03, 0x0b]);
+		code.function(&function);
+
+		let mut module = Module::new();
+		module
+			.section(&types)
+			.section(&imports)
+			.section(&functions)
+			.section(&code);
+		let wasm = module.finish();
+
+		let mut direct_imports = DirectImports::default();
+		let mut output = None;
+
+		for payload in Parser::new(0).parse_all(&wasm) {
+			let payload = payload.unwrap();
+			direct_imports.parse(&payload).unwrap();
+
+			if let Payload::CodeSectionStart { range, .. } = payload {
+				let data = &wasm[range.clone()];
+				let patched = direct_imports
+					.code_section(data, range.start)
+					.unwrap()
+					.unwrap();
+
+				assert_eq!(calls(data), [vec![0], vec![1], vec![2], vec![3]]);
+				assert_eq!(data.len(), patched.len());
+				output = Some(patched);
+			}
+		}
+
+		assert_eq!(
+			calls(&output.unwrap()),
+			[vec![0], vec![1], vec![0], vec![3]]
+		);
+	}
+}
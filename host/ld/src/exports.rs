@@ -0,0 +1,94 @@
+use anyhow::{Context, Result};
+use js_bindgen_cli_lib::{Export, ExportKind, ValType};
+use wasmparser::{CompositeInnerType, ExternalKind, FuncType, Payload, TypeRef};
+
+/// Collects all exports of the final Wasm module with their types.
+#[derive(Default)]
+pub struct ExportParser {
+	types: Vec<Option<FuncType>>,
+	/// Type index of every function, including imported ones.
+	functions: Vec<u32>,
+	exports: Vec<Export<String>>,
+}
+
+impl ExportParser {
+	pub fn parse(&mut self, payload: &Payload<'_>) -> Result<()> {
+		match payload {
+			Payload::TypeSection(section) => {
+				for rec_group in section.clone() {
+					for sub_type in rec_group?.into_types() {
+						self.types.push(match sub_type.composite_type.inner {
+							CompositeInnerType::Func(r#type) => Some(r#type),
+							_ => None,
+						});
+					}
+				}
+			}
+			Payload::ImportSection(section) => {
+				for import in section.clone().into_imports() {
+					if let TypeRef::Func(index) = import?.ty {
+						self.functions.push(index);
+					}
+				}
+			}
+			Payload::FunctionSection(section) => {
+				for index in section.clone() {
+					self.functions.push(index?);
+				}
+			}
+			Payload::ExportSection(section) => {
+				for export in section.clone() {
+					let export = export?;
+
+					let kind = match export.kind {
+						ExternalKind::Func => {
+							let r#type = self
+								.functions
+								.get(export.index as usize)
+								.and_then(|index| self.types.get(*index as usize))
+								.and_then(Option::as_ref)
+								.with_context(|| {
+									format!(
+										"unable to find type of exported function: `{}`",
+										export.name
+									)
+								})?;
+
+							ExportKind::Function {
+								params: r#type
+									.params()
+									.iter()
+									.copied()
+									.map(ValType::from)
+									.collect(),
+								results: r#type
+									.results()
+									.iter()
+									.copied()
+									.map(ValType::from)
+									.collect(),
+							}
+						}
+						ExternalKind::Global => ExportKind::Global,
+						ExternalKind::Memory => ExportKind::Memory,
+						ExternalKind::Table => ExportKind::Table,
+						// Not representable in JS.
+						_ => continue,
+					};
+
+					self.exports.push(Export {
+						name: export.name.to_owned(),
+						kind,
+					});
+				}
+			}
+			_ => (),
+		}
+
+		Ok(())
+	}
+
+	pub fn into_exports(self) -> Vec<Export<String>> {
+		self.exports
+	}
+}
@@ -1,24 +1,35 @@
+use std::path::Path;
+use std::rc::Rc;
+
 use anyhow::{Result, bail, ensure};
 use foldhash::fast::FixedState;
 use hashbrown::{HashMap, HashSet};
-use js_bindgen_cli_lib::{JsOutput, MainMemory};
-use js_bindgen_ld_shared::{JsBindgenJsSectionParser, JsRequiredEmbed};
+use js_bindgen_cli_lib::{Export, JsOutput, JsSource, MainMemory};
+use js_bindgen_ld_shared::{JsBindgenJsSection, JsBindgenJsSectionParser, JsRequiredEmbed};
 use wasmparser::{CustomSectionReader, Import};
 
+use crate::report::SizeReport;
+
 type FixedHashMap<K, V> = HashMap<K, V, FixedState>;
 
 #[derive(Default)]
 pub struct JsStore {
-	import: FixedHashMap<String, FixedHashMap<String, String>>,
+	import: FixedHashMap<String, FixedHashMap<String, Js>>,
 	expected_import: HashMap<String, HashSet<String>>,
 	provided_import: HashMap<String, HashMap<String, JsWithEmbeds>>,
-	embed: FixedHashMap<String, FixedHashMap<String, String>>,
+	embed: FixedHashMap<String, FixedHashMap<String, Js>>,
 	expected_embed: HashMap<String, HashSet<String>>,
 	provided_embed: HashMap<String, HashMap<String, JsWithEmbeds>>,
 }
 
+struct Js {
+	source: JsSource<String>,
+	/// Object file the JS was found in.
+	origin: Rc<Path>,
+}
+
 struct JsWithEmbeds {
-	js: String,
+	js: Js,
 	embeds: Vec<JsEmbed>,
 }
 
@@ -58,8 +69,12 @@ impl JsStore {
 		Ok(())
 	}
 
-	pub fn add_js_imports(&mut self, custom_section: &CustomSectionReader<'_>) -> Result<()> {
-		for import in JsBindgenJsSectionParser::new(custom_section) {
+	pub fn add_js_imports(
+		&mut self,
+		custom_section: &CustomSectionReader<'_>,
+		origin: &Rc<Path>,
+	) -> Result<()> {
+		for import in JsBindgenJsSectionParser::new(custom_section)? {
 			if self
 				.expected_import
 				.get_mut(import.module)
@@ -68,7 +83,7 @@ impl JsStore {
 				self.import
 					.entry_ref(import.module)
 					.or_default()
-					.insert(import.name.to_owned(), import.js.to_owned());
+					.insert(import.name.to_owned(), js(&import, origin));
 
 				for embed in import.embeds {
 					self.require_js_embed(embed.into());
@@ -80,7 +95,7 @@ impl JsStore {
 				.try_insert(
 					import.name.to_owned(),
 					JsWithEmbeds {
-						js: import.js.to_owned(),
+						js: js(&import, origin),
 						embeds: import.embeds.into_iter().map(JsEmbed::from).collect(),
 					},
 				) {
@@ -89,7 +104,7 @@ impl JsStore {
 					 2:\n{:?}",
 					import.module,
 					error.entry.key(),
-					error.entry.get().js,
+					error.entry.get().js.source.js,
 					import.js
 				);
 			}
@@ -98,8 +113,12 @@ impl JsStore {
 		Ok(())
 	}
 
-	pub fn add_js_embeds(&mut self, custom_section: &CustomSectionReader<'_>) -> Result<()> {
-		for embed in JsBindgenJsSectionParser::new(custom_section) {
+	pub fn add_js_embeds(
+		&mut self,
+		custom_section: &CustomSectionReader<'_>,
+		origin: &Rc<Path>,
+	) -> Result<()> {
+		for embed in JsBindgenJsSectionParser::new(custom_section)? {
 			if self
 				.expected_embed
 				.get_mut(embed.module)
@@ -108,7 +127,7 @@ impl JsStore {
 				self.embed
 					.entry_ref(embed.module)
 					.or_default()
-					.insert(embed.name.to_owned(), embed.js.to_owned());
+					.insert(embed.name.to_owned(), js(&embed, origin));
 
 				for required_embed in embed.embeds {
 					self.require_js_embed(required_embed.into());
@@ -120,7 +139,7 @@ impl JsStore {
 				.try_insert(
 					embed.name.to_owned(),
 					JsWithEmbeds {
-						js: embed.js.to_owned(),
+						js: js(&embed, origin),
 						embeds: embed.embeds.into_iter().map(JsEmbed::from).collect(),
 					},
 				) {
@@ -128,7 +147,7 @@ impl JsStore {
 					"found multiple JS embeds for `{}:{}`\n\tJS Embed 1:\n{}\n\tJS Embed 2:\n{}",
 					embed.module,
 					error.entry.key(),
-					error.entry.get().js,
+					error.entry.get().js.source.js,
 					embed.js
 				);
 			}
@@ -175,15 +194,63 @@ impl JsStore {
 		Ok(())
 	}
 
-	pub fn into_output(self, main_memory: MainMemory<'_>) -> JsOutput<'_, String> {
+	pub fn size_report(&self, report: &mut SizeReport) {
+		for (module, names) in &self.import {
+			for (name, js) in names {
+				report.add_js_import(module, name, &js.origin, &js.source.js);
+			}
+		}
+
+		for (module, names) in &self.embed {
+			for (name, js) in names {
+				report.add_js_embed(module, name, &js.origin, &js.source.js);
+			}
+		}
+	}
+
+	pub fn into_output(
+		self,
+		main_memory: MainMemory<'_>,
+		source_root: String,
+		threads: bool,
+		exports: Vec<Export<String>>,
+	) -> JsOutput<'_, String> {
 		JsOutput {
 			main_memory,
-			js_import: self.import,
-			js_embed: self.embed,
+			source_root,
+			js_import: sources(self.import),
+			js_embed: sources(self.embed),
+			threads,
+			exports,
 		}
 	}
 }
 
+fn js(section: &JsBindgenJsSection<'_>, origin: &Rc<Path>) -> Js {
+	Js {
+		source: JsSource {
+			js: section.js.to_owned(),
+			file: section.location.file.to_owned(),
+			line: section.location.line,
+		},
+		origin: Rc::clone(origin),
+	}
+}
+
+fn sources(
+	js: FixedHashMap<String, FixedHashMap<String, Js>>,
+) -> FixedHashMap<String, FixedHashMap<String, JsSource<String>>> {
+	js.into_iter()
+		.map(|(module, names)| {
+			let names = names
+				.into_iter()
+				.map(|(name, js)| (name, js.source))
+				.collect();
+			(module, names)
+		})
+		.collect()
+}
+
 impl From<JsRequiredEmbed<'_>> for JsEmbed {
 	fn from(value: JsRequiredEmbed<'_>) -> Self {
 		Self {
@@ -192,3 +259,239 @@ impl From<JsRequiredEmbed<'_>> for JsEmbed {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+	use std::rc::Rc;
+
+	use js_bindgen_cli_lib::MainMemory;
+	use js_bindgen_ld_shared::{FORMAT_VERSION, VERSION_MARKER};
+	use wasm_encoder::{CustomSection, Module};
+	use wasmparser::{CustomSectionReader, Import, Parser, Payload, TypeRef};
+
+	use crate::js::JsStore;
+
+	type Entry<'a> = (&'a str, &'a str, &'a [(&'a str, &'a str)], &'a str);
+
+	/// Encodes entries like the `js-bindgen` macros do.
+	fn custom_section(
+		name: &str,
+		entries: &[Entry<'_>],
+		fun: impl FnOnce(&CustomSectionReader<'_>),
+	) {
+		versioned_custom_section(Some(FORMAT_VERSION), name, entries, fun);
+	}
+
+	/// Entries are unversioned if `version` is [`None`].
+	fn versioned_custom_section(
+		version: Option<u16>,
+		name: &str,
+		entries: &[Entry<'_>],
+		fun: impl FnOnce(&CustomSectionReader<'_>),
+	) {
+		fn string(data: &mut Vec<u8>, string: &str) {
+			data.extend(u16::try_from(string.len()).unwrap().to_le_bytes());
+			data.extend(string.as_bytes());
+		}
+
+		let mut data = Vec::new();
+
+		for (module, name, embeds, js) in entries {
+			let mut entry = Vec::new();
+
+			if let Some(version) = version {
+				entry.push(VERSION_MARKER);
+				entry.extend(version.to_le_bytes());
+			}
+
+			string(&mut entry, module);
+			string(&mut entry, name);
+			string(&mut entry, "src/lib.rs:1");
+			entry.push(u8::try_from(embeds.len()).unwrap());
+
+			for (module, name) in *embeds {
+				string(&mut entry, module);
+				string(&mut entry, name);
+			}
+
+			entry.extend(js.as_bytes());
+			data.extend(u32::try_from(entry.len()).unwrap().to_le_bytes());
+			data.extend(entry);
+		}
+
+		let mut module = Module::new();
+		module.section(&CustomSection {
+			name: name.into(),
+			data: data.into(),
+		});
+		let module = module.finish();
+
+		let custom_section = Parser::new(0)
+			.parse_all(&module)
+			.find_map(|payload| match payload.unwrap() {
+				Payload::CustomSection(c) => Some(c),
+				_ => None,
+			})
+			.unwrap();
+		fun(&custom_section);
+	}
+
+	#[test]
+	fn unused_embeds() {
+		let mut js_store = JsStore::default();
+		let origin: Rc<Path> = Rc::from(Path::new("test.o"));
+
+		custom_section(
+			"js_bindgen.import",
+			&[
+				(
+					"foo",
+					"used",
+					&[("bar", "a")],
+					"() => this.#jsEmbed.bar.a()",
+				),
+				(
+					"foo",
+					"unused",
+					&[("bar", "b")],
+					"() => this.#jsEmbed.bar.b()",
+				),
+			],
+			|c| js_store.add_js_imports(c, &origin).unwrap(),
+		);
+		custom_section(
+			"js_bindgen.embed",
+			&[
+				("bar", "a", &[], "() => 1"),
+				("bar", "b", &[("bar", "c")], "() => this.#jsEmbed.bar.c()"),
+				("bar", "c", &[], "() => 2"),
+			],
+			|c| js_store.add_js_embeds(c, &origin).unwrap(),
+		);
+
+		// `wasm-ld` removed `foo:unused`.
+		js_store
+			.add_import(Import {
+				module: "foo",
+				name: "used",
+				ty: TypeRef::Func(0),
+			})
+			.unwrap();
+		js_store.assert_expected().unwrap();
+
+		let output = js_store.into_output(
+			MainMemory {
+				module: "env",
+				name: "memory",
+			},
+			String::new(),
+			false,
+			Vec::new(),
+		);
+
+		assert_eq!(output.js_import["foo"].keys().collect::<Vec<_>>(), ["used"]);
+		assert_eq!(output.js_embed["bar"].keys().collect::<Vec<_>>(), ["a"]);
+	}
+
+	#[test]
+	fn multiple_versions() {
+		let mut js_store = JsStore::default();
+
+		// Two semver-incompatible versions of the same crate.
+		for (namespace, origin) in [("js_sys_1", "js_sys-1.o"), ("js_sys_2", "js_sys-2.o")] {
+			let origin: Rc<Path> = Rc::from(Path::new(origin));
+			let js = format!("this.#jsEmbed.{namespace}['externref.table']");
+
+			custom_section(
+				"js_bindgen.import",
+				&[(
+					namespace,
+					"externref.table",
+					&[(namespace, "externref.table")],
+					&js,
+				)],
+				|c| js_store.add_js_imports(c, &origin).unwrap(),
+			);
+			custom_section(
+				"js_bindgen.embed",
+				&[(namespace, "externref.table", &[], "new WebAssembly.Table()")],
+				|c| js_store.add_js_embeds(c, &origin).unwrap(),
+			);
+			js_store
+				.add_import(Import {
+					module: namespace,
+					name: "externref.table",
+					ty: TypeRef::Func(0),
+				})
+				.unwrap();
+		}
+
+		js_store.assert_expected().unwrap();
+
+		let output = js_store.into_output(
+			MainMemory {
+				module: "env",
+				name: "memory",
+			},
+			String::new(),
+			false,
+			Vec::new(),
+		);
+
+		for namespace in ["js_sys_1", "js_sys_2"] {
+			assert_eq!(
+				output.js_import[namespace]["externref.table"].js,
+				format!("this.#jsEmbed.{namespace}['externref.table']")
+			);
+			assert!(output.js_embed[namespace].contains_key("externref.table"));
+		}
+	}
+
+	#[test]
+	fn unsupported_format() {
+		let mut js_store = JsStore::default();
+		let origin: Rc<Path> = Rc::from(Path::new("test.o"));
+
+		versioned_custom_section(
+			Some(FORMAT_VERSION + 1),
+			"js_bindgen.import",
+			&[("foo", "bar", &[], "() => 1")],
+			|c| {
+				let error = js_store.add_js_imports(c, &origin).unwrap_err();
+				assert_eq!(
+					error.to_string(),
+					format!(
+						"found custom section `js_bindgen.import` with format version {}, \
+						 expected version {FORMAT_VERSION}: it was built with an incompatible \
+						 version of `js-bindgen`",
+						FORMAT_VERSION + 1
+					)
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn unversioned_format() {
+		let mut js_store = JsStore::default();
+		let origin: Rc<Path> = Rc::from(Path::new("test.o"));
+
+		versioned_custom_section(
+			None,
+			"js_bindgen.import",
+			&[("foo", "bar", &[], "() => 1")],
+			|c| {
+				let error = js_store.add_js_imports(c, &origin).unwrap_err();
+				assert_eq!(
+					error.to_string(),
+					format!(
+						"found custom section `js_bindgen.import` with an unversioned format, \
+						 expected version {FORMAT_VERSION}: it was built with an incompatible \
+						 version of `js-bindgen`"
+					)
+				);
+			},
+		);
+	}
+}
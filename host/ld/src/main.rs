@@ -1,7 +1,11 @@
 mod args;
+mod direct;
+mod exports;
 mod js;
 mod post;
 mod pre;
+mod report;
+mod wat;
 
 use std::process::{self, Command};
 use std::{env, fs};
@@ -22,7 +26,9 @@ fn main() {
 		output_path,
 		main_memory,
 		js_store,
+		mut size_report,
 		is_test,
+		threads,
 	} = pre::processing(&args);
 
 	let status = Command::new("rust-lld")
@@ -34,8 +40,16 @@ fn main() {
 	if status.success() {
 		let wasm_input = ReadFile::new(output_path).expect("output file should be readable");
 
-		let wasm_output =
-			post::processing(&wasm_input, main_memory, js_store, args.web(), is_test).unwrap();
+		let wasm_output = post::processing(
+			&wasm_input,
+			main_memory,
+			js_store,
+			size_report.as_mut(),
+			&args,
+			is_test,
+			threads,
+		)
+		.unwrap();
 		drop(wasm_input);
 
 		// We could write into the file directly, but `wasm-encoder` doesn't support
@@ -44,6 +58,11 @@ fn main() {
 		// When it does, we should rename the old file and write to a new file. This way
 		// we can keep parsing and writing at the same time without allocating memory.
 		fs::write(output_path, wasm_output).expect("output Wasm file should be writable");
+
+		if let Some((size_report, path)) = size_report.zip(args.size_report()) {
+			let size_report = size_report.into_json().unwrap();
+			fs::write(path, size_report).expect("size report should be writable");
+		}
 	}
 
 	if !status.success() {
@@ -1,28 +1,55 @@
+use std::env;
+
 use anyhow::{Context, Result, bail};
 use js_bindgen_cli_lib::{JS_OUTPUT_SECTION, MainMemory};
 use js_bindgen_shared::{IS_COMPAT_SECTION, IS_TEST_SECTION};
 use wasm_encoder::{
-	CustomSection, EntityType, ImportSection, Module, ProducersField, ProducersSection, RawSection,
-	Section,
+	CustomSection, EntityType, ExportKind, ExportSection, ImportSection, Module, ProducersField,
+	ProducersSection, RawSection, Section, SectionId,
 };
 use wasmparser::{Encoding, KnownCustom, Parser, Payload, TypeRef};
 
+use crate::args::Arguments;
+use crate::direct::DirectImports;
+use crate::exports::ExportParser;
 use crate::js::JsStore;
+use crate::report::SizeReport;
+
+/// Export of the start function in modules with shared memory. Must match
+/// `imports.mts`.
+const START_EXPORT: &str = "__js_bindgen_start";
 
 /// This removes our custom sections and generates the JS import file.
 pub fn processing(
 	wasm_input: &[u8],
 	main_memory: MainMemory<'_>,
 	mut js_store: JsStore,
-	web: bool,
+	size_report: Option<&mut SizeReport>,
+	args: &Arguments<'_>,
 	is_test: bool,
+	threads: bool,
 ) -> Result<Vec<u8>> {
 	// Start building final Wasm and JS.
 	let mut wasm_output = Vec::new();
+	let mut export_parser = ExportParser::default();
+	let mut direct_imports = (!args.no_direct_imports()).then(DirectImports::default);
+	// Workers have to set up their stack before any code runs, so the start
+	// function is exported and called by the JS glue instead.
+	let mut start = if threads {
+		start_function(wasm_input)?
+	} else {
+		None
+	};
 
 	for payload in Parser::new(0).parse_all(wasm_input) {
 		let payload = payload.context("object file should be valid Wasm")?;
 
+		export_parser.parse(&payload)?;
+
+		if let Some(direct_imports) = &mut direct_imports {
+			direct_imports.parse(&payload)?;
+		}
+
 		match payload {
 			Payload::Version { encoding, .. } => wasm_output.extend_from_slice(match encoding {
 				Encoding::Module => &Module::HEADER,
@@ -57,6 +84,25 @@ pub fn processing(
 
 				import_section.append_to(&mut wasm_output);
 			}
+			Payload::ExportSection(e) if start.is_some() => {
+				let mut export_section = ExportSection::new();
+
+				for export in e {
+					let export = export.context("export should be parsable")?;
+					export_section.export(export.name, export.kind.into(), export.index);
+				}
+
+				export_section.export(START_EXPORT, ExportKind::Func, start.take().unwrap());
+				export_section.append_to(&mut wasm_output);
+			}
+			Payload::StartSection { func, .. } if threads => {
+				// No export section was present.
+				if start.take().is_some() {
+					let mut export_section = ExportSection::new();
+					export_section.export(START_EXPORT, ExportKind::Func, func);
+					export_section.append_to(&mut wasm_output);
+				}
+			}
 			// Don't write back our own custom sections.
 			Payload::CustomSection(c) if c.name() == "js_bindgen.wat" => (),
 			Payload::CustomSection(c) if c.name() == "js_bindgen.import" => (),
@@ -87,23 +133,34 @@ pub fn processing(
 
 				section.append_to(&mut wasm_output);
 			}
-			Payload::CodeSectionEntry(_) | Payload::End(_) => (),
-			payload => {
-				let (id, range) = payload
-					.as_section()
-					.with_context(|| format!("expected parsable Wasm payload:\n{payload:?}"))?;
+			Payload::CodeSectionStart { range, .. } => {
+				let data = &wasm_input[range.clone()];
+				let patched = if let Some(direct_imports) = &direct_imports {
+					direct_imports.code_section(data, range.start)?
+				} else {
+					None
+				};
+
 				RawSection {
-					id,
-					data: &wasm_input[range],
+					id: SectionId::Code.into(),
+					data: patched.as_deref().unwrap_or(data),
 				}
 				.append_to(&mut wasm_output);
 			}
+			Payload::CodeSectionEntry(_) | Payload::End(_) => (),
+			payload => {
+				js_bindgen_cli_lib::raw_section(wasm_input, &payload)?.append_to(&mut wasm_output);
+			}
 		}
 	}
 
 	js_store.assert_expected()?;
 
-	if !web {
+	if let Some(size_report) = size_report {
+		js_store.size_report(size_report);
+	}
+
+	if !args.web() {
 		CustomSection {
 			name: IS_COMPAT_SECTION.into(),
 			data: (&[]).into(),
@@ -119,7 +176,18 @@ pub fn processing(
 		.append_to(&mut wasm_output);
 	}
 
-	let output = js_store.into_output(main_memory);
+	// `file!()` paths are relative to the directory `rustc` was invoked in, which
+	// invokes us.
+	let source_root = env::current_dir()
+		.context("failed to get the current directory")?
+		.to_string_lossy()
+		.into_owned();
+	let output = js_store.into_output(
+		main_memory,
+		source_root,
+		threads,
+		export_parser.into_exports(),
+	);
 	let data = postcard::to_allocvec(&output)?;
 
 	CustomSection {
@@ -130,3 +198,149 @@ pub fn processing(
 
 	Ok(wasm_output)
 }
+
+fn start_function(wasm: &[u8]) -> Result<Option<u32>> {
+	for payload in Parser::new(0).parse_all(wasm) {
+		if let Payload::StartSection { func, .. } = payload? {
+			return Ok(Some(func));
+		}
+	}
+
+	Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+	use js_bindgen_cli_lib::MainMemory;
+	use wasm_encoder::{
+		CodeSection, CustomSection, ExportKind, ExportSection, Function, FunctionSection, Module,
+		NameSection, StartSection, TypeSection,
+	};
+	use wasmparser::{Parser, Payload};
+
+	use crate::args::Arguments;
+	use crate::js::JsStore;
+
+	fn custom_sections(wasm: &[u8]) -> Vec<(String, Vec<u8>)> {
+		Parser::new(0)
+			.parse_all(wasm)
+			.filter_map(|payload| match payload.unwrap() {
+				Payload::CustomSection(c) => Some((c.name().to_owned(), c.data().to_owned())),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn debug_info() {
+		let mut names = NameSection::new();
+		names.module("test");
+
+		let mut module = Module::new();
+		module.section(&CustomSection {
+			name: ".debug_info".into(),
+			data: (&[1, 2, 3]).into(),
+		});
+		module.section(&CustomSection {
+			name: ".debug_line".into(),
+			data: (&[4, 5, 6]).into(),
+		});
+		module.section(&names);
+		let wasm_input = module.finish();
+
+		let wasm_output = super::processing(
+			&wasm_input,
+			MainMemory {
+				module: "env",
+				name: "memory",
+			},
+			JsStore::default(),
+			None,
+			&Arguments::new(&["--web".into()]),
+			false,
+			false,
+		)
+		.unwrap();
+
+		let output = custom_sections(&wasm_output);
+
+		for section in custom_sections(&wasm_input) {
+			assert!(output.contains(&section), "missing `{}`", section.0);
+		}
+	}
+
+	#[test]
+	fn start_export() {
+		let mut types = TypeSection::new();
+		types.ty().function([], []);
+		let mut functions = FunctionSection::new();
+		functions.function(0);
+		functions.function(0);
+		let mut exports = ExportSection::new();
+		exports.export("main", ExportKind::Func, 1);
+		let mut function = Function::new([]);
+		function.instructions().end();
+		let mut code = CodeSection::new();
+		code.function(&function).function(&function);
+
+		let mut module = Module::new();
+		module
+			.section(&types)
+			.section(&functions)
+			.section(&exports)
+			.section(&StartSection { function_index: 0 })
+			.section(&code);
+		let wasm_input = module.finish();
+
+		let processing = |threads| {
+			super::processing(
+				&wasm_input,
+				MainMemory {
+					module: "env",
+					name: "memory",
+				},
+				JsStore::default(),
+				None,
+				&Arguments::new(&["--web".into()]),
+				false,
+				threads,
+			)
+			.unwrap()
+		};
+
+		let start = |wasm: &[u8]| {
+			let mut start = None;
+			let mut exports = Vec::new();
+
+			for payload in Parser::new(0).parse_all(wasm) {
+				match payload.unwrap() {
+					Payload::StartSection { func, .. } => start = Some(func),
+					Payload::ExportSection(section) => {
+						for export in section {
+							let export = export.unwrap();
+							exports.push((export.name.to_owned(), export.index));
+						}
+					}
+					_ => (),
+				}
+			}
+
+			(start, exports)
+		};
+
+		assert_eq!(
+			start(&processing(false)),
+			(Some(0), vec![(String::from("main"), 1)])
+		);
+		assert_eq!(
+			start(&processing(true)),
+			(
+				None,
+				vec![
+					(String::from("main"), 1),
+					(String::from(super::START_EXPORT), 0)
+				]
+			)
+		);
+	}
+}
@@ -1,22 +1,27 @@
 use std::ffi::{OsStr, OsString};
-use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::time::SystemTime;
+use std::{fs, process};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use js_bindgen_cli_lib::MainMemory;
 use js_bindgen_ld_shared::JsBindgenWatSectionParser;
 use wasmparser::{Parser, Payload};
 
 use crate::args::Arguments;
 use crate::js::JsStore;
+use crate::report::SizeReport;
+use crate::wat;
 
 pub struct PreOutput<'args> {
 	pub add_args: Vec<OsString>,
 	pub output_path: &'args Path,
 	pub main_memory: MainMemory<'args>,
 	pub js_store: JsStore,
+	pub size_report: Option<SizeReport>,
 	pub is_test: bool,
+	pub threads: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -56,7 +61,17 @@ pub fn processing<'a>(args: &'a Arguments<'a>) -> PreOutput<'a> {
 	// with our own path.
 	let main_memory = main_memory(arch, args, &mut add_args);
 
+	// Workers need to set up their own stack and TLS.
+	let threads = args.arg_flag("shared-memory");
+
+	if threads {
+		for symbol in ["__stack_pointer", "__tls_size", "__tls_align"] {
+			add_args.push(OsString::from(format!("--export-if-defined={symbol}")));
+		}
+	}
+
 	let mut js_store = JsStore::default();
+	let mut size_report = args.size_report().map(|_| SizeReport::default());
 	let mut is_test = false;
 
 	// Extract embedded WAT from object files.
@@ -65,18 +80,18 @@ pub fn processing<'a>(args: &'a Arguments<'a>) -> PreOutput<'a> {
 			is_test |= is_libtest(input);
 		}
 
-		js_bindgen_ld_shared::ld_input_parser(input, |path, data, object_mtime| {
-			process_object(
-				&mut js_store,
-				matches!(arch, Arch::Wasm64),
-				&mut add_args,
-				path,
-				data,
-				object_mtime,
-			)
-		})
-		.unwrap()
-		.unwrap();
+		process_input(
+			&mut js_store,
+			size_report.as_mut(),
+			matches!(arch, Arch::Wasm64),
+			&mut add_args,
+			input,
+		)
+		.with_context(|| format!("failed to process `{}`", Path::new(input).display()))
+		.unwrap_or_else(|error| {
+			eprintln!("error: {error:#}");
+			process::exit(1);
+		});
 	}
 
 	PreOutput {
@@ -84,7 +99,9 @@ pub fn processing<'a>(args: &'a Arguments<'a>) -> PreOutput<'a> {
 		output_path,
 		main_memory,
 		js_store,
+		size_report,
 		is_test,
+		threads,
 	}
 }
 
@@ -95,19 +112,72 @@ fn is_libtest(input: &OsStr) -> bool {
 		.is_some_and(|name| name.starts_with("libtest-"))
 }
 
-/// Extracts any WAT instructions from `js-bindgen`, builds object files from
-/// them and passes them to the linker.
-fn process_object(
+/// Extracts any WAT instructions from `js-bindgen` in all objects of an input
+/// file, builds a single object file from them and passes it to the linker.
+fn process_input(
 	js_store: &mut JsStore,
+	mut size_report: Option<&mut SizeReport>,
 	wasm64: bool,
 	add_args: &mut Vec<OsString>,
-	archive_path: &Path,
-	object: &[u8],
-	object_mtime: Option<SystemTime>,
+	input: &OsStr,
 ) -> Result<()> {
-	// Multiple files from the same object file need different names.
-	let mut file_counter = 0;
+	let input = Path::new(input);
+	// JS and WAT of all objects are reported with the input file as their origin.
+	let origin: Rc<Path> = Rc::from(input);
+	let mut wats = Vec::new();
+	let mut input_mtime = None;
+
+	js_bindgen_ld_shared::ld_input_parser(input.as_os_str(), |_, object, object_mtime| {
+		input_mtime = object_mtime;
+		process_object(js_store, &mut wats, &origin, object)
+	})
+	.unwrap()?;
+
+	if wats.is_empty() {
+		return Ok(());
+	}
+
+	let wasm_path = input.with_added_extension("wasm.o");
+
+	if !is_outdated(&wasm_path, input_mtime)? {
+		return add_wat_object(size_report, add_args, input, wasm_path, None);
+	}
+
+	let wasm = wat::merge(wats.iter().map(String::as_str))
+		.ok()
+		.and_then(|wat| js_bindgen_ld_shared::wat_to_object(wasm64, &wat).ok());
+
+	if let Some(wasm) = wasm {
+		fs::write(&wasm_path, &wasm)?;
+		return add_wat_object(size_report, add_args, input, wasm_path, Some(wasm));
+	}
+
+	// Identifiers of separate `unsafe_global_wat!` calls can conflict when merged.
+	// Fall back to one object file per call, which also reports any errors.
+	for (index, wat) in wats.iter().enumerate() {
+		let wasm_path = input.with_added_extension(format!("wasm.{}.o", index + 1));
+
+		let wasm = if is_outdated(&wasm_path, input_mtime)? {
+			let wasm = js_bindgen_ld_shared::wat_to_object(wasm64, wat)?;
+			fs::write(&wasm_path, &wasm)?;
+			Some(wasm)
+		} else {
+			None
+		};
+
+		add_wat_object(size_report.as_deref_mut(), add_args, input, wasm_path, wasm)?;
+	}
 
+	Ok(())
+}
+
+/// Extracts JS and collects WAT from `js-bindgen` custom sections.
+fn process_object(
+	js_store: &mut JsStore,
+	wats: &mut Vec<String>,
+	origin: &Rc<Path>,
+	object: &[u8],
+) -> Result<()> {
 	for payload in Parser::new(0).parse_all(object) {
 		let payload = match payload {
 			Ok(payload) => payload,
@@ -120,36 +190,15 @@ fn process_object(
 		// We are only interested in reading custom sections with our name.
 		match &payload {
 			Payload::CustomSection(c) if c.name() == "js_bindgen.wat" => {
-				for wat in JsBindgenWatSectionParser::new(c) {
-					file_counter += 1;
-					let wasm_path =
-						archive_path.with_added_extension(format!("wasm.{file_counter}.o"));
-
-					// We first use a fingerprint to quickly determine whether `wasm.o` needs to be
-					// regenerated: https://doc.rust-lang.org/1.92.0/nightly-rustc/cargo/core/compiler/fingerprint/index.html#fingerprints-and-unithashs
-					//
-					// Then we compare the `mtime` of the `.o` files with that of `wasm.o`. If it is
-					// `None`(should not occur on major platforms), or if the `.o` files are
-					// newer than `wasm.o`, we regenerate `wasm.o`.
-					if !wasm_path.exists() || {
-						js_bindgen_shared::mtime(&std::fs::metadata(&wasm_path)?)?
-							.zip(object_mtime)
-							.is_none_or(|(t1, t2)| t1 < t2)
-					} {
-						let wasm = js_bindgen_ld_shared::wat_to_object(wasm64, wat)?;
-						fs::write(&wasm_path, wasm)?;
-					}
-
-					add_args.push(wasm_path.into());
-				}
+				wats.extend(JsBindgenWatSectionParser::new(c)?.map(str::to_owned));
 			}
 			// Extract all JS imports.
 			Payload::CustomSection(c) if c.name() == "js_bindgen.import" => {
-				js_store.add_js_imports(c)?;
+				js_store.add_js_imports(c, origin)?;
 			}
 			// Extract all JS embeds.
 			Payload::CustomSection(c) if c.name() == "js_bindgen.embed" => {
-				js_store.add_js_embeds(c)?;
+				js_store.add_js_embeds(c, origin)?;
 			}
 			_ => (),
 		}
@@ -158,6 +207,41 @@ fn process_object(
 	Ok(())
 }
 
+/// We first use a fingerprint to quickly determine whether `wasm.o` needs to be
+/// regenerated: <https://doc.rust-lang.org/1.92.0/nightly-rustc/cargo/core/compiler/fingerprint/index.html#fingerprints-and-unithashs>
+///
+/// Then we compare the `mtime` of the input file with that of `wasm.o`. If it
+/// is `None`(should not occur on major platforms), or if the input file is
+/// newer than `wasm.o`, we regenerate `wasm.o`.
+fn is_outdated(wasm_path: &Path, input_mtime: Option<SystemTime>) -> Result<bool> {
+	Ok(!wasm_path.exists()
+		|| js_bindgen_shared::mtime(&fs::metadata(wasm_path)?)?
+			.zip(input_mtime)
+			.is_none_or(|(t1, t2)| t1 < t2))
+}
+
+/// Passes a generated object file to the linker. `wasm` is [`None`] if it was
+/// not regenerated.
+fn add_wat_object(
+	size_report: Option<&mut SizeReport>,
+	add_args: &mut Vec<OsString>,
+	input: &Path,
+	wasm_path: PathBuf,
+	wasm: Option<Vec<u8>>,
+) -> Result<()> {
+	if let Some(size_report) = size_report {
+		let wasm = match wasm {
+			Some(wasm) => wasm,
+			None => fs::read(&wasm_path)?,
+		};
+		size_report.add_wat(input, &wasm_path, &wasm)?;
+	}
+
+	add_args.push(wasm_path.into());
+
+	Ok(())
+}
+
 fn main_memory<'args>(
 	arch: Arch,
 	wasm_ld_args: &Arguments<'args>,
@@ -0,0 +1,87 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+use wasmparser::{Parser, Payload};
+
+/// Sizes of everything `js-bindgen` contributes to the output, written with
+/// `--size-report=<path>`.
+#[derive(Default, Serialize)]
+pub struct SizeReport {
+	/// JS imports included in the JS glue.
+	js_import: Vec<JsSize>,
+	/// JS embeds included in the JS glue.
+	js_embed: Vec<JsSize>,
+	/// Objects generated from `unsafe_global_wat!` and friends.
+	wat: Vec<WatSize>,
+}
+
+#[derive(Serialize)]
+struct JsSize {
+	module: String,
+	name: String,
+	/// Object file the JS was found in.
+	origin: String,
+	/// Size of the JS in bytes, before indentation.
+	size: usize,
+}
+
+#[derive(Serialize)]
+struct WatSize {
+	/// Archive or object file the WAT was found in.
+	origin: String,
+	/// Object file generated from the WAT.
+	object: String,
+	/// Size of the code section in bytes.
+	code_size: u32,
+}
+
+impl SizeReport {
+	pub fn add_js_import(&mut self, module: &str, name: &str, origin: &Path, js: &str) {
+		self.js_import.push(JsSize::new(module, name, origin, js));
+	}
+
+	pub fn add_js_embed(&mut self, module: &str, name: &str, origin: &Path, js: &str) {
+		self.js_embed.push(JsSize::new(module, name, origin, js));
+	}
+
+	pub fn add_wat(&mut self, origin: &Path, object_path: &Path, object: &[u8]) -> Result<()> {
+		let mut code_size = 0;
+
+		for payload in Parser::new(0).parse_all(object) {
+			if let Payload::CodeSectionStart { size, .. } = payload? {
+				code_size = size;
+			}
+		}
+
+		self.wat.push(WatSize {
+			origin: origin.display().to_string(),
+			object: object_path.display().to_string(),
+			code_size,
+		});
+
+		Ok(())
+	}
+
+	/// Sorts all entries to keep the report stable between builds.
+	pub fn into_json(mut self) -> Result<Vec<u8>> {
+		self.js_import
+			.sort_unstable_by(|a, b| (&a.module, &a.name).cmp(&(&b.module, &b.name)));
+		self.js_embed
+			.sort_unstable_by(|a, b| (&a.module, &a.name).cmp(&(&b.module, &b.name)));
+		self.wat.sort_unstable_by(|a, b| a.object.cmp(&b.object));
+
+		Ok(serde_json::to_vec_pretty(&self)?)
+	}
+}
+
+impl JsSize {
+	fn new(module: &str, name: &str, origin: &Path, js: &str) -> Self {
+		Self {
+			module: module.to_owned(),
+			name: name.to_owned(),
+			origin: origin.display().to_string(),
+			size: js.len(),
+		}
+	}
+}
@@ -0,0 +1,270 @@
+use std::mem;
+
+use anyhow::{Result, bail};
+use hashbrown::HashSet;
+
+/// Merges the WAT of all `unsafe_global_wat!` calls found in an input file
+/// into a single module, so only one object has to be built and linked.
+///
+/// Identical module fields, like imports shared between calls, are only
+/// included once. Function imports are dropped if the module defines a
+/// function with the same identifier, calls are then resolved inside the
+/// module instead of by the linker.
+pub fn merge<'wat>(wats: impl IntoIterator<Item = &'wat str>) -> Result<String> {
+	let mut fields = Vec::new();
+	let mut seen = HashSet::new();
+	let mut defined = HashSet::new();
+
+	for wat in wats {
+		for field in fields_of(wat)? {
+			if let ["(", "func", id, ..] = field.as_slice()
+				&& id.starts_with('$')
+			{
+				defined.insert(*id);
+			}
+
+			let text = text(&field);
+
+			if seen.insert(text.clone()) {
+				fields.push((imported_function(&field), text));
+			}
+		}
+	}
+
+	let mut output = String::new();
+
+	for (import, text) in fields {
+		if import.is_none_or(|id| !defined.contains(id)) {
+			output.push_str(&text);
+			output.push('\n');
+		}
+	}
+
+	Ok(output)
+}
+
+/// Splits `wat` into the tokens of each module field, skipping comments.
+fn fields_of(wat: &str) -> Result<Vec<Vec<&str>>> {
+	let mut fields = Vec::new();
+	let mut field = Vec::new();
+	let mut depth = 0_usize;
+	let mut rest = wat;
+
+	while let Some(char) = rest.chars().next() {
+		let len = if char.is_whitespace() {
+			rest = &rest[char.len_utf8()..];
+			continue;
+		} else if rest.starts_with(";;") {
+			rest = &rest[rest.find('\n').unwrap_or(rest.len())..];
+			continue;
+		} else if rest.starts_with("(;") {
+			rest = &rest[block_comment_len(rest)?..];
+			continue;
+		} else if char == '"' {
+			string_len(rest)?
+		} else if char == '(' || char == ')' {
+			1
+		} else {
+			rest.find(|char: char| char.is_whitespace() || matches!(char, '(' | ')' | '"'))
+				.unwrap_or(rest.len())
+		};
+
+		let (token, next) = rest.split_at(len);
+		rest = next;
+
+		match token {
+			"(" => depth += 1,
+			")" if depth == 0 => bail!("found unbalanced `)` in WAT"),
+			")" => depth -= 1,
+			_ if depth == 0 => bail!("found `{token}` outside of a module field in WAT"),
+			_ => (),
+		}
+
+		field.push(token);
+
+		if depth == 0 {
+			fields.push(mem::take(&mut field));
+		}
+	}
+
+	if depth != 0 {
+		bail!("found unbalanced `(` in WAT");
+	}
+
+	Ok(fields)
+}
+
+fn block_comment_len(wat: &str) -> Result<usize> {
+	let mut depth = 0_usize;
+	let mut index = 0;
+
+	while index < wat.len() {
+		if wat[index..].starts_with("(;") {
+			depth += 1;
+			index += 2;
+		} else if wat[index..].starts_with(";)") {
+			depth -= 1;
+			index += 2;
+
+			if depth == 0 {
+				return Ok(index);
+			}
+		} else {
+			index += wat[index..].chars().next().map_or(1, char::len_utf8);
+		}
+	}
+
+	bail!("found unterminated block comment in WAT")
+}
+
+fn string_len(wat: &str) -> Result<usize> {
+	let mut chars = wat.char_indices().skip(1);
+
+	while let Some((index, char)) = chars.next() {
+		match char {
+			'\\' => {
+				chars.next();
+			}
+			'"' => return Ok(index + 1),
+			_ => (),
+		}
+	}
+
+	bail!("found unterminated string in WAT")
+}
+
+/// Joins tokens with the least amount of whitespace. Annotations like
+/// `(@sym)` don't allow whitespace after the parenthesis.
+fn text(tokens: &[&str]) -> String {
+	let mut text = String::new();
+
+	for token in tokens {
+		if !text.is_empty() && !text.ends_with('(') && *token != ")" {
+			text.push(' ');
+		}
+
+		text.push_str(token);
+	}
+
+	text
+}
+
+/// Returns the identifier of an imported function.
+fn imported_function<'wat>(field: &[&'wat str]) -> Option<&'wat str> {
+	if let ["(", "import", _, _, "(", "func", id, ..] = field
+		&& id.starts_with('$')
+	{
+		Some(id)
+	} else {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::process::Command;
+	use std::time::{Duration, Instant};
+	use std::{env, fs, slice};
+
+	use super::merge;
+
+	#[test]
+	fn merge_fields() {
+		let merged = merge([
+			"(import \"env\" \"a\" (func $a (@sym) (param i32)))\n(func $b (@sym) (param $x \
+			 i32)\n  local.get $x ;; Comment.\n  call $a (@reloc)\n)",
+			"(import  \"env\" \"a\"\n (func $a (@sym) (param i32)))",
+			"(; Block (; nested ;) comment. ;)(import \"env\" \"b\" (func $b (@sym) (param i32)))",
+			"(import \"js\" \"c\" (func $c (@sym (name \"c \\\" )\")) (param i32)))",
+		])
+		.unwrap();
+
+		assert_eq!(
+			merged,
+			"(import \"env\" \"a\" (func $a (@sym) (param i32)))\n(func $b (@sym) (param $x i32) \
+			 local.get $x call $a (@reloc))\n(import \"js\" \"c\" (func $c (@sym (name \"c \\\" \
+			 )\")) (param i32)))\n"
+		);
+	}
+
+	#[test]
+	fn unbalanced() {
+		assert!(merge(["(func $a"]).is_err());
+		assert!(merge(["(func $a))"]).is_err());
+		assert!(merge(["func $a"]).is_err());
+	}
+
+	/// Compares linking one object per `unsafe_global_wat!` call with linking a
+	/// single merged object, for a synthetic input with 4000 bindings.
+	///
+	/// Run with `cargo test -p js-bindgen-ld link_bench -- --ignored
+	/// --nocapture`. Requires `rust-lld` in `PATH`.
+	#[test]
+	#[ignore = "benchmark"]
+	fn link_bench() {
+		const BINDINGS: usize = 4000;
+		const RUNS: usize = 7;
+
+		let wats: Vec<_> = (0..BINDINGS)
+			.map(|index| {
+				format!(
+					"(import \"bench\" \"f{index}\" (func $bench.import.f{index} (@sym (name \
+					 \"bench.import.f{index}\")) (param i32) (result i32)))\n(func \
+					 $bench.f{index} (@sym) (param $value i32) (result i32)\n  local.get $value\n  \
+					 call $bench.import.f{index} (@reloc)\n)"
+				)
+			})
+			.collect();
+
+		let dir = env::temp_dir().join("js-bindgen-ld-link-bench");
+		// The directory only exists if a previous run was interrupted.
+		let _ = fs::remove_dir_all(&dir);
+		fs::create_dir_all(&dir).unwrap();
+
+		let split: Vec<_> = wats
+			.iter()
+			.enumerate()
+			.map(|(index, wat)| {
+				let path = dir.join(format!("wasm.{}.o", index + 1));
+				let wasm = js_bindgen_ld_shared::wat_to_object(false, wat).unwrap();
+				fs::write(&path, wasm).unwrap();
+				path
+			})
+			.collect();
+
+		let merged = dir.join("wasm.o");
+		let wat = merge(wats.iter().map(String::as_str)).unwrap();
+		let wasm = js_bindgen_ld_shared::wat_to_object(false, &wat).unwrap();
+		fs::write(&merged, wasm).unwrap();
+
+		for (name, objects) in [
+			("split", split.as_slice()),
+			("merged", slice::from_ref(&merged)),
+		] {
+			let mut times: Vec<Duration> = (0..RUNS)
+				.map(|_| {
+					let start = Instant::now();
+					let status = Command::new("rust-lld")
+						.args(["-flavor", "wasm", "--no-entry", "--export-all"])
+						.arg("--allow-undefined")
+						.args(objects)
+						.arg("-o")
+						.arg(dir.join(format!("{name}.wasm")))
+						.status()
+						.unwrap();
+					assert!(status.success(), "`rust-lld` failed");
+					start.elapsed()
+				})
+				.collect();
+			times.sort_unstable();
+
+			println!(
+				"{name}: {} objects, median {:.3}s",
+				objects.len(),
+				times[RUNS / 2].as_secs_f64()
+			);
+		}
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+}
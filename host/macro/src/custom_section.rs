@@ -8,6 +8,11 @@ use proc_macro2 as proc_macro;
 
 use crate::path;
 
+/// Has to be kept in sync with `js_bindgen_ld_shared::FORMAT_VERSION`.
+const FORMAT_VERSION: u16 = 1;
+/// Has to be kept in sync with `js_bindgen_ld_shared::VERSION_MARKER`.
+const VERSION_MARKER: u8 = 0;
+
 pub struct CustomSection {
 	named_values: Vec<NamedValue>,
 	tuple_values: Vec<TupleValue>,
@@ -60,12 +65,19 @@ enum Bytes {
 }
 
 impl CustomSection {
+	/// Every entry starts with [`VERSION_MARKER`] and [`FORMAT_VERSION`],
+	/// letting the linker detect objects built with an incompatible
+	/// `js-bindgen`.
 	pub fn new() -> Self {
-		Self {
+		let mut custom_section = Self {
 			named_values: Vec::new(),
 			tuple_values: Vec::new(),
 			values: Vec::new(),
-		}
+		};
+		custom_section.byte_value(None, VERSION_MARKER);
+		custom_section.bytes_value(None, FORMAT_VERSION.to_le_bytes());
+
+		custom_section
 	}
 
 	pub fn byte_value(&mut self, cfg: Option<[TokenTree; 2]>, byte: u8) {
@@ -1031,3 +1043,12 @@ where
 fn ident(string: &str) -> TokenTree {
 	Ident::new(string, Span::mixed_site()).into()
 }
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn format_version() {
+		assert_eq!(super::FORMAT_VERSION, js_bindgen_ld_shared::FORMAT_VERSION);
+		assert_eq!(super::VERSION_MARKER, js_bindgen_ld_shared::VERSION_MARKER);
+	}
+}
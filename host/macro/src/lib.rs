@@ -5,9 +5,12 @@ mod custom_section;
 mod tests;
 mod util;
 
+use std::env;
 use std::iter::Peekable;
 
-use proc_macro::{Span, TokenStream, TokenTree, token_stream};
+use proc_macro::{
+	Delimiter, Group, Literal, Punct, Spacing, Span, TokenStream, TokenTree, token_stream,
+};
 #[cfg(test)]
 use proc_macro2 as proc_macro;
 use util::*;
@@ -68,6 +71,7 @@ fn js_internal(input: TokenStream, section: &str) -> Result<TokenStream, TokenSt
 
 	expect_js_path(&mut input, "module", &mut custom_section)?;
 	expect_js_path(&mut input, "name", &mut custom_section)?;
+	location(&mut custom_section);
 
 	parse_required_embeds(&mut input, &mut custom_section)?;
 	parse_string_arguments(&mut input, Span::mixed_site(), &mut custom_section)?;
@@ -75,6 +79,78 @@ fn js_internal(input: TokenStream, section: &str) -> Result<TokenStream, TokenSt
 	Ok(custom_section.output(section))
 }
 
+#[proc_macro]
+pub fn namespace(input: ::proc_macro::TokenStream) -> ::proc_macro::TokenStream {
+	#[cfg_attr(
+		not(test),
+		expect(clippy::useless_conversion, reason = "`proc-macro2` compatibility")
+	)]
+	namespace_internal(input.into())
+		.unwrap_or_else(|e| e)
+		.into()
+}
+
+fn namespace_internal(input: TokenStream) -> Result<TokenStream, TokenStream> {
+	if let Some(token) = input.into_iter().next() {
+		return Err(compile_error(token.span(), "expected no arguments"));
+	}
+
+	// Cargo sets these for every `rustc` invocation, so they describe the crate
+	// calling us.
+	let var = |name| {
+		env::var(name).map_err(|_| {
+			compile_error(
+				Span::call_site(),
+				format!("`{name}` not found, `namespace!()` requires building with Cargo"),
+			)
+		})
+	};
+	let namespace = namespace_from(
+		&var("CARGO_CRATE_NAME")?,
+		&var("CARGO_PKG_VERSION_MAJOR")?,
+		&var("CARGO_PKG_VERSION_MINOR")?,
+		&var("CARGO_PKG_VERSION_PATCH")?,
+	);
+
+	Ok(TokenTree::from(Literal::string(&namespace)).into())
+}
+
+/// Only includes the semver-compatible part of the version, so compatible
+/// versions share a namespace and are deduplicated when linking. Every `0.0.x`
+/// release is incompatible with every other.
+fn namespace_from(crate_: &str, major: &str, minor: &str, patch: &str) -> String {
+	match (major, minor) {
+		("0", "0") => format!("{crate_}_0_0_{patch}"),
+		("0", _) => format!("{crate_}_0_{minor}"),
+		_ => format!("{crate_}_{major}"),
+	}
+}
+
+/// Stores `<file>:<line>` of the macro call, used to generate source maps.
+fn location(custom_section: &mut CustomSection) {
+	let span = Span::mixed_site();
+	let r#macro = |name, args: Vec<TokenTree>| {
+		path(["core", name], span).chain([
+			Punct::new('!', Spacing::Alone).into(),
+			Group::new(Delimiter::Parenthesis, args.into_iter().collect()).into(),
+		])
+	};
+
+	// ```
+	// ::core::concat!(::core::file!(), ":", ::core::line!())
+	// ```
+	let args = r#macro("file", Vec::new())
+		.chain([
+			Punct::new(',', Spacing::Alone).into(),
+			Literal::string(":").into(),
+			Punct::new(',', Spacing::Alone).into(),
+		])
+		.chain(r#macro("line", Vec::new()))
+		.collect();
+
+	custom_section.interpolate_with_length_value(r#macro("concat", args).collect());
+}
+
 fn parse_required_embeds(
 	input: &mut Peekable<token_stream::IntoIter>,
 	custom_section: &mut CustomSection,
@@ -9,18 +9,41 @@ fn basic() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 11] = *b"\x03\0foo\x03\0bar\0";
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
+			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
+			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
+			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
+			const VAL_1_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
+			const ARR_2: [::core::primitive::u8; 1] = *b"\0";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 11;
+				len += 13;
+				len += LEN_1;
+				len += 2;
+				len += 1;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 11]);
+			struct Layout(
+				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 13],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_1],
+				[::core::primitive::u8; 1],
+			);
 
 			#[unsafe(link_section = "js_bindgen.embed")]
-			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+			static CUSTOM_SECTION: Layout = Layout(
+				::core::primitive::u32::to_le_bytes(LEN),
+				ARR_0,
+				VAL_1_LEN,
+				ARR_1,
+				ARR_2,
+			);
 		};
 	});
 }
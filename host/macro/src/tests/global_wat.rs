@@ -6,15 +6,15 @@ fn basic() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 7] = *b"foo\nbar";
+			const ARR_0: [::core::primitive::u8; 10] = *b"\0\x01\0foo\nbar";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 7;
+				len += 10;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 7]);
+			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 10]);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
@@ -28,15 +28,18 @@ fn minimum() {
 
 	test!(output, {
 		const _: () = {
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
+				len += 3;
 				len as _
 			};
+
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4]);
+			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 3]);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
-			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN));
+			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
 		};
 	});
 }
@@ -47,15 +50,15 @@ fn no_newline() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 3] = *b"foo";
+			const ARR_0: [::core::primitive::u8; 6] = *b"\0\x01\0foo";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 3;
+				len += 6;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 3]);
+			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 6]);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
@@ -79,13 +82,13 @@ fn merge_strings() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 12] = *b"foo\nbar\nbaz\n";
+			const ARR_0: [::core::primitive::u8; 15] = *b"\0\x01\0foo\nbar\nbaz\n";
 			#[cfg(test)]
 			const ARR_1: [::core::primitive::u8; 4] = *b"qux\n";
 			const ARR_2: [::core::primitive::u8; 17] = *b"quux\ncorge\ngrault";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 12;
+				len += 15;
 				#[cfg(test)]
 				{
 					len += 4;
@@ -97,7 +100,7 @@ fn merge_strings() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 12],
+				[::core::primitive::u8; 15],
 				#[cfg(test)] [::core::primitive::u8; 4],
 				[::core::primitive::u8; 17],
 			);
@@ -128,7 +131,7 @@ fn merge_edge_1() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 1] = *b"\n";
+			const ARR_0: [::core::primitive::u8; 4] = *b"\0\x01\0\n";
 			#[cfg(test)]
 			const ARR_1: [::core::primitive::u8; 1] = *b"\n";
 			const ARR_2: [::core::primitive::u8; 4] = *b"foo\n";
@@ -138,7 +141,7 @@ fn merge_edge_1() {
 			const ARR_3: [::core::primitive::u8; LEN_3] = unsafe { *(PTR_3 as *const _) };
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 1;
+				len += 4;
 				#[cfg(test)]
 				{
 					len += 1;
@@ -151,7 +154,7 @@ fn merge_edge_1() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 1],
+				[::core::primitive::u8; 4],
 				#[cfg(test)] [::core::primitive::u8; 1],
 				[::core::primitive::u8; 4],
 				[::core::primitive::u8; LEN_3],
@@ -182,10 +185,12 @@ fn merge_edge_2() {
 
 	test!(output, {
 		const _: () = {
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
 			#[cfg(test)]
-			const ARR_0: [::core::primitive::u8; 1] = *b"\n";
+			const ARR_1: [::core::primitive::u8; 1] = *b"\n";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
+				len += 3;
 				#[cfg(test)]
 				{
 					len += 1;
@@ -196,14 +201,16 @@ fn merge_edge_2() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
 				#[cfg(test)] [::core::primitive::u8; 1],
 			);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(
 				::core::primitive::u32::to_le_bytes(LEN),
-				#[cfg(test)]
 				ARR_0,
+				#[cfg(test)]
+				ARR_1,
 			);
 		};
 	});
@@ -221,13 +228,13 @@ fn cfg() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 6] = *b"test1\n";
+			const ARR_0: [::core::primitive::u8; 9] = *b"\0\x01\0test1\n";
 			#[cfg(test)]
 			const ARR_1: [::core::primitive::u8; 6] = *b"test2\n";
 			const ARR_2: [::core::primitive::u8; 5] = *b"test3";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 6;
+				len += 9;
 				#[cfg(test)]
 				{
 					len += 6;
@@ -239,7 +246,7 @@ fn cfg() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 6],
+				[::core::primitive::u8; 9],
 				#[cfg(test)] [::core::primitive::u8; 6],
 				[::core::primitive::u8; 5],
 			);
@@ -262,15 +269,15 @@ fn escape() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 6] = *b"\n\t\"\\{}";
+			const ARR_0: [::core::primitive::u8; 9] = *b"\0\x01\0\n\t\"\\{}";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 6;
+				len += 9;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 6]);
+			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 9]);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
@@ -286,15 +293,15 @@ fn escape_newline() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 8] = *b"foo \tbar";
+			const ARR_0: [::core::primitive::u8; 11] = *b"\0\x01\0foo \tbar";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 8;
+				len += 11;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 8]);
+			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 11]);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
@@ -308,21 +315,28 @@ fn interpolate() {
 
 	test!(output, {
 		const _: () = {
-			const VAL_0: &::core::primitive::str = "test";
-			const LEN_0: ::core::primitive::usize = ::core::primitive::str::len(VAL_0);
-			const PTR_0: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_0);
-			const ARR_0: [::core::primitive::u8; LEN_0] = unsafe { *(PTR_0 as *const _) };
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
+			const VAL_1: &::core::primitive::str = "test";
+			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
+			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
+			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += LEN_0;
+				len += 3;
+				len += LEN_1;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; LEN_0]);
+			struct Layout(
+				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
+				[::core::primitive::u8; LEN_1],
+			);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
-			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+			static CUSTOM_SECTION: Layout =
+				Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0, ARR_1);
 		};
 	});
 }
@@ -333,21 +347,28 @@ fn r#const() {
 
 	test!(output, {
 		const _: () = {
-			const LEN_0: ::core::primitive::usize =
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
+			const LEN_1: ::core::primitive::usize =
 				::js_bindgen::r#macro::ConstInteger(42).__jbg_len();
-			const ARR_0: [::core::primitive::u8; LEN_0] =
-				::js_bindgen::r#macro::ConstInteger(42).__jbg_to_le_bytes::<LEN_0>();
+			const ARR_1: [::core::primitive::u8; LEN_1] =
+				::js_bindgen::r#macro::ConstInteger(42).__jbg_to_le_bytes::<LEN_1>();
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += LEN_0;
+				len += 3;
+				len += LEN_1;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; LEN_0]);
+			struct Layout(
+				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
+				[::core::primitive::u8; LEN_1],
+			);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
-			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+			static CUSTOM_SECTION: Layout =
+				Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0, ARR_1);
 		};
 	});
 }
@@ -364,29 +385,32 @@ fn interpolate_macro() {
 
 	test!(output, {
 		const _: () = {
-			const VAL_0: &::core::primitive::str = foo!();
-			const LEN_0: ::core::primitive::usize = ::core::primitive::str::len(VAL_0);
-			const PTR_0: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_0);
-			const ARR_0: [::core::primitive::u8; LEN_0] = unsafe { *(PTR_0 as *const _) };
-			const ARR_1: [::core::primitive::u8; 1] = *b"\n";
-			const VAL_2: &::core::primitive::str = <Foo<Bar::Baz> as Qux>::QUUX;
-			const LEN_2: ::core::primitive::usize = ::core::primitive::str::len(VAL_2);
-			const PTR_2: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_2);
-			const ARR_2: [::core::primitive::u8; LEN_2] = unsafe { *(PTR_2 as *const _) };
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
+			const VAL_1: &::core::primitive::str = foo!();
+			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
+			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
+			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
+			const ARR_2: [::core::primitive::u8; 1] = *b"\n";
+			const VAL_3: &::core::primitive::str = <Foo<Bar::Baz> as Qux>::QUUX;
+			const LEN_3: ::core::primitive::usize = ::core::primitive::str::len(VAL_3);
+			const PTR_3: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_3);
+			const ARR_3: [::core::primitive::u8; LEN_3] = unsafe { *(PTR_3 as *const _) };
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += LEN_0;
+				len += 3;
+				len += LEN_1;
 				len += 1;
-				len += LEN_2;
+				len += LEN_3;
 				len as _
 			};
 
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; LEN_0],
+				[::core::primitive::u8; 3],
+				[::core::primitive::u8; LEN_1],
 				[::core::primitive::u8; 1],
-				[::core::primitive::u8; LEN_2],
+				[::core::primitive::u8; LEN_3],
 			);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
@@ -395,6 +419,7 @@ fn interpolate_macro() {
 				ARR_0,
 				ARR_1,
 				ARR_2,
+				ARR_3,
 			);
 		};
 	});
@@ -410,18 +435,24 @@ fn named_const() {
 				::js_bindgen::r#macro::ConstInteger(42).__jbg_len();
 			const ARR_par: [::core::primitive::u8; LEN_par] =
 				::js_bindgen::r#macro::ConstInteger(42).__jbg_to_le_bytes::<LEN_par>();
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
+				len += 3;
 				len += LEN_par;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; LEN_par]);
+			struct Layout(
+				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
+				[::core::primitive::u8; LEN_par],
+			);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout =
-				Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_par);
+				Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0, ARR_par);
 		};
 	});
 }
@@ -445,8 +476,10 @@ fn named_cfg() {
 			const PTR_par: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_par);
 			#[cfg(test)]
 			const ARR_par: [::core::primitive::u8; LEN_par] = unsafe { *(PTR_par as *const _) };
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
+				len += 3;
 				#[cfg(test)]
 				{
 					len += LEN_par;
@@ -457,12 +490,14 @@ fn named_cfg() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
 				#[cfg(test)] [::core::primitive::u8; LEN_par],
 			);
 
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(
 				::core::primitive::u32::to_le_bytes(LEN),
+				ARR_0,
 				#[cfg(test)]
 				ARR_par,
 			);
@@ -499,9 +534,11 @@ fn named_cfg_2() {
 			#[cfg(test)]
 			const ARR_par_2: [::core::primitive::u8; LEN_par_2] =
 				unsafe { *(PTR_par_2 as *const _) };
-			const ARR_0: [::core::primitive::u8; 1] = *b"\n";
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
+			const ARR_1: [::core::primitive::u8; 1] = *b"\n";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
+				len += 3;
 				len += LEN_par_1;
 				len += 1;
 				#[cfg(test)]
@@ -514,6 +551,7 @@ fn named_cfg_2() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
 				[::core::primitive::u8; LEN_par_1],
 				[::core::primitive::u8; 1],
 				#[cfg(test)] [::core::primitive::u8; LEN_par_2],
@@ -522,8 +560,9 @@ fn named_cfg_2() {
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(
 				::core::primitive::u32::to_le_bytes(LEN),
-				ARR_par_1,
 				ARR_0,
+				ARR_par_1,
+				ARR_1,
 				#[cfg(test)]
 				ARR_par_2,
 			);
@@ -560,8 +599,10 @@ fn named_cfg_same() {
 			const PTR_par: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_par);
 			#[cfg(not(test))]
 			const ARR_par: [::core::primitive::u8; LEN_par] = unsafe { *(PTR_par as *const _) };
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
+				len += 3;
 				#[cfg(test)]
 				{
 					len += LEN_par;
@@ -576,6 +617,7 @@ fn named_cfg_same() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
 				#[cfg(test)] [::core::primitive::u8; LEN_par],
 				#[cfg(not(test))] [::core::primitive::u8; LEN_par],
 			);
@@ -583,6 +625,7 @@ fn named_cfg_same() {
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(
 				::core::primitive::u32::to_le_bytes(LEN),
+				ARR_0,
 				#[cfg(test)]
 				ARR_par,
 				#[cfg(not(test))]
@@ -617,8 +660,10 @@ fn named_const_cfg_same() {
 			#[cfg(not(test))]
 			const ARR_par: [::core::primitive::u8; LEN_par] =
 				::js_bindgen::r#macro::ConstInteger(0).__jbg_to_le_bytes::<LEN_par>();
+			const ARR_0: [::core::primitive::u8; 3] = *b"\0\x01\0";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
+				len += 3;
 				#[cfg(test)]
 				{
 					len += LEN_par;
@@ -633,6 +678,7 @@ fn named_const_cfg_same() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 3],
 				#[cfg(test)] [::core::primitive::u8; LEN_par],
 				#[cfg(not(test))] [::core::primitive::u8; LEN_par],
 			);
@@ -640,6 +686,7 @@ fn named_const_cfg_same() {
 			#[unsafe(link_section = "js_bindgen.wat")]
 			static CUSTOM_SECTION: Layout = Layout(
 				::core::primitive::u32::to_le_bytes(LEN),
+				ARR_0,
 				#[cfg(test)]
 				ARR_par,
 				#[cfg(not(test))]
@@ -9,18 +9,41 @@ fn basic() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 18] = *b"\x03\0foo\x03\0bar\0baz\nqux";
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
+			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
+			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
+			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
+			const VAL_1_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
+			const ARR_2: [::core::primitive::u8; 8] = *b"\0baz\nqux";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 18;
+				len += 13;
+				len += LEN_1;
+				len += 2;
+				len += 8;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 18]);
+			struct Layout(
+				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 13],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_1],
+				[::core::primitive::u8; 8],
+			);
 
 			#[unsafe(link_section = "js_bindgen.import")]
-			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+			static CUSTOM_SECTION: Layout = Layout(
+				::core::primitive::u32::to_le_bytes(LEN),
+				ARR_0,
+				VAL_1_LEN,
+				ARR_1,
+				ARR_2,
+			);
 		};
 	});
 }
@@ -35,25 +58,36 @@ fn required_embeds() {
 	test!(output, {
 		const _: () = {
 			const TUPLE_0: (&::core::primitive::str, &::core::primitive::str) = ("baz", "qux");
-			const ARR_0: [::core::primitive::u8; 11] = *b"\x03\0foo\x03\0bar\x01";
-			const VAL_1: &::core::primitive::str = TUPLE_0.0;
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
 			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
 			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
 			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
 			const VAL_1_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
-			const VAL_2: &::core::primitive::str = TUPLE_0.1;
-			const LEN_2: ::core::primitive::usize = ::core::primitive::str::len(VAL_2);
-			const PTR_2: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_2);
-			const ARR_2: [::core::primitive::u8; LEN_2] = unsafe { *(PTR_2 as *const _) };
-			const VAL_2_LEN: [::core::primitive::u8; 2] =
-				::core::primitive::u16::to_le_bytes(LEN_2 as u16);
+			const ARR_2: [::core::primitive::u8; 1] = *b"\x01";
+			const VAL_3: &::core::primitive::str = TUPLE_0.0;
+			const LEN_3: ::core::primitive::usize = ::core::primitive::str::len(VAL_3);
+			const PTR_3: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_3);
+			const ARR_3: [::core::primitive::u8; LEN_3] = unsafe { *(PTR_3 as *const _) };
+			const VAL_3_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_3 as u16);
+			const VAL_4: &::core::primitive::str = TUPLE_0.1;
+			const LEN_4: ::core::primitive::usize = ::core::primitive::str::len(VAL_4);
+			const PTR_4: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_4);
+			const ARR_4: [::core::primitive::u8; LEN_4] = unsafe { *(PTR_4 as *const _) };
+			const VAL_4_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_4 as u16);
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 11;
+				len += 13;
 				len += LEN_1;
 				len += 2;
-				len += LEN_2;
+				len += 1;
+				len += LEN_3;
+				len += 2;
+				len += LEN_4;
 				len += 2;
 				len as _
 			};
@@ -61,11 +95,14 @@ fn required_embeds() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 11],
+				[::core::primitive::u8; 13],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_1],
+				[::core::primitive::u8; 1],
 				[::core::primitive::u8; 2],
-				[::core::primitive::u8; LEN_2],
+				[::core::primitive::u8; LEN_3],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_4],
 			);
 
 			#[unsafe(link_section = "js_bindgen.import")]
@@ -74,8 +111,11 @@ fn required_embeds() {
 				ARR_0,
 				VAL_1_LEN,
 				ARR_1,
-				VAL_2_LEN,
 				ARR_2,
+				VAL_3_LEN,
+				ARR_3,
+				VAL_4_LEN,
+				ARR_4,
 			);
 		};
 	});
@@ -91,25 +131,36 @@ fn required_embeds_expr() {
 	test!(output, {
 		const _: () = {
 			const TUPLE_0: (&::core::primitive::str, &::core::primitive::str) = 123 + 456;
-			const ARR_0: [::core::primitive::u8; 11] = *b"\x03\0foo\x03\0bar\x01";
-			const VAL_1: &::core::primitive::str = TUPLE_0.0;
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
 			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
 			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
 			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
 			const VAL_1_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
-			const VAL_2: &::core::primitive::str = TUPLE_0.1;
-			const LEN_2: ::core::primitive::usize = ::core::primitive::str::len(VAL_2);
-			const PTR_2: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_2);
-			const ARR_2: [::core::primitive::u8; LEN_2] = unsafe { *(PTR_2 as *const _) };
-			const VAL_2_LEN: [::core::primitive::u8; 2] =
-				::core::primitive::u16::to_le_bytes(LEN_2 as u16);
+			const ARR_2: [::core::primitive::u8; 1] = *b"\x01";
+			const VAL_3: &::core::primitive::str = TUPLE_0.0;
+			const LEN_3: ::core::primitive::usize = ::core::primitive::str::len(VAL_3);
+			const PTR_3: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_3);
+			const ARR_3: [::core::primitive::u8; LEN_3] = unsafe { *(PTR_3 as *const _) };
+			const VAL_3_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_3 as u16);
+			const VAL_4: &::core::primitive::str = TUPLE_0.1;
+			const LEN_4: ::core::primitive::usize = ::core::primitive::str::len(VAL_4);
+			const PTR_4: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_4);
+			const ARR_4: [::core::primitive::u8; LEN_4] = unsafe { *(PTR_4 as *const _) };
+			const VAL_4_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_4 as u16);
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 11;
+				len += 13;
 				len += LEN_1;
 				len += 2;
-				len += LEN_2;
+				len += 1;
+				len += LEN_3;
+				len += 2;
+				len += LEN_4;
 				len += 2;
 				len as _
 			};
@@ -117,11 +168,14 @@ fn required_embeds_expr() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 11],
+				[::core::primitive::u8; 13],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_1],
+				[::core::primitive::u8; 1],
 				[::core::primitive::u8; 2],
-				[::core::primitive::u8; LEN_2],
+				[::core::primitive::u8; LEN_3],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_4],
 			);
 
 			#[unsafe(link_section = "js_bindgen.import")]
@@ -130,8 +184,11 @@ fn required_embeds_expr() {
 				ARR_0,
 				VAL_1_LEN,
 				ARR_1,
-				VAL_2_LEN,
 				ARR_2,
+				VAL_3_LEN,
+				ARR_3,
+				VAL_4_LEN,
+				ARR_4,
 			);
 		};
 	});
@@ -148,20 +205,16 @@ fn required_embeds_cfg() {
 		const _: () = {
 			#[cfg(test)]
 			const TUPLE_0: (&::core::primitive::str, &::core::primitive::str) = ("baz", "qux");
-			const ARR_0: [::core::primitive::u8; 10] = *b"\x03\0foo\x03\0bar";
-			#[cfg(test)]
-			const VAL_1: &::core::primitive::str = TUPLE_0.0;
-			#[cfg(test)]
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
 			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
-			#[cfg(test)]
 			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
-			#[cfg(test)]
 			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
-			#[cfg(test)]
 			const VAL_1_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
 			#[cfg(test)]
-			const VAL_2: &::core::primitive::str = TUPLE_0.1;
+			const VAL_2: &::core::primitive::str = TUPLE_0.0;
 			#[cfg(test)]
 			const LEN_2: ::core::primitive::usize = ::core::primitive::str::len(VAL_2);
 			#[cfg(test)]
@@ -171,18 +224,31 @@ fn required_embeds_cfg() {
 			#[cfg(test)]
 			const VAL_2_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_2 as u16);
+			#[cfg(test)]
+			const VAL_3: &::core::primitive::str = TUPLE_0.1;
+			#[cfg(test)]
+			const LEN_3: ::core::primitive::usize = ::core::primitive::str::len(VAL_3);
+			#[cfg(test)]
+			const PTR_3: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_3);
+			#[cfg(test)]
+			const ARR_3: [::core::primitive::u8; LEN_3] = unsafe { *(PTR_3 as *const _) };
+			#[cfg(test)]
+			const VAL_3_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_3 as u16);
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 10;
+				len += 13;
+				len += LEN_1;
+				len += 2;
 				len += 1;
 				#[cfg(test)]
 				{
-					len += LEN_1;
+					len += LEN_2;
 					len += 2;
 				}
 				#[cfg(test)]
 				{
-					len += LEN_2;
+					len += LEN_3;
 					len += 2;
 				}
 				len as _
@@ -199,27 +265,31 @@ fn required_embeds_cfg() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 10],
+				[::core::primitive::u8; 13],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_1],
 				[::core::primitive::u8; 1],
 				#[cfg(test)] [::core::primitive::u8; 2],
-				#[cfg(test)] [::core::primitive::u8; LEN_1],
-				#[cfg(test)] [::core::primitive::u8; 2],
 				#[cfg(test)] [::core::primitive::u8; LEN_2],
+				#[cfg(test)] [::core::primitive::u8; 2],
+				#[cfg(test)] [::core::primitive::u8; LEN_3],
 			);
 
 			#[unsafe(link_section = "js_bindgen.import")]
 			static CUSTOM_SECTION: Layout = Layout(
 				::core::primitive::u32::to_le_bytes(LEN),
 				ARR_0,
-				[TUPLE_COUNT],
-				#[cfg(test)]
 				VAL_1_LEN,
-				#[cfg(test)]
 				ARR_1,
+				[TUPLE_COUNT],
 				#[cfg(test)]
 				VAL_2_LEN,
 				#[cfg(test)]
 				ARR_2,
+				#[cfg(test)]
+				VAL_3_LEN,
+				#[cfg(test)]
+				ARR_3,
 			);
 		};
 	});
@@ -236,57 +306,71 @@ fn required_embeds_multiple() {
 		const _: () = {
 			const TUPLE_0: (&::core::primitive::str, &::core::primitive::str) = ("baz", "qux");
 			const TUPLE_1: (&::core::primitive::str, &::core::primitive::str) = ("quux", "corge");
-			const ARR_0: [::core::primitive::u8; 11] = *b"\x03\0foo\x03\0bar\x02";
-			const VAL_1: &::core::primitive::str = TUPLE_0.0;
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
 			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
 			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
 			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
 			const VAL_1_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
-			const VAL_2: &::core::primitive::str = TUPLE_0.1;
-			const LEN_2: ::core::primitive::usize = ::core::primitive::str::len(VAL_2);
-			const PTR_2: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_2);
-			const ARR_2: [::core::primitive::u8; LEN_2] = unsafe { *(PTR_2 as *const _) };
-			const VAL_2_LEN: [::core::primitive::u8; 2] =
-				::core::primitive::u16::to_le_bytes(LEN_2 as u16);
-			const VAL_3: &::core::primitive::str = TUPLE_1.0;
+			const ARR_2: [::core::primitive::u8; 1] = *b"\x02";
+			const VAL_3: &::core::primitive::str = TUPLE_0.0;
 			const LEN_3: ::core::primitive::usize = ::core::primitive::str::len(VAL_3);
 			const PTR_3: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_3);
 			const ARR_3: [::core::primitive::u8; LEN_3] = unsafe { *(PTR_3 as *const _) };
 			const VAL_3_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_3 as u16);
-			const VAL_4: &::core::primitive::str = TUPLE_1.1;
+			const VAL_4: &::core::primitive::str = TUPLE_0.1;
 			const LEN_4: ::core::primitive::usize = ::core::primitive::str::len(VAL_4);
 			const PTR_4: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_4);
 			const ARR_4: [::core::primitive::u8; LEN_4] = unsafe { *(PTR_4 as *const _) };
 			const VAL_4_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_4 as u16);
+			const VAL_5: &::core::primitive::str = TUPLE_1.0;
+			const LEN_5: ::core::primitive::usize = ::core::primitive::str::len(VAL_5);
+			const PTR_5: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_5);
+			const ARR_5: [::core::primitive::u8; LEN_5] = unsafe { *(PTR_5 as *const _) };
+			const VAL_5_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_5 as u16);
+			const VAL_6: &::core::primitive::str = TUPLE_1.1;
+			const LEN_6: ::core::primitive::usize = ::core::primitive::str::len(VAL_6);
+			const PTR_6: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_6);
+			const ARR_6: [::core::primitive::u8; LEN_6] = unsafe { *(PTR_6 as *const _) };
+			const VAL_6_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_6 as u16);
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 11;
+				len += 13;
 				len += LEN_1;
 				len += 2;
-				len += LEN_2;
-				len += 2;
+				len += 1;
 				len += LEN_3;
 				len += 2;
 				len += LEN_4;
 				len += 2;
+				len += LEN_5;
+				len += 2;
+				len += LEN_6;
+				len += 2;
 				len as _
 			};
 
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 11],
+				[::core::primitive::u8; 13],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_1],
-				[::core::primitive::u8; 2],
-				[::core::primitive::u8; LEN_2],
+				[::core::primitive::u8; 1],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_3],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_4],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_5],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_6],
 			);
 
 			#[unsafe(link_section = "js_bindgen.import")]
@@ -295,12 +379,15 @@ fn required_embeds_multiple() {
 				ARR_0,
 				VAL_1_LEN,
 				ARR_1,
-				VAL_2_LEN,
 				ARR_2,
 				VAL_3_LEN,
 				ARR_3,
 				VAL_4_LEN,
 				ARR_4,
+				VAL_5_LEN,
+				ARR_5,
+				VAL_6_LEN,
+				ARR_6,
 			);
 		};
 	});
@@ -315,18 +402,41 @@ fn required_embeds_empty() {
 
 	test!(output, {
 		const _: () = {
-			const ARR_0: [::core::primitive::u8; 11] = *b"\x03\0foo\x03\0bar\0";
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
+			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
+			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
+			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
+			const VAL_1_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
+			const ARR_2: [::core::primitive::u8; 1] = *b"\0";
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 11;
+				len += 13;
+				len += LEN_1;
+				len += 2;
+				len += 1;
 				len as _
 			};
 
 			#[repr(C)]
-			struct Layout([::core::primitive::u8; 4], [::core::primitive::u8; 11]);
+			struct Layout(
+				[::core::primitive::u8; 4],
+				[::core::primitive::u8; 13],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_1],
+				[::core::primitive::u8; 1],
+			);
 
 			#[unsafe(link_section = "js_bindgen.import")]
-			static CUSTOM_SECTION: Layout = Layout(::core::primitive::u32::to_le_bytes(LEN), ARR_0);
+			static CUSTOM_SECTION: Layout = Layout(
+				::core::primitive::u32::to_le_bytes(LEN),
+				ARR_0,
+				VAL_1_LEN,
+				ARR_1,
+				ARR_2,
+			);
 		};
 	});
 }
@@ -356,32 +466,28 @@ fn required_embeds_mixed() {
 			#[cfg(not(test))]
 			const TUPLE_2: (&::core::primitive::str, &::core::primitive::str) = ("42", "42");
 			const TUPLE_3: (&::core::primitive::str, &::core::primitive::str) = ("2", "2");
-			const ARR_0: [::core::primitive::u8; 10] = *b"\x03\0foo\x03\0bar";
-			const VAL_1: &::core::primitive::str = TUPLE_0.0;
+			const ARR_0: [::core::primitive::u8; 13] = *b"\0\x01\0\x03\0foo\x03\0bar";
+			const VAL_1: &::core::primitive::str =
+				::core::concat!(::core::file!(), ":", ::core::line!(),);
 			const LEN_1: ::core::primitive::usize = ::core::primitive::str::len(VAL_1);
 			const PTR_1: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_1);
 			const ARR_1: [::core::primitive::u8; LEN_1] = unsafe { *(PTR_1 as *const _) };
 			const VAL_1_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_1 as u16);
-			const VAL_2: &::core::primitive::str = TUPLE_0.1;
+			const VAL_2: &::core::primitive::str = TUPLE_0.0;
 			const LEN_2: ::core::primitive::usize = ::core::primitive::str::len(VAL_2);
 			const PTR_2: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_2);
 			const ARR_2: [::core::primitive::u8; LEN_2] = unsafe { *(PTR_2 as *const _) };
 			const VAL_2_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_2 as u16);
-			#[cfg(test)]
-			const VAL_3: &::core::primitive::str = TUPLE_1.0;
-			#[cfg(test)]
+			const VAL_3: &::core::primitive::str = TUPLE_0.1;
 			const LEN_3: ::core::primitive::usize = ::core::primitive::str::len(VAL_3);
-			#[cfg(test)]
 			const PTR_3: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_3);
-			#[cfg(test)]
 			const ARR_3: [::core::primitive::u8; LEN_3] = unsafe { *(PTR_3 as *const _) };
-			#[cfg(test)]
 			const VAL_3_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_3 as u16);
 			#[cfg(test)]
-			const VAL_4: &::core::primitive::str = TUPLE_1.1;
+			const VAL_4: &::core::primitive::str = TUPLE_1.0;
 			#[cfg(test)]
 			const LEN_4: ::core::primitive::usize = ::core::primitive::str::len(VAL_4);
 			#[cfg(test)]
@@ -391,19 +497,19 @@ fn required_embeds_mixed() {
 			#[cfg(test)]
 			const VAL_4_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_4 as u16);
-			#[cfg(not(test))]
-			const VAL_5: &::core::primitive::str = TUPLE_2.0;
-			#[cfg(not(test))]
+			#[cfg(test)]
+			const VAL_5: &::core::primitive::str = TUPLE_1.1;
+			#[cfg(test)]
 			const LEN_5: ::core::primitive::usize = ::core::primitive::str::len(VAL_5);
-			#[cfg(not(test))]
+			#[cfg(test)]
 			const PTR_5: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_5);
-			#[cfg(not(test))]
+			#[cfg(test)]
 			const ARR_5: [::core::primitive::u8; LEN_5] = unsafe { *(PTR_5 as *const _) };
-			#[cfg(not(test))]
+			#[cfg(test)]
 			const VAL_5_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_5 as u16);
 			#[cfg(not(test))]
-			const VAL_6: &::core::primitive::str = TUPLE_2.1;
+			const VAL_6: &::core::primitive::str = TUPLE_2.0;
 			#[cfg(not(test))]
 			const LEN_6: ::core::primitive::usize = ::core::primitive::str::len(VAL_6);
 			#[cfg(not(test))]
@@ -413,50 +519,63 @@ fn required_embeds_mixed() {
 			#[cfg(not(test))]
 			const VAL_6_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_6 as u16);
-			const VAL_7: &::core::primitive::str = TUPLE_3.0;
+			#[cfg(not(test))]
+			const VAL_7: &::core::primitive::str = TUPLE_2.1;
+			#[cfg(not(test))]
 			const LEN_7: ::core::primitive::usize = ::core::primitive::str::len(VAL_7);
+			#[cfg(not(test))]
 			const PTR_7: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_7);
+			#[cfg(not(test))]
 			const ARR_7: [::core::primitive::u8; LEN_7] = unsafe { *(PTR_7 as *const _) };
+			#[cfg(not(test))]
 			const VAL_7_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_7 as u16);
-			const VAL_8: &::core::primitive::str = TUPLE_3.1;
+			const VAL_8: &::core::primitive::str = TUPLE_3.0;
 			const LEN_8: ::core::primitive::usize = ::core::primitive::str::len(VAL_8);
 			const PTR_8: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_8);
 			const ARR_8: [::core::primitive::u8; LEN_8] = unsafe { *(PTR_8 as *const _) };
 			const VAL_8_LEN: [::core::primitive::u8; 2] =
 				::core::primitive::u16::to_le_bytes(LEN_8 as u16);
+			const VAL_9: &::core::primitive::str = TUPLE_3.1;
+			const LEN_9: ::core::primitive::usize = ::core::primitive::str::len(VAL_9);
+			const PTR_9: *const ::core::primitive::u8 = ::core::primitive::str::as_ptr(VAL_9);
+			const ARR_9: [::core::primitive::u8; LEN_9] = unsafe { *(PTR_9 as *const _) };
+			const VAL_9_LEN: [::core::primitive::u8; 2] =
+				::core::primitive::u16::to_le_bytes(LEN_9 as u16);
 			const LEN: ::core::primitive::u32 = {
 				let mut len = 0;
-				len += 10;
-				len += 1;
+				len += 13;
 				len += LEN_1;
 				len += 2;
+				len += 1;
 				len += LEN_2;
 				len += 2;
+				len += LEN_3;
+				len += 2;
 				#[cfg(test)]
 				{
-					len += LEN_3;
+					len += LEN_4;
 					len += 2;
 				}
 				#[cfg(test)]
 				{
-					len += LEN_4;
+					len += LEN_5;
 					len += 2;
 				}
 				#[cfg(not(test))]
 				{
-					len += LEN_5;
+					len += LEN_6;
 					len += 2;
 				}
 				#[cfg(not(test))]
 				{
-					len += LEN_6;
+					len += LEN_7;
 					len += 2;
 				}
-				len += LEN_7;
-				len += 2;
 				len += LEN_8;
 				len += 2;
+				len += LEN_9;
+				len += 2;
 				len as _
 			};
 			const TUPLE_COUNT: ::core::primitive::u8 = {
@@ -475,55 +594,59 @@ fn required_embeds_mixed() {
 			#[repr(C)]
 			struct Layout(
 				[::core::primitive::u8; 4],
-				[::core::primitive::u8; 10],
-				[::core::primitive::u8; 1],
+				[::core::primitive::u8; 13],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_1],
+				[::core::primitive::u8; 1],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_2],
-				#[cfg(test)] [::core::primitive::u8; 2],
-				#[cfg(test)] [::core::primitive::u8; LEN_3],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_3],
 				#[cfg(test)] [::core::primitive::u8; 2],
 				#[cfg(test)] [::core::primitive::u8; LEN_4],
-				#[cfg(not(test))] [::core::primitive::u8; 2],
-				#[cfg(not(test))] [::core::primitive::u8; LEN_5],
+				#[cfg(test)] [::core::primitive::u8; 2],
+				#[cfg(test)] [::core::primitive::u8; LEN_5],
 				#[cfg(not(test))] [::core::primitive::u8; 2],
 				#[cfg(not(test))] [::core::primitive::u8; LEN_6],
-				[::core::primitive::u8; 2],
-				[::core::primitive::u8; LEN_7],
+				#[cfg(not(test))] [::core::primitive::u8; 2],
+				#[cfg(not(test))] [::core::primitive::u8; LEN_7],
 				[::core::primitive::u8; 2],
 				[::core::primitive::u8; LEN_8],
+				[::core::primitive::u8; 2],
+				[::core::primitive::u8; LEN_9],
 			);
 
 			#[unsafe(link_section = "js_bindgen.import")]
 			static CUSTOM_SECTION: Layout = Layout(
 				::core::primitive::u32::to_le_bytes(LEN),
 				ARR_0,
-				[TUPLE_COUNT],
 				VAL_1_LEN,
 				ARR_1,
+				[TUPLE_COUNT],
 				VAL_2_LEN,
 				ARR_2,
-				#[cfg(test)]
 				VAL_3_LEN,
-				#[cfg(test)]
 				ARR_3,
 				#[cfg(test)]
 				VAL_4_LEN,
 				#[cfg(test)]
 				ARR_4,
-				#[cfg(not(test))]
+				#[cfg(test)]
 				VAL_5_LEN,
-				#[cfg(not(test))]
+				#[cfg(test)]
 				ARR_5,
 				#[cfg(not(test))]
 				VAL_6_LEN,
 				#[cfg(not(test))]
 				ARR_6,
+				#[cfg(not(test))]
 				VAL_7_LEN,
+				#[cfg(not(test))]
 				ARR_7,
 				VAL_8_LEN,
 				ARR_8,
+				VAL_9_LEN,
+				ARR_9,
 			);
 		};
 	});
@@ -10,3 +10,4 @@ macro_rules! test {
 mod embed_js;
 mod global_wat;
 mod import_js;
+mod namespace;
@@ -0,0 +1,15 @@
+#[test]
+fn semver_compatible() {
+	assert_eq!(
+		crate::namespace_from("js_sys", "0", "0", "0"),
+		"js_sys_0_0_0"
+	);
+	assert_eq!(
+		crate::namespace_from("js_sys", "0", "0", "3"),
+		"js_sys_0_0_3"
+	);
+	assert_eq!(crate::namespace_from("js_sys", "0", "2", "0"), "js_sys_0_2");
+	assert_eq!(crate::namespace_from("js_sys", "0", "2", "3"), "js_sys_0_2");
+	assert_eq!(crate::namespace_from("js_sys", "1", "0", "0"), "js_sys_1");
+	assert_eq!(crate::namespace_from("js_sys", "1", "2", "3"), "js_sys_1");
+}
@@ -76,6 +76,7 @@ export async function run(module, jsBindgenCtor, report) {
     async function instantiate() {
         let panicMessage;
         let panicPayload;
+        const pending = [];
         let jsBindgen;
         try {
             jsBindgen = new jsBindgenCtor(module);
@@ -85,9 +86,11 @@ export async function run(module, jsBindgenCtor, report) {
             return;
         }
         jsBindgen.extendImportObject({
+            // Not namespaced, so the runner supports every version of `js-bindgen-test`.
             js_bindgen_test: {
                 set_message: (message) => (panicMessage = message),
                 set_payload: (payload) => (panicPayload = payload),
+                wait_for: (promise) => pending.push(promise),
             },
         });
         const importObject = jsBindgen.importObject;
@@ -95,6 +98,7 @@ export async function run(module, jsBindgenCtor, report) {
         return {
             importObject,
             instance,
+            pending,
             get panicMessage() {
                 return panicMessage;
             },
@@ -177,6 +181,8 @@ export async function run(module, jsBindgenCtor, report) {
         interceptFlag = true;
         try {
             testFn();
+            // Registered by tests waiting for other threads.
+            await Promise.all(state.pending);
             result = { success: true };
         }
         catch (error) {
@@ -194,9 +200,9 @@ export async function run(module, jsBindgenCtor, report) {
                 failures.push({ name: test.name, error: stdout + "note: test did not panic as expected" });
                 continue;
             }
-            if (typeof test.shouldPanic === "string" &&
-                typeof state.panicPayload === "string" &&
-                !state.panicPayload.includes(test.shouldPanic)) {
+            // Panics thrown as JS errors, e.g. by `js_sys::panic()`, don't go through the panic hook.
+            const panicPayload = state.panicPayload ?? result.message;
+            if (typeof test.shouldPanic === "string" && !panicPayload.includes(test.shouldPanic)) {
                 report(0 /* Stream.Stdout */, [failedText, newLineText]);
                 let stdout = interceptStore.join("");
                 if (stdout.length !== 0) {
@@ -210,7 +216,7 @@ export async function run(module, jsBindgenCtor, report) {
                         result.stack +
                         "\n" +
                         "note: panic did not contain expected string\n" +
-                        `      panic message: "${state.panicPayload}"\n` +
+                        `      panic message: "${panicPayload}"\n` +
                         ` expected substring: "${test.shouldPanic}"`,
                 });
                 continue;
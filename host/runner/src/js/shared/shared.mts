@@ -127,6 +127,7 @@ export async function run(
 	async function instantiate() {
 		let panicMessage: string | undefined
 		let panicPayload: string | undefined
+		const pending: Promise<unknown>[] = []
 		let jsBindgen
 
 		try {
@@ -137,9 +138,11 @@ export async function run(
 		}
 
 		jsBindgen.extendImportObject({
+			// Not namespaced, so the runner supports every version of `js-bindgen-test`.
 			js_bindgen_test: {
 				set_message: (message: string) => (panicMessage = message),
 				set_payload: (payload: string) => (panicPayload = payload),
+				wait_for: (promise: Promise<unknown>) => pending.push(promise),
 			},
 		})
 
@@ -149,6 +152,7 @@ export async function run(
 		return {
 			importObject,
 			instance,
+			pending,
 			get panicMessage() {
 				return panicMessage
 			},
@@ -245,6 +249,8 @@ export async function run(
 
 		try {
 			testFn()
+			// Registered by tests waiting for other threads.
+			await Promise.all(state.pending)
 			result = { success: true }
 		} catch (error) {
 			result = {
@@ -265,11 +271,10 @@ export async function run(
 				continue
 			}
 
-			if (
-				typeof test.shouldPanic === "string" &&
-				typeof state.panicPayload === "string" &&
-				!state.panicPayload.includes(test.shouldPanic)
-			) {
+			// Panics thrown as JS errors, e.g. by `js_sys::panic()`, don't go through the panic hook.
+			const panicPayload = state.panicPayload ?? result.message
+
+			if (typeof test.shouldPanic === "string" && !panicPayload.includes(test.shouldPanic)) {
 				report(Stream.Stdout, [failedText, newLineText])
 				let stdout = interceptStore.join("")
 
@@ -286,7 +291,7 @@ export async function run(
 						result.stack +
 						"\n" +
 						"note: panic did not contain expected string\n" +
-						`      panic message: "${state.panicPayload}"\n` +
+						`      panic message: "${panicPayload}"\n` +
 						` expected substring: "${test.shouldPanic}"`,
 				})
 				continue
@@ -7,10 +7,10 @@ mod test;
 use std::path::PathBuf;
 use std::{env, iter};
 
-use anyhow::{Context, Result, bail};
-use js_bindgen_cli_lib::{JS_OUTPUT_SECTION, JsOutput};
+use anyhow::{Context, Result};
+use js_bindgen_cli_lib::{JsOutputParser, Target};
 use js_bindgen_shared::{IS_COMPAT_SECTION, ReadFile};
-use wasmparser::{MemoryType, Parser, Payload, TypeRef};
+use wasmparser::{Parser, Payload};
 
 use crate::run_data::RunData;
 use crate::runner::Runner;
@@ -38,49 +38,28 @@ fn main() -> Result<()> {
 		.with_context(|| format!("failed to read Wasm file: {}", wasm_path.display()))?;
 
 	let mut web = true;
-	let mut js_output = None;
-	let mut memories = Vec::new();
+	let mut js_output = JsOutputParser::default();
 	let mut test_parser = TestParser::new();
 
 	for payload in Parser::new(0).parse_all(&wasm_bytes) {
 		let payload = payload?;
 
-		if let Payload::ImportSection(section) = &payload {
-			for import in section.clone().into_imports() {
-				let import = import?;
-
-				if let TypeRef::Memory(memory) = import.ty {
-					memories.push(Memory {
-						module: import.module,
-						name: import.name,
-						data: memory,
-					});
-				}
-			}
-		}
-
-		if let Payload::CustomSection(section) = &payload {
-			match section.name() {
-				IS_COMPAT_SECTION => web = false,
-				JS_OUTPUT_SECTION => js_output = Some(postcard::from_bytes(section.data())?),
-				_ => (),
-			}
+		if let Payload::CustomSection(section) = &payload
+			&& section.name() == IS_COMPAT_SECTION
+		{
+			web = false;
 		}
 
+		js_output.parse(&payload)?;
 		test_parser.parse(&payload)?;
 
 		// We found everything we need.
-		if js_output.is_some() && !web && test_parser.found() {
+		if js_output.found() && !web && test_parser.found() {
 			break;
 		}
 	}
 
-	let js_output: JsOutput<&str> = js_output.context("unable to find JS output")?;
-	let Some(main_memory) = memories.iter().find(|memory| {
-		memory.module == js_output.main_memory.module && memory.name == js_output.main_memory.name
-	}) else {
-		bail!("unable to find main memory as encoded")
-	};
+	let (js_output, main_memory) = js_output.finish()?;
 
 	let run_data = if let Some(tests) = test_parser.into_tests() {
 		TestCli::run(iter::once(binary).chain(args), tests)
@@ -102,7 +81,7 @@ fn main() -> Result<()> {
 		};
 
 		Some(RunData::Binary {
-			wasm64: main_memory.data.memory64,
+			wasm64: main_memory.memory64,
 			memory: js_output.main_memory,
 			args,
 		})
@@ -110,7 +89,7 @@ fn main() -> Result<()> {
 
 	if let Some(run_data) = run_data {
 		let mut js_file = Vec::new();
-		js_output.js(&mut js_file, main_memory.data)?;
+		js_output.js(&mut js_file, main_memory, Target::Web, false)?;
 		drop(js_output);
 
 		let run_data = serde_json::to_string(&run_data).unwrap();
@@ -120,9 +99,3 @@ fn main() -> Result<()> {
 
 	Ok(())
 }
-
-struct Memory<'a> {
-	module: &'a str,
-	name: &'a str,
-	data: MemoryType,
-}
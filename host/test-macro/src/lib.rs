@@ -174,7 +174,7 @@ fn test_internal(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
 			#[unsafe(export_name = #foreign_test)]
 			extern "C" fn __jbg_test() {
 				#crate_::set_panic_hook();
-				#ident();
+				#crate_::run_test(#ident);
 			}
 		};
 	})